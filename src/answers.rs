@@ -0,0 +1,102 @@
+//! A registry of known-good answers for checking the solvers against real inputs
+//!
+//! Each non-empty line of the registry is `<day> <part> <input hash> <expected answer>` where the
+//! input hash is the SHA-256 of the input file (the same as `sha256sum` prints) so that answers for
+//! different inputs to the same day can live side by side. Lines starting with `#` are comments.
+
+use sha2::{Digest, Sha256};
+
+use crate::parse::{self, ParseError};
+use crate::Part;
+
+/// An answer which is known to be correct for an input
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Answer {
+    pub day: u8,
+    pub part: Part,
+    pub input_hash: String,
+    pub expected: String,
+}
+
+impl Answer {
+    /// Format this answer as a line of the registry
+    pub fn to_line(&self) -> String {
+        format!("{} {} {} {}", self.day, self.part, self.input_hash, self.expected)
+    }
+}
+
+/// Hash an input so that its answers can be found in the registry
+pub fn hash_input(input: &str) -> String {
+    Sha256::digest(input.as_bytes())
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+/// Parse the registry of answers
+pub fn parse_answers(registry: &str) -> Result<Vec<Answer>, ParseError> {
+    parse::lines(registry.lines())
+        .filter(|line| !line.text.trim().is_empty() && !line.text.starts_with('#'))
+        .map(|line| {
+            let mut fields = line.text.split_whitespace();
+            let mut next = |expected: &str| fields.next().ok_or_else(|| line.missing(expected));
+            let day = next("a day")?;
+            let day = line.parse(day, "a day from 1 to 25")
+                .ok()
+                .filter(|day| (1..=25).contains(day))
+                .ok_or_else(|| line.error(day, "a day from 1 to 25"))?;
+            let part = next("a part")?;
+            let part = match part {
+                "1" => Part::One,
+                "2" => Part::Two,
+                _ => return Err(line.error(part, "`1` or `2`")),
+            };
+            let input_hash = next("the hash of an input")?;
+            if input_hash.len() != 64 || !input_hash.chars().all(|char| char.is_ascii_hexdigit()) {
+                return Err(line.error(input_hash, "a SHA-256 hash"));
+            }
+            let expected = next("the expected answer")?;
+            if let Some(extra) = fields.next() {
+                return Err(line.error(extra, "the end of the line"));
+            }
+            Ok(Answer {
+                day,
+                part,
+                input_hash: input_hash.to_ascii_lowercase(),
+                expected: expected.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Find the expected answer for a part of a day on an input
+pub fn find<'a>(answers: &'a [Answer], day: u8, part: Part, input_hash: &str) -> Option<&'a Answer> {
+    answers.iter().find(|answer| answer.day == day && answer.part == part && answer.input_hash == input_hash)
+}
+
+#[test]
+fn test_hash_input() {
+    assert_eq!("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad", hash_input("abc"));
+}
+
+#[test]
+fn test_parse_answers() {
+    let hash = hash_input("abc");
+    let registry = format!("# day part hash answer\n\n1 2 {hash} 281\n25 2 {hash} -\n");
+    let answers = parse_answers(&registry).unwrap();
+    assert_eq!(
+        vec![
+            Answer { day: 1, part: Part::Two, input_hash: hash.clone(), expected: "281".into() },
+            Answer { day: 25, part: Part::Two, input_hash: hash.clone(), expected: "-".into() },
+        ],
+        answers
+    );
+    assert_eq!(format!("1 2 {hash} 281"), answers[0].to_line());
+    assert_eq!(Some(&answers[1]), find(&answers, 25, Part::Two, &hash));
+    assert_eq!(None, find(&answers, 25, Part::One, &hash));
+
+    assert_eq!(Err(ParseError::at(0, 0, "26", "a day from 1 to 25")), parse_answers(&format!("26 1 {hash} 5")));
+    assert_eq!(Err(ParseError::at(0, 2, "3", "`1` or `2`")), parse_answers(&format!("1 3 {hash} 5")));
+    assert_eq!(Err(ParseError::at(0, 4, "abc", "a SHA-256 hash")), parse_answers("1 1 abc 5"));
+    assert_eq!(Err(ParseError::at(0, 68, "", "the expected answer")), parse_answers(&format!("1 1 {hash}")));
+}
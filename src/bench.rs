@@ -0,0 +1,178 @@
+//! Timing the parser and each part of a solution
+//!
+//! Parsing and solving are timed separately so that a slow parser doesn't hide in the numbers for
+//! the parts (or the other way around)
+
+use std::fmt::{Display, Formatter};
+use std::hint::black_box;
+use std::panic;
+use std::panic::AssertUnwindSafe;
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+
+use crate::parse::ParseError;
+use crate::{Part, Solution};
+
+/// One of the steps of a solution which gets timed
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Step {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl From<Part> for Step {
+    fn from(value: Part) -> Self {
+        match value {
+            Part::One => Step::Part1,
+            Part::Two => Step::Part2,
+        }
+    }
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Step::Parse => write!(f, "parse"),
+            Step::Part1 => write!(f, "part1"),
+            Step::Part2 => write!(f, "part2"),
+        }
+    }
+}
+
+/// The time taken by every iteration of a step
+/// This is None for a part which panicked (it is only run once in that case)
+pub type Samples = Vec<(Step, Option<Vec<Duration>>)>;
+
+/// Time parsing the input and then solving each of the requested parts `iterations` times
+pub(crate) fn time<S: Solution>(input: &str, parts: &[Part], iterations: usize) -> Result<Samples, ParseError> {
+    let mut parse_times = Vec::with_capacity(iterations);
+    let mut parsed = None;
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let result = black_box(S::parse(black_box(input)))?;
+        parse_times.push(start.elapsed());
+        // The previous result is dropped here so that it isn't part of the timing
+        parsed = Some(result);
+    }
+
+    let mut samples = vec![(Step::Parse, Some(parse_times))];
+    let Some(parsed) = parsed else { return Ok(samples); };
+    for &part in parts {
+        let mut part_times = Some(Vec::with_capacity(iterations));
+        for _ in 0..iterations {
+            let start = Instant::now();
+            let solved = panic::catch_unwind(AssertUnwindSafe(|| match part {
+                Part::One => { black_box(S::part1(black_box(&parsed))); }
+                Part::Two => { black_box(S::part2(black_box(&parsed))); }
+            }));
+            let elapsed = start.elapsed();
+            match (solved, &mut part_times) {
+                (Ok(_), Some(times)) => times.push(elapsed),
+                _ => {
+                    // No point in timing something which doesn't work
+                    part_times = None;
+                    break;
+                }
+            }
+        }
+        samples.push((part.into(), part_times));
+    }
+    Ok(samples)
+}
+
+/// A summary of the time taken by many iterations of a step
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Summary {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Summary {
+    /// Summarize the times taken by a step
+    /// Returns None if there are no times
+    pub fn of(times: &[Duration]) -> Option<Self> {
+        let mut times = times.to_vec();
+        times.sort();
+        Some(Summary {
+            min: *times.first()?,
+            median: percentile(&times, 50),
+            p95: percentile(&times, 95),
+        })
+    }
+}
+
+/// Get a percentile of some sorted times using the nearest-rank method
+fn percentile(sorted: &[Duration], percent: usize) -> Duration {
+    let rank = (sorted.len() * percent).div_ceil(100);
+    sorted[rank.saturating_sub(1)]
+}
+
+/// A row of a benchmark report
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+pub struct Record {
+    pub day: u8,
+    pub title: &'static str,
+    pub step: Step,
+    pub iterations: usize,
+    pub min_ns: u128,
+    pub median_ns: u128,
+    pub p95_ns: u128,
+}
+
+impl Record {
+    /// Create a row of the report for a step
+    pub fn new(day: u8, title: &'static str, step: Step, iterations: usize, summary: Summary) -> Self {
+        Record {
+            day,
+            title,
+            step,
+            iterations,
+            min_ns: summary.min.as_nanos(),
+            median_ns: summary.median.as_nanos(),
+            p95_ns: summary.p95.as_nanos(),
+        }
+    }
+
+    /// The header for the CSV form of the report
+    pub const CSV_HEADER: &'static str = "day,title,step,iterations,min_ns,median_ns,p95_ns";
+
+    /// Get this record as a line of CSV
+    pub fn to_csv(&self) -> String {
+        // None of the titles contain a double quote so quoting them is enough
+        format!(
+            "{},\"{}\",{},{},{},{},{}",
+            self.day, self.title, self.step, self.iterations, self.min_ns, self.median_ns, self.p95_ns,
+        )
+    }
+}
+
+#[test]
+fn test_summary() {
+    let times = (1..=20).rev().map(Duration::from_millis).collect::<Vec<_>>();
+    assert_eq!(
+        Some(Summary {
+            min: Duration::from_millis(1),
+            median: Duration::from_millis(10),
+            p95: Duration::from_millis(19),
+        }),
+        Summary::of(&times)
+    );
+    assert_eq!(None, Summary::of(&[]));
+
+    let single = Summary::of(&[Duration::from_millis(5)]).unwrap();
+    assert_eq!(Duration::from_millis(5), single.median);
+    assert_eq!(Duration::from_millis(5), single.p95);
+}
+
+#[test]
+fn test_record_csv() {
+    let summary = Summary::of(&[Duration::from_nanos(1500), Duration::from_nanos(2500)]).unwrap();
+    assert_eq!(
+        "1,\"Trebuchet?!\",part2,2,1500,1500,2500",
+        Record::new(1, "Trebuchet?!", Step::Part2, 2, summary).to_csv()
+    );
+}
@@ -0,0 +1,580 @@
+use std::fs;
+use std::io::{self, Read, Write};
+use std::num::NonZeroUsize;
+use std::process::ExitCode;
+use std::thread;
+use std::time::Duration;
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+use aoc::answers::{self, Answer};
+use aoc::bench::{Record, Step, Summary};
+use aoc::days::day17_crucible::{self, StraightRun};
+use aoc::days::day18_lavaduct::{self, Instruction};
+use aoc::days::day23_long;
+use aoc::days::day25_snowverland;
+use aoc::parse::ParseError;
+use aoc::{days, random, Day, Part};
+
+/// Exit code bit set when part 1 of a puzzle couldn't be solved
+const PART_1_FAILED: u8 = 1;
+/// Exit code bit set when part 2 of a puzzle couldn't be solved
+const PART_2_FAILED: u8 = 2;
+/// Exit code when the command line arguments are invalid
+const USAGE_ERROR: u8 = 64;
+/// Exit code when the registry of answers is malformed
+const DATA_ERROR: u8 = 65;
+/// Exit code when a puzzle input couldn't be read
+const INPUT_ERROR: u8 = 66;
+/// Exit code when the report couldn't be written
+const OUTPUT_ERROR: u8 = 73;
+
+/// My solutions to Advent of Code 2023
+#[derive(Debug, Parser)]
+#[command(name = "aoc")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// The seed for the randomized algorithms (defaults to `AOC_SEED` or else a fresh one, which is logged)
+    #[arg(long, global = true)]
+    seed: Option<u64>,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Solve a day (or every day) and print the answers
+    Run(Selection),
+    /// Time parsing and each part of a day (or every day)
+    Bench(BenchArgs),
+    /// Check the answers for a day (or every day) against the registry of known-good answers
+    Verify(VerifyArgs),
+    /// Draw a picture of how a part of a day was solved
+    Render(RenderArgs),
+    /// Find the wires to cut to split the day 25 wiring diagram into pieces
+    Wires(WiresArgs),
+}
+
+/// The days and parts to work on
+#[derive(Debug, Args)]
+struct Selection {
+    /// The day to solve
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25), required_unless_present = "all")]
+    day: Option<u8>,
+    /// Only solve this part (both parts are solved by default)
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// The puzzle input to use or `-` to read it from stdin (defaults to my input for the day)
+    #[arg(long, conflicts_with = "all")]
+    input: Option<String>,
+    /// Solve every day on my inputs
+    #[arg(long, conflicts_with = "day")]
+    all: bool,
+}
+
+impl Selection {
+    /// The parts which were selected
+    fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(1) => vec![Part::One],
+            Some(_) => vec![Part::Two],
+            None => Part::BOTH.to_vec(),
+        }
+    }
+
+    /// The days which were selected
+    fn days(&self) -> Vec<&'static Day> {
+        match self.day {
+            Some(day) => days::get(day).into_iter().collect(),
+            None => days::ALL.iter().collect(),
+        }
+    }
+
+    /// Read the input for a selected day (or report why it couldn't be read)
+    fn read_input(&self, day: &Day) -> Result<String, ExitCode> {
+        read_input(day, self.input.as_deref()).map_err(|error| {
+            eprintln!("Unable to read the input for day {}: {error}", day.day);
+            ExitCode::from(INPUT_ERROR)
+        })
+    }
+}
+
+#[derive(Debug, Args)]
+struct BenchArgs {
+    #[command(flatten)]
+    selection: Selection,
+    /// How many times to run each step
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    iterations: u32,
+    /// How to format the report
+    #[arg(long, value_enum, default_value_t = Format::Table)]
+    format: Format,
+    /// Write the report to this file instead of stdout
+    #[arg(long)]
+    output: Option<String>,
+}
+
+#[derive(Debug, Args)]
+struct VerifyArgs {
+    #[command(flatten)]
+    selection: Selection,
+    /// The registry of known-good answers (defaults to the one in this repository)
+    #[arg(long)]
+    answers: Option<String>,
+    /// Add the answers for inputs which aren't in the registry yet
+    #[arg(long)]
+    record: bool,
+}
+
+#[derive(Debug, Args)]
+struct RenderArgs {
+    /// The day to draw (only days 17, 18, and 23 can be drawn so far)
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+    /// The part whose solution is drawn
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+    /// The puzzle input to use or `-` to read it from stdin (defaults to my input for the day)
+    #[arg(long)]
+    input: Option<String>,
+    /// How to draw the picture
+    #[arg(long, value_enum, default_value_t = Picture::Ascii)]
+    format: Picture,
+    /// Write the picture to this file instead of stdout
+    #[arg(long)]
+    output: Option<String>,
+    /// Leave out the colors from the input (for day 18)
+    #[arg(long)]
+    plain: bool,
+}
+
+#[derive(Debug, Args)]
+struct WiresArgs {
+    /// The wiring diagram to use or `-` to read it from stdin (defaults to my input for day 25)
+    #[arg(long)]
+    input: Option<String>,
+    /// How many pieces to split the diagram into
+    #[arg(long, default_value_t = 2, value_parser = clap::value_parser!(u32).range(1..))]
+    pieces: u32,
+    /// The most wires which can be cut
+    #[arg(long, default_value_t = 3)]
+    max_cut: usize,
+    /// How to find the wires to cut
+    #[arg(long, value_enum, default_value_t = CutAlgorithm::StoerWagner)]
+    algorithm: CutAlgorithm,
+    /// How many times to repeat Karger's algorithm for each cut
+    #[arg(long, default_value_t = 100, value_parser = clap::value_parser!(u32).range(1..))]
+    attempts: u32,
+}
+
+/// The ways to find the minimum cut of a wiring diagram
+#[derive(Debug, Copy, Clone, Eq, PartialEq, ValueEnum)]
+enum CutAlgorithm {
+    /// Always finds a minimum cut
+    StoerWagner,
+    /// Randomized, so it might not find a minimum cut (see `--seed`)
+    Karger,
+}
+
+/// The formats a picture can be drawn in
+#[derive(Debug, Copy, Clone, Eq, PartialEq, ValueEnum)]
+enum Picture {
+    /// Text for a terminal
+    Ascii,
+    Svg,
+    Png,
+}
+
+/// The formats a benchmark report can be written in
+#[derive(Debug, Copy, Clone, Eq, PartialEq, ValueEnum)]
+enum Format {
+    /// A table for people to read
+    Table,
+    Json,
+    Csv,
+}
+
+fn main() -> ExitCode {
+    let cli = match Cli::try_parse() {
+        Ok(cli) => cli,
+        Err(error) if error.use_stderr() => {
+            let _ = error.print();
+            return ExitCode::from(USAGE_ERROR);
+        }
+        Err(error) => error.exit(), // --help and --version
+    };
+    if let Some(seed) = cli.seed {
+        random::set_seed(seed);
+    }
+
+    match cli.command {
+        Command::Run(args) => run(args),
+        Command::Bench(args) => bench(args),
+        Command::Verify(args) => verify(args),
+        Command::Render(args) => render(args),
+        Command::Wires(args) => wires(args),
+    }
+}
+
+/// Read the puzzle input from a path, from stdin if the path is `-`, or my input for the day
+fn read_input(day: &Day, input: Option<&str>) -> io::Result<String> {
+    match input {
+        Some("-") => {
+            let mut buffer = String::new();
+            io::stdin().read_to_string(&mut buffer)?;
+            Ok(buffer)
+        }
+        Some(path) => fs::read_to_string(path),
+        None => fs::read_to_string(day.input_path()),
+    }
+}
+
+/// Write a report (or picture) to a file or to stdout if there isn't one
+fn write_output(output: Option<&str>, report: impl AsRef<[u8]>) -> Result<(), ExitCode> {
+    match output {
+        Some(path) => fs::write(path, report).map_err(|error| {
+            eprintln!("Unable to write to {path}: {error}");
+            ExitCode::from(OUTPUT_ERROR)
+        }),
+        None => io::stdout().write_all(report.as_ref()).map_err(|error| {
+            eprintln!("Unable to write to stdout: {error}");
+            ExitCode::from(OUTPUT_ERROR)
+        }),
+    }
+}
+
+/// The exit code bit for a part which failed
+fn failed(part: Part) -> u8 {
+    match part {
+        Part::One => PART_1_FAILED,
+        Part::Two => PART_2_FAILED,
+    }
+}
+
+/// Handle the run command
+/// The exit code has a bit set for each part which failed on any of the days
+fn run(args: Selection) -> ExitCode {
+    let parts = args.parts();
+    let selected = args.days();
+    // Only label the answers when there is more than one of them
+    let labelled = selected.len() * parts.len() > 1;
+
+    let mut exit_code = 0;
+    for day in selected {
+        let input = match args.read_input(day) {
+            Ok(input) => input,
+            Err(exit_code) => return exit_code,
+        };
+
+        let answers = day.solve(&input, &parts).unwrap_or_else(|error| {
+            eprintln!("Unable to parse the input for day {}: {error}", day.day);
+            vec![None; parts.len()]
+        });
+        for (part, answer) in parts.iter().zip(answers) {
+            if answer.is_none() {
+                exit_code |= failed(*part);
+            }
+            match (labelled, answer) {
+                (false, Some(answer)) => println!("{answer}"),
+                (false, None) => {}
+                (true, answer) => println!(
+                    "Day {} part {part}: {}",
+                    day.day,
+                    answer.as_deref().unwrap_or("failed"),
+                ),
+            }
+        }
+    }
+
+    ExitCode::from(exit_code)
+}
+
+/// Handle the bench command
+/// Like the run command, the exit code has a bit set for each part which failed
+fn bench(args: BenchArgs) -> ExitCode {
+    let parts = args.selection.parts();
+    let iterations = args.iterations as usize;
+
+    let mut exit_code = 0;
+    let mut records = vec![];
+    for day in args.selection.days() {
+        let input = match args.selection.read_input(day) {
+            Ok(input) => input,
+            Err(exit_code) => return exit_code,
+        };
+
+        let samples = match day.time(&input, &parts, iterations) {
+            Ok(samples) => samples,
+            Err(error) => {
+                eprintln!("Unable to parse the input for day {}: {error}", day.day);
+                for &part in &parts {
+                    exit_code |= failed(part);
+                }
+                continue;
+            }
+        };
+        for (step, times) in samples {
+            match times.as_deref().and_then(Summary::of) {
+                Some(summary) => records.push(Record::new(day.day, day.title, step, iterations, summary)),
+                None => {
+                    eprintln!("Day {} {step} failed", day.day);
+                    exit_code |= match step {
+                        Step::Parse => 0, // Parsing either succeeds or the day is skipped
+                        Step::Part1 => PART_1_FAILED,
+                        Step::Part2 => PART_2_FAILED,
+                    };
+                }
+            }
+        }
+    }
+
+    let report = match args.format {
+        Format::Table => table(&records),
+        Format::Json => serde_json::to_string_pretty(&records).expect("Records can always be serialized") + "\n",
+        Format::Csv => std::iter::once(Record::CSV_HEADER.to_string())
+            .chain(records.iter().map(Record::to_csv))
+            .map(|line| line + "\n")
+            .collect(),
+    };
+    match write_output(args.output.as_deref(), &report) {
+        Ok(()) => ExitCode::from(exit_code),
+        Err(exit_code) => exit_code,
+    }
+}
+
+/// Format the records as a table for people to read
+fn table(records: &[Record]) -> String {
+    let mut table = format!("{:>3}  {:<5}  {:>12}  {:>12}  {:>12}\n", "Day", "Step", "Min", "Median", "P95");
+    for record in records {
+        let duration = |nanos: u128| format!("{:.2?}", Duration::from_nanos(nanos as u64));
+        table += &format!(
+            "{:>3}  {:<5}  {:>12}  {:>12}  {:>12}\n",
+            record.day,
+            record.step.to_string(),
+            duration(record.min_ns),
+            duration(record.median_ns),
+            duration(record.p95_ns),
+        );
+    }
+    table
+}
+
+/// Handle the verify command
+/// Like the run command, the exit code has a bit set for each part which failed (or was wrong)
+fn verify(args: VerifyArgs) -> ExitCode {
+    let path = args.answers.unwrap_or_else(|| concat!(env!("CARGO_MANIFEST_DIR"), "/answers.txt").to_string());
+    let registry = match fs::read_to_string(&path) {
+        Ok(registry) => registry,
+        Err(error) if error.kind() == io::ErrorKind::NotFound && args.record => String::new(),
+        Err(error) => {
+            eprintln!("Unable to read the answers from {path}: {error}");
+            return ExitCode::from(INPUT_ERROR);
+        }
+    };
+    let known = match answers::parse_answers(&registry) {
+        Ok(known) => known,
+        Err(error) => {
+            eprintln!("Unable to parse the answers in {path}: {error}");
+            return ExitCode::from(DATA_ERROR);
+        }
+    };
+
+    let parts = args.selection.parts();
+    let (mut passed, mut wrong, mut unknown) = (0, 0, 0);
+    let mut recorded = vec![];
+    let mut exit_code = 0;
+    for day in args.selection.days() {
+        let input = match args.selection.read_input(day) {
+            Ok(input) => input,
+            Err(exit_code) => return exit_code,
+        };
+        let input_hash = answers::hash_input(&input);
+
+        let actual = day.solve(&input, &parts).unwrap_or_else(|error| {
+            eprintln!("Unable to parse the input for day {}: {error}", day.day);
+            vec![None; parts.len()]
+        });
+        for (&part, actual) in parts.iter().zip(actual) {
+            let label = format!("Day {} part {part}", day.day);
+            match (answers::find(&known, day.day, part, &input_hash), actual) {
+                (Some(answer), Some(actual)) if answer.expected == actual => {
+                    passed += 1;
+                    println!("{label}: pass");
+                }
+                (Some(answer), actual) => {
+                    wrong += 1;
+                    exit_code |= failed(part);
+                    println!("{label}: FAIL");
+                    println!("  expected: {}", answer.expected);
+                    println!("  actual:   {}", actual.as_deref().unwrap_or("(failed)"));
+                }
+                (None, Some(actual)) if args.record => {
+                    println!("{label}: recorded {actual}");
+                    recorded.push(Answer { day: day.day, part, input_hash: input_hash.clone(), expected: actual });
+                }
+                (None, _) => {
+                    unknown += 1;
+                    println!("{label}: unknown (no answer for the input with hash {})", &input_hash[..12]);
+                }
+            }
+        }
+    }
+    println!("{passed} passed, {wrong} failed, {unknown} unknown");
+
+    if !recorded.is_empty() {
+        let mut registry = registry;
+        if registry.is_empty() {
+            registry += "# day part sha256-of-input expected-answer\n";
+        } else if !registry.ends_with('\n') {
+            registry += "\n";
+        }
+        for answer in &recorded {
+            registry += &answer.to_line();
+            registry += "\n";
+        }
+        if let Err(error) = fs::write(&path, registry) {
+            eprintln!("Unable to write the answers to {path}: {error}");
+            return ExitCode::from(OUTPUT_ERROR);
+        }
+    }
+
+    ExitCode::from(exit_code)
+}
+
+/// Handle the render command
+/// Like the run command, the exit code has the bit set for the part if it couldn't be solved
+fn render(args: RenderArgs) -> ExitCode {
+    let Some(day) = days::get(args.day) else { return ExitCode::from(USAGE_ERROR); };
+    let part = if args.part == 1 { Part::One } else { Part::Two };
+    let input = match read_input(day, args.input.as_deref()) {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("Unable to read the input for day {}: {error}", day.day);
+            return ExitCode::from(INPUT_ERROR);
+        }
+    };
+
+    let picture = match (day.day, args.format) {
+        (17, Picture::Png) => {
+            eprintln!("Day 17 can't be drawn as a PNG");
+            return ExitCode::from(USAGE_ERROR);
+        }
+        (17, format) => render_crucible(&input, part, format),
+        (18, format) => render_lagoon(&input, part, format, !args.plain),
+        (23, Picture::Ascii) => render_hike(&input, part),
+        (23, _) => {
+            eprintln!("Day 23 can only be drawn as text");
+            return ExitCode::from(USAGE_ERROR);
+        }
+        _ => {
+            eprintln!("Day {} can't be drawn", day.day);
+            return ExitCode::from(USAGE_ERROR);
+        }
+    };
+    let picture = match picture {
+        Ok(Some(picture)) => picture,
+        Ok(None) => {
+            eprintln!("Day {} part {part} has no solution to draw", day.day);
+            return ExitCode::from(failed(part));
+        }
+        Err(error) => {
+            eprintln!("Unable to parse the input for day {}: {error}", day.day);
+            return ExitCode::from(failed(part));
+        }
+    };
+    match write_output(args.output.as_deref(), &picture) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(exit_code) => exit_code,
+    }
+}
+
+/// Draw the route taken by the crucible (part 1) or the ultra crucible (part 2)
+fn render_crucible(input: &str, part: Part, format: Picture) -> Result<Option<Vec<u8>>, ParseError> {
+    let heat_map = day17_crucible::preprocess(input.lines())?;
+    let rule = match part {
+        Part::One => StraightRun::NORMAL,
+        Part::Two => StraightRun::ULTRA,
+    };
+    Ok(day17_crucible::get_minimum_heat_loss_route(&heat_map, rule).map(|route| match format {
+        Picture::Ascii => day17_crucible::render_ascii(&heat_map, &route.states).into_bytes(),
+        // PNGs of the route are turned away before getting here
+        Picture::Svg | Picture::Png => day17_crucible::render_svg(&heat_map, &route.states).into_bytes(),
+    }))
+}
+
+/// Draw the lagoon dug by the plan (part 1) or the swapped plan (part 2)
+/// The part 2 lagoon is far too big to draw so its rows and columns are compressed
+fn render_lagoon(input: &str, part: Part, format: Picture, colored: bool) -> Result<Option<Vec<u8>>, ParseError> {
+    let dig_plan = day18_lavaduct::preprocess(input.lines())?;
+    let lagoon = match part {
+        Part::One => day18_lavaduct::map_lagoon(&dig_plan, false),
+        Part::Two => {
+            let dig_plan = dig_plan.into_iter().map(Instruction::swap).collect::<Vec<_>>();
+            day18_lavaduct::map_lagoon(&dig_plan, true)
+        }
+    };
+    Ok(Some(match format {
+        Picture::Ascii => day18_lavaduct::render_ascii(&lagoon).into_bytes(),
+        Picture::Svg => day18_lavaduct::render_svg(&lagoon, colored).into_bytes(),
+        Picture::Png => day18_lavaduct::render_png(&lagoon, colored),
+    }))
+}
+
+/// Draw the longest hike down the slopes (part 1) or over them (part 2)
+fn render_hike(input: &str, part: Part) -> Result<Option<Vec<u8>>, ParseError> {
+    let map = day23_long::preprocess_map(input.lines())?;
+    let hike = match part {
+        Part::One => day23_long::find_longest_hike(&map),
+        Part::Two => {
+            let threads = thread::available_parallelism().map_or(1, NonZeroUsize::get);
+            day23_long::find_longest_hike_no_slopes(&map, threads)
+        }
+    };
+    Ok(hike.map(|hike| day23_long::render_hike(&map, &hike).into_bytes()))
+}
+
+/// Handle the wires command
+/// The exit code has the part 1 bit set if the diagram can't be split by cutting few enough wires
+fn wires(args: WiresArgs) -> ExitCode {
+    let Some(day) = days::get(25) else { return ExitCode::from(USAGE_ERROR); };
+    let input = match read_input(day, args.input.as_deref()) {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("Unable to read the input for day {}: {error}", day.day);
+            return ExitCode::from(INPUT_ERROR);
+        }
+    };
+    let graph = match day25_snowverland::preprocess(input.lines()) {
+        Ok(graph) => graph,
+        Err(error) => {
+            eprintln!("Unable to parse the input for day {}: {error}", day.day);
+            return ExitCode::from(PART_1_FAILED);
+        }
+    };
+
+    let pieces = args.pieces as usize;
+    let split = match args.algorithm {
+        CutAlgorithm::StoerWagner => day25_snowverland::split_wiring(&graph, pieces, args.max_cut),
+        CutAlgorithm::Karger => {
+            let mut random = random::generator();
+            day25_snowverland::split_wiring_with(&graph, pieces, args.max_cut, |graph| {
+                day25_snowverland::karger_minimum_cut(graph, args.attempts as usize, &mut random)
+            })
+        }
+    };
+    let Some(split) = split else {
+        eprintln!("Unable to split the wiring diagram into {pieces} pieces by cutting at most {} wires", args.max_cut);
+        return ExitCode::from(PART_1_FAILED);
+    };
+
+    let mut report = String::new();
+    for wire in &split.wires {
+        report += &format!("Cut {wire}\n");
+    }
+    for component in &split.components {
+        report += &format!("{} components: {}\n", component.len(), component.join(", "));
+    }
+    match write_output(None, report) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(exit_code) => exit_code,
+    }
+}
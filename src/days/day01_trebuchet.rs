@@ -54,16 +54,36 @@ last digit on each line. For example:
 
  */
 
-use std::fs;
+use crate::parse::ParseError;
+use crate::Solution;
 
-fn main() {
-    let calibration_file = fs::read_to_string("./inputs/01_trebuchet.txt").unwrap();
-    let calibration_document = calibration_file.lines();
-    println!("{}", get_calibration_sum_part1(calibration_document.clone()));
-    println!("{}", get_calibration_sum_part2(calibration_document));
+/// Day 1: Trebuchet?!
+pub struct Trebuchet;
+
+impl Solution for Trebuchet {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Trebuchet?!";
+    const INPUT: &'static str = "./inputs/01_trebuchet.txt";
+
+    type Input<'a> = Vec<&'a str>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        // Any line is a valid (if not very useful) calibration value
+        Ok(input.lines().collect())
+    }
+
+    fn part1(calibration_document: &Self::Input<'_>) -> usize {
+        get_calibration_sum_part1(calibration_document.iter().copied())
+    }
+
+    fn part2(calibration_document: &Self::Input<'_>) -> usize {
+        get_calibration_sum_part2(calibration_document.iter().copied())
+    }
 }
 
-fn get_calibration_sum_part1<'a>(calibration_document: impl Iterator<Item=&'a str>) -> usize {
+pub fn get_calibration_sum_part1<'a>(calibration_document: impl Iterator<Item=&'a str>) -> usize {
     // Part 1 solution: use a simple is_numeric to find the first numeric character (and similarly for the last character)
     calibration_document.map(|line| {
         let first_number = line.chars().find(|x| x.is_numeric())
@@ -77,7 +97,7 @@ fn get_calibration_sum_part1<'a>(calibration_document: impl Iterator<Item=&'a st
 // Set of numbers used to map for the part 2
 const NUMBERS: [&str; 9] = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
 
-fn get_calibration_sum_part2<'a>(calibration_document: impl Iterator<Item=&'a str>) -> usize {
+pub fn get_calibration_sum_part2<'a>(calibration_document: impl Iterator<Item=&'a str>) -> usize {
     // Part 2 solution: use a more complex function to determine what the "numeric value" of a given character is
     // This is run both forwards and backwards on the line to get the first and last number
     calibration_document.map(|line| {
@@ -89,99 +89,103 @@ What is the sum of the power of these sets?
  */
 
 use std::cmp::max;
-use std::fs;
-use std::str::FromStr;
-
-fn main() {
-    let file = fs::read_to_string("./inputs/02_cube_conundrum.txt").unwrap();
-    let games = file.lines();
-    println!("{}", get_possible_id_sum(games.clone()));
-    println!("{}", get_total_game_power(games));
+
+use crate::parse::{self, ParseError};
+use crate::Solution;
+
+/// Day 2: Cube Conundrum
+pub struct CubeConundrum;
+
+impl Solution for CubeConundrum {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Cube Conundrum";
+    const INPUT: &'static str = "./inputs/02_cube_conundrum.txt";
+
+    type Input<'a> = Vec<Game>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        preprocess(input.lines())
+    }
+
+    fn part1(games: &Self::Input<'_>) -> usize {
+        get_possible_id_sum(games)
+    }
+
+    fn part2(games: &Self::Input<'_>) -> usize {
+        get_total_game_power(games)
+    }
 }
 
+#[derive(Debug, Clone, Eq, PartialEq)]
 struct CubeCount {
     red: usize,
     green: usize,
     blue: usize,
 }
 
-/// Solve part1
-fn get_possible_id_sum<'a>(games: impl Iterator<Item=&'a str>) -> usize {
-    // Noticed that in the format after each number which might be interesting is immediately (excluding whitespace)
-    // followed by a character that tells us how to process it
-    // An additional thing to note is that we only need to know the maximum number of each color and
+/// A single game and the most cubes of each color which were drawn at once
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Game {
+    id: usize,
+    max_cube_count: CubeCount,
+}
+
+/// Preprocess the input into a more useful form
+pub fn preprocess<'a>(games: impl Iterator<Item=&'a str>) -> Result<Vec<Game>, ParseError> {
+    // Note that we only need to know the maximum number of each color and
     // not how that applies to the individual drawings
-    // This means that for each row we can ignore whitespace, accumulate adjacent digits, and
-    // perform an action when we see another character depending on the character
-    const LIMIT: CubeCount = CubeCount {
-        red: 12,
-        green: 13,
-        blue: 14,
-    };
-    games.map(|game| {
-        let chars = game.chars();
-        let mut number_accumulator = String::new();
-        let mut game_number = 0;
+    // This means that we can treat the `;` between drawings the same as the `,` within them
+    parse::lines(games).map(|line| {
+        let (game, drawings) = line.split_once(line.text, ":")?;
+        let id = game.strip_prefix("Game ")
+            .ok_or_else(|| line.error(game, "`Game <id>`"))
+            .and_then(|id| line.parse(id, "a game id"))?;
+
         let mut max_cube_count = CubeCount {
             red: 0,
             green: 0,
             blue: 0,
         };
-        for char in chars {
-            if char.is_numeric() {
-                number_accumulator.push(char);
-            } else if char.is_whitespace() {
-                // Intentionally left blank
-            } else {
-                match char {
-                    ':' => game_number = usize::from_str(&number_accumulator).unwrap_or(0),
-                    'r' => max_cube_count.red = max(max_cube_count.red, usize::from_str(&number_accumulator).unwrap_or(0)),
-                    'g' => max_cube_count.green = max(max_cube_count.green, usize::from_str(&number_accumulator).unwrap_or(0)),
-                    'b' => max_cube_count.blue = max(max_cube_count.blue, usize::from_str(&number_accumulator).unwrap_or(0)),
-                    _ => {}
-                }
-                number_accumulator.clear();
-            }
+        for cubes in drawings.split([';', ',']) {
+            let (count, color) = line.split_once(cubes.trim(), " ")?;
+            let count = line.parse(count, "a number of cubes")?;
+            let max_count = match color {
+                "red" => &mut max_cube_count.red,
+                "green" => &mut max_cube_count.green,
+                "blue" => &mut max_cube_count.blue,
+                _ => return Err(line.error(color, "`red`, `green`, or `blue`")),
+            };
+            *max_count = max(*max_count, count);
         }
-        if max_cube_count.red <= LIMIT.red &&
-            max_cube_count.green <= LIMIT.green &&
-            max_cube_count.blue <= LIMIT.blue {
-            game_number
-        } else {
-            0
-        }
-    }).sum()
+
+        Ok(Game { id, max_cube_count })
+    }).collect()
+}
+
+/// Solve part1
+pub fn get_possible_id_sum(games: &[Game]) -> usize {
+    const LIMIT: CubeCount = CubeCount {
+        red: 12,
+        green: 13,
+        blue: 14,
+    };
+
+    games.iter()
+        .filter(|game| game.max_cube_count.red <= LIMIT.red &&
+            game.max_cube_count.green <= LIMIT.green &&
+            game.max_cube_count.blue <= LIMIT.blue)
+        .map(|game| game.id)
+        .sum()
 }
 
 /// Solve part2
-fn get_total_game_power<'a>(games: impl Iterator<Item=&'a str>) -> usize {
-    // This is a simplified version of what we had above since we no longer care
-    // about the game count nor do we have a limit.
-    games.map(|game| {
-        let chars = game.chars();
-        let mut number_accumulator = String::new();
-        let mut max_cube_count = CubeCount {
-            red: 0,
-            green: 0,
-            blue: 0,
-        };
-        for char in chars {
-            if char.is_numeric() {
-                number_accumulator.push(char);
-            } else if char.is_whitespace() {
-                // Intentionally left blank
-            } else {
-                match char {
-                    'r' => max_cube_count.red = max(max_cube_count.red, usize::from_str(&number_accumulator).unwrap_or(0)),
-                    'g' => max_cube_count.green = max(max_cube_count.green, usize::from_str(&number_accumulator).unwrap_or(0)),
-                    'b' => max_cube_count.blue = max(max_cube_count.blue, usize::from_str(&number_accumulator).unwrap_or(0)),
-                    _ => {}
-                }
-                number_accumulator.clear();
-            }
-        }
-        max_cube_count.red * max_cube_count.green * max_cube_count.blue
-    }).sum()
+pub fn get_total_game_power(games: &[Game]) -> usize {
+    // The fewest cubes needed for each color is the maximum we saw drawn at once
+    games.iter()
+        .map(|game| game.max_cube_count.red * game.max_cube_count.green * game.max_cube_count.blue)
+        .sum()
 }
 
 #[test]
@@ -189,11 +193,11 @@ fn test_part1() {
     assert_eq!(
         8,
         get_possible_id_sum(
-            r"Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+            &preprocess(r"Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
 Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
 Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
 Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
-Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green".lines()
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green".lines()).unwrap()
         )
     )
 }
@@ -203,12 +207,23 @@ fn test_part2() {
     assert_eq!(
         2286,
         get_total_game_power(
-            r"Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+            &preprocess(r"Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
 Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
 Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
 Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
-Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green".lines()
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green".lines()).unwrap()
         )
     )
 }
 
+
+#[test]
+fn test_parse_error() {
+    assert_eq!(
+        Err(ParseError::at(1, 18, "purple", "`red`, `green`, or `blue`")),
+        preprocess(
+            r"Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 purple; 3 green, 4 blue, 1 red; 1 green, 1 blue".lines()
+        )
+    )
+}
@@ -88,112 +88,132 @@ What is the sum of all of the gear ratios in your engine schematic?
  */
 
 use std::cmp::min;
-use std::fs;
 use std::str::FromStr;
 
-fn main() {
-    let file = fs::read_to_string("./inputs/03_gear_ratios.txt").unwrap();
-    let games = file.lines();
-    println!("{}", get_part_number_sum(games.clone()));
-    println!("{}", get_gear_ratio_sum(games));
+use crate::grid::{Grid, Position};
+use crate::parse::ParseError;
+use crate::Solution;
+
+/// Day 3: Gear Ratios
+pub struct GearRatios;
+
+impl Solution for GearRatios {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Gear Ratios";
+    const INPUT: &'static str = "./inputs/03_gear_ratios.txt";
+
+    type Input<'a> = Grid<char>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        preprocess(input.lines())
+    }
+
+    fn part1(schematic: &Self::Input<'_>) -> usize {
+        get_part_number_sum(schematic)
+    }
+
+    fn part2(schematic: &Self::Input<'_>) -> usize {
+        get_gear_ratio_sum(schematic)
+    }
 }
 
-fn preprocess_schematic<'a>(schematic: impl Iterator<Item=&'a str>, width: usize, height: usize) -> (Vec<Vec<usize>>, Vec<(usize, usize)>) {
-    // The total sum of adjacent numbers for each cell
-    // Doing this preprocessing means that we can simply look up the sum for each symbol and add
-    // them all together
-    let mut result = vec![vec![0; height]; width];
-    // A list of (x, y) coordinates of all of the symbols
+/// Preprocess the input into a grid of characters
+/// Any character is allowed, but the schematic has to be rectangular
+pub fn preprocess<'a>(schematic: impl Iterator<Item=&'a str>) -> Result<Grid<char>, ParseError> {
+    Grid::parse(schematic, "any character")
+}
+
+/// Call `on_cell` for every cell in the box surrounding a number which ends just before `end`
+fn for_each_adjacent(schematic: &Grid<char>, length: usize, end: Position, mut on_cell: impl FnMut(Position)) {
+    let start_column = end.column - length;
+    for row in end.row.saturating_sub(1)..=min(end.row + 1, schematic.height() - 1) {
+        for column in start_column.saturating_sub(1)..=min(end.column, schematic.width() - 1) {
+            on_cell(Position::new(row, column));
+        }
+    }
+}
+
+/// Find each of the numbers and symbols in the schematic
+/// `on_number` is called with each number and the position just after its last digit
+fn scan(schematic: &Grid<char>, mut on_number: impl FnMut(&str, Position)) -> Vec<(Position, char)> {
+    // A list of positions of all of the symbols along with the symbol
     let mut symbols = vec![];
 
     let mut accumulator = String::new();
-    let mut add_number = |accumulator: &mut String, end_x: usize, y: usize| {
-        if !accumulator.is_empty() {
-            let start_x = end_x - accumulator.len();
-            let value = usize::from_str(&accumulator).unwrap_or(0);
-            for x in start_x.saturating_sub(1)..=min(end_x, width - 1) {
-                for y in y.saturating_sub(1)..=min(y + 1, height - 1) {
-                    result[x][y] += value;
-                }
-            }
-            accumulator.clear();
-        }
-    };
-    for (y, row) in schematic.enumerate() {
-        for (x, char) in row.char_indices() {
+    for (y, row) in schematic.rows().enumerate() {
+        for (x, &char) in row.iter().enumerate() {
             if char.is_numeric() {
                 accumulator.push(char);
             } else {
-                add_number(&mut accumulator, x, y);
+                if !accumulator.is_empty() {
+                    on_number(&accumulator, Position::new(y, x));
+                    accumulator.clear();
+                }
                 if char != '.' {
-                    symbols.push((x, y));
+                    symbols.push((Position::new(y, x), char));
                 }
             }
         }
-        add_number(&mut accumulator, width, y);
+        if !accumulator.is_empty() {
+            on_number(&accumulator, Position::new(y, schematic.width()));
+            accumulator.clear();
+        }
     }
 
+    symbols
+}
+
+fn preprocess_schematic(schematic: &Grid<char>) -> (Grid<usize>, Vec<(Position, char)>) {
+    // The total sum of adjacent numbers for each cell
+    // Doing this preprocessing means that we can simply look up the sum for each symbol and add
+    // them all together
+    let mut result = Grid::filled(schematic.width(), schematic.height(), 0);
+
+    let symbols = scan(schematic, |number, end| {
+        let value = usize::from_str(number).unwrap_or(0);
+        for_each_adjacent(schematic, number.len(), end, |position| result[position] += value);
+    });
+
     (result, symbols)
 }
 
 
-fn get_part_number_sum<'a>(schematic: impl Iterator<Item=&'a str> + Clone) -> usize {
-    let width = schematic.clone().next().map_or(0, |x| x.len());
-    let height = schematic.clone().count();
-    let (preprocessed, symbols) = preprocess_schematic(schematic, width, height);
+pub fn get_part_number_sum(schematic: &Grid<char>) -> usize {
+    let (preprocessed, symbols) = preprocess_schematic(schematic);
     symbols.iter()
-        .map(|(x, y)| preprocessed[*x][*y])
+        .map(|(position, _)| preprocessed[*position])
         .sum()
 }
 
-fn preprocess_gear<'a>(schematic: impl Iterator<Item=&'a str>, width: usize, height: usize) -> (Vec<Vec<(usize, usize)>>, Vec<(usize, usize, char)>) {
+/// The product and count of the numbers adjacent to each cell along with the position of each symbol
+type GearSchematic = (Grid<(usize, usize)>, Vec<(Position, char)>);
+
+fn preprocess_gear(schematic: &Grid<char>) -> GearSchematic {
     // The total product of adjacent numbers for each cell as well as the count of numbers
     // which were multiplied to arrive at that product
     // Doing this preprocessing means that we can simply look up the sum for each valid gear symbol
     // and add them all together
-    let mut result = vec![vec![(1, 0); height]; width];
-    // A list of (x, y, symbol) coordinate and symbol of all of the symbols
-    let mut symbols = vec![];
+    let mut result = Grid::filled(schematic.width(), schematic.height(), (1, 0));
 
-    let mut accumulator = String::new();
-    let mut add_number = |accumulator: &mut String, end_x: usize, y: usize| {
-        if !accumulator.is_empty() {
-            let start_x = end_x - accumulator.len();
-            let value = usize::from_str(&accumulator).unwrap_or(0);
-            for x in start_x.saturating_sub(1)..=min(end_x, width - 1) {
-                for y in y.saturating_sub(1)..=min(y + 1, height - 1) {
-                    result[x][y].0 *= value;
-                    result[x][y].1 += 1;
-                }
-            }
-            accumulator.clear();
-        }
-    };
-    for (y, row) in schematic.enumerate() {
-        for (x, char) in row.char_indices() {
-            if char.is_numeric() {
-                accumulator.push(char);
-            } else {
-                add_number(&mut accumulator, x, y);
-                if char != '.' {
-                    symbols.push((x, y, char));
-                }
-            }
-        }
-        add_number(&mut accumulator, width, y);
-    }
+    let symbols = scan(schematic, |number, end| {
+        let value = usize::from_str(number).unwrap_or(0);
+        for_each_adjacent(schematic, number.len(), end, |position| {
+            result[position].0 *= value;
+            result[position].1 += 1;
+        });
+    });
 
     (result, symbols)
 }
 
-fn get_gear_ratio_sum<'a>(schematic: impl Iterator<Item=&'a str> + Clone) -> usize {
-    let width = schematic.clone().next().map_or(0, |x| x.len());
-    let height = schematic.clone().count();
-    let (preprocessed, symbols) = preprocess_gear(schematic, width, height);
+pub fn get_gear_ratio_sum(schematic: &Grid<char>) -> usize {
+    let (preprocessed, symbols) = preprocess_gear(schematic);
     symbols.iter()
-        .filter(|(_, _, symbol)| *symbol == '*')
-        .filter(|(x, y, _)| preprocessed[*x][*y].1 == 2)
-        .map(|(x, y, _)| preprocessed[*x][*y].0)
+        .filter(|(_, symbol)| *symbol == '*')
+        .filter(|(position, _)| preprocessed[*position].1 == 2)
+        .map(|(position, _)| preprocessed[*position].0)
         .sum()
 }
 
@@ -202,7 +222,7 @@ fn test_part1() {
     assert_eq!(
         4361,
         get_part_number_sum(
-            r"467..114..
+            &preprocess(r"467..114..
 ...*......
 ..35..633.
 ......#...
@@ -211,7 +231,7 @@ fn test_part1() {
 ..592.....
 ......755.
 ...$.*....
-.664.598..".lines()
+.664.598..".lines()).unwrap()
         )
     )
 }
@@ -221,7 +241,7 @@ fn test_part2() {
     assert_eq!(
         467835,
         get_gear_ratio_sum(
-            r"467..114..
+            &preprocess(r"467..114..
 ...*......
 ..35..633.
 ......#...
@@ -230,7 +250,7 @@ fn test_part2() {
 ..592.....
 ......755.
 ...$.*....
-.664.598..".lines()
+.664.598..".lines()).unwrap()
         )
     )
 }
@@ -116,69 +116,81 @@ scratchcards are won. Including the original set of scratchcards, how many
 total scratchcards do you end up with?
  */
 
-use std::fs;
-use std::str::FromStr;
-
-fn main() {
-    let file = fs::read_to_string("./inputs/04_scratchcards.txt").unwrap();
-    let cards = file.lines();
-    println!("{}", get_total_point_value(cards.clone()));
-    println!("{}", get_total_copies(cards));
+use crate::parse::{self, Line, ParseError};
+use crate::Solution;
+
+/// Day 4: Scratchcards
+pub struct Scratchcards;
+
+impl Solution for Scratchcards {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Scratchcards";
+    const INPUT: &'static str = "./inputs/04_scratchcards.txt";
+
+    type Input<'a> = Vec<usize>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        preprocess(input.lines())
+    }
+
+    fn part1(card_matches: &Self::Input<'_>) -> usize {
+        get_total_point_value(card_matches)
+    }
+
+    fn part2(card_matches: &Self::Input<'_>) -> usize {
+        get_total_copies(card_matches)
+    }
 }
 
+/// Preprocess the input into the number of matches on each card
+pub fn preprocess<'a>(cards: impl Iterator<Item=&'a str>) -> Result<Vec<usize>, ParseError> {
+    parse::lines(cards)
+        .map(|card| determine_card_matches(&card))
+        .collect()
+}
 
 /// Called to determine the amount of matches for a given card string
 ///
 /// card : the card string
 ///
 /// return : the number of matches
-fn determine_card_matches(card: &str) -> usize {
+fn determine_card_matches(card: &Line) -> Result<usize, ParseError> {
     // Skip everything before the colon
-    let chars = card.chars().skip_while(|x| *x != ':');
+    let (_, numbers) = card.split_once(card.text, ":")?;
+    let (winning, have) = card.split_once(numbers, "|")?;
     // Could use a hashset here instead of an array, but since we only have to deal with up to
     // 100 elements an array fits on the stack and might be faster as it wouldn't require heap
-    let mut winning_numbers = [false; 100];
+    const LIMIT: usize = 100;
+    let mut winning_numbers = [false; LIMIT];
+    let parse_number = |number: &str| card.parse::<usize>(number, "a number below 100")
+        .and_then(|value| if value < LIMIT {
+            Ok(value)
+        } else {
+            Err(card.error(number, "a number below 100"))
+        });
 
-    let mut number_accumulator = String::new();
+    // Left of the pipe we set the winning numbers table
+    for number in winning.split_whitespace() {
+        winning_numbers[parse_number(number)?] = true;
+    }
 
-    let mut found_all_winning = false;
+    // Right of the pipe we check if this is a winning number and add to the match count if it is
     let mut num_matches: usize = 0;
-
-    chars.for_each(|char| {
-        if char.is_numeric() {
-            number_accumulator.push(char);
-        } else if char == '|' {
-            // Switch modes when we get to the pipe
-            found_all_winning = true;
-        } else if !number_accumulator.is_empty() {
-            let number = usize::from_str(&number_accumulator).unwrap_or(0);
-            // If we are left of the pipe we set the winning numbers table
-            // Otherwise we check if this is a winning number and add to the match count if it is
-            if found_all_winning {
-                if winning_numbers[number] {
-                    num_matches += 1;
-                }
-            } else {
-                winning_numbers[number] = true;
-            }
-            number_accumulator.clear();
+    for number in have.split_whitespace() {
+        if winning_numbers[parse_number(number)?] {
+            num_matches += 1;
         }
-    });
-    // We need to check that the last thing is one of the winning numbers just in case
-    let number = usize::from_str(&number_accumulator).unwrap_or(0);
-    if !number_accumulator.is_empty() && winning_numbers[number] {
-        num_matches += 1;
     }
 
     // Return the number of matches we found
-    num_matches
+    Ok(num_matches)
 }
 
 /// Solve part1
-fn get_total_point_value<'a>(cards: impl Iterator<Item=&'a str>) -> usize {
-    cards.map(|card| {
-        // Get the number of matches for each card
-        let num_matches = determine_card_matches(card);
+pub fn get_total_point_value(card_matches: &[usize]) -> usize {
+    card_matches.iter().map(|&num_matches| {
         // Perform the exponentiation (0 is 0, 1 is 1, 2 is 2, 3 is 4, etc.)
         if num_matches > 0 {
             1 << (num_matches - 1)
@@ -189,13 +201,11 @@ fn get_total_point_value<'a>(cards: impl Iterator<Item=&'a str>) -> usize {
 }
 
 /// Solve part2
-fn get_total_copies<'a>(cards: impl Iterator<Item=&'a str> + Clone) -> usize {
+pub fn get_total_copies(card_matches: &[usize]) -> usize {
     // Going to store the number of copies we have of each card in this vec
     // We start with only one copy of each card
-    let mut copies = vec![1; cards.clone().count()];
-    cards.enumerate().map(|(i, card)| {
-        // Get the number of matches for each card
-        let num_matches = determine_card_matches(card);
+    let mut copies = vec![1; card_matches.len()];
+    card_matches.iter().enumerate().map(|(i, &num_matches)| {
         // We win the a copy of each of the next num_matches cards for each copy of this card
         for j in (i + 1)..(i + 1 + num_matches) {
             copies[j] += copies[i];
@@ -210,12 +220,12 @@ fn test_part1() {
     assert_eq!(
         13,
         get_total_point_value(
-            r"Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+            &preprocess(r"Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
 Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
 Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
 Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
 Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
-Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11".lines()
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11".lines()).unwrap()
         )
     )
 }
@@ -225,12 +235,20 @@ fn test_part2() {
     assert_eq!(
         30,
         get_total_copies(
-            r"Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+            &preprocess(r"Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
 Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
 Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
 Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
 Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
-Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11".lines()
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11".lines()).unwrap()
         )
     )
 }
+
+#[test]
+fn test_parse_error() {
+    assert_eq!(
+        Err(ParseError::at(0, 11, "148", "a number below 100")),
+        preprocess("Card 1: 41 148 83 86 17 | 83 86  6 31 17  9 48 53".lines())
+    );
+}
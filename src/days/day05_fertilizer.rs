@@ -175,59 +175,119 @@ any of the initial seed numbers?
  */
 
 use std::cmp::{max, min};
-use std::fs;
-use std::str::FromStr;
+use std::mem;
 
-use regex::Regex;
+use crate::parse::{self, Line, ParseError};
+use crate::Solution;
 
-fn main() {
-    let file = fs::read_to_string("./inputs/05_fertilizer.txt").unwrap();
-    let almanac = file.lines();
-    println!("{}", get_lowest_location_value(almanac.clone()));
-    println!("{}", get_lowest_location_value_range(almanac));
+/// Day 5: If You Give A Seed A Fertilizer
+pub struct Fertilizer;
+
+impl Solution for Fertilizer {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "If You Give A Seed A Fertilizer";
+    const INPUT: &'static str = "./inputs/05_fertilizer.txt";
+
+    type Input<'a> = Almanac;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        preprocess(input.lines())
+    }
+
+    fn part1(almanac: &Self::Input<'_>) -> usize {
+        get_lowest_location_value(almanac)
+    }
+
+    fn part2(almanac: &Self::Input<'_>) -> usize {
+        get_lowest_location_value_range(almanac)
+    }
+}
+
+/// A single line of one of the maps in the almanac
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct Mapping {
+    destination_start: usize,
+    source_start: usize,
+    length: usize,
+}
+
+/// The seeds which need to be planted and the maps which need to be applied to them (in order)
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Almanac {
+    seeds: Vec<usize>,
+    maps: Vec<Vec<Mapping>>,
+}
+
+/// Preprocess the input into a more useful form
+/// The names of the maps are ignored as they are always listed in the order they need to be applied
+pub fn preprocess<'a>(almanac: impl Iterator<Item=&'a str>) -> Result<Almanac, ParseError> {
+    let mut lines = parse::lines(almanac);
+    let seeds_line = lines.next().unwrap_or(Line::new(0, ""));
+    let seeds = seeds_line.text.strip_prefix("seeds:")
+        .ok_or_else(|| seeds_line.error(seeds_line.text, "`seeds: <seeds>`"))?
+        .split_whitespace()
+        .map(|seed| seeds_line.parse(seed, "a seed"))
+        .collect::<Result<Vec<usize>, _>>()?;
+
+    let mut maps = vec![];
+    let mut map = vec![];
+    for line in lines {
+        // Blank lines and headers separate the maps
+        if line.text.trim().is_empty() || line.text.ends_with(" map:") {
+            if !map.is_empty() {
+                maps.push(mem::take(&mut map));
+            }
+            continue;
+        }
+        let numbers = line.text.split_whitespace()
+            .map(|number| line.parse(number, "a number"))
+            .collect::<Result<Vec<usize>, _>>()?;
+        let [destination_start, source_start, length] = numbers[..] else {
+            return Err(line.error(line.text, "a destination start, source start, and length"));
+        };
+        map.push(Mapping { destination_start, source_start, length });
+    }
+    if !map.is_empty() {
+        maps.push(map);
+    }
+
+    Ok(Almanac { seeds, maps })
 }
 
 /// Solve part1
-fn get_lowest_location_value<'a>(mut almanac: impl Iterator<Item=&'a str>) -> usize {
+pub fn get_lowest_location_value(almanac: &Almanac) -> usize {
     // I am taking advantage of the fact that we can handle each mapping row individually
     // Additionally the types of mappings (i.e. seed-to-soil) do not matter
     // I can look at each mapping and apply that mapping to each seed
     // The only thing necessary to recognize is that the mappings may overlap, so within a run
     // of mappings on adjacent lines a given seed can only be mapped once
-    // This is handled by a flag for each seed which is reset when a non-mapping line is detected
+    // This is handled by a flag for each seed which is reset at the start of each map
     // An upside to this implementation is that it is completely agnostic to how many types of mappings
     // there are
-    let map_pattern: Regex = Regex::new(r"(\d+) (\d+) (\d+)").unwrap();
-    let seeds = almanac.next().unwrap();
     // The currently mapped seeds
-    let mut seeds = seeds[7..].split(" ").map(|seed| usize::from_str(seed).unwrap()).collect::<Vec<usize>>();
-    // Can this seed currently be mapped
-    let mut can_map = vec![false; seeds.len()];
-    while let Some(map) = almanac.next() { // We will consume perform all of the mappings (in order)
-        let Some(mapping) = map_pattern.captures(map) else {
-            can_map.iter_mut().for_each(|x| *x = true); // Reset the can map to true when we fail to match
-            continue;
-        };
-        // Get the values in the mapping from the regex
-        let destination_start = usize::from_str(mapping.get(1).unwrap().as_str()).unwrap();
-        let source_start = usize::from_str(mapping.get(2).unwrap().as_str()).unwrap();
-        let length = usize::from_str(mapping.get(3).unwrap().as_str()).unwrap();
-
-        // Update all of the seeds which fall into this mapping
-        seeds.iter_mut().enumerate().for_each(|(i, seed)| {
-            if can_map[i] && (source_start..(source_start + length)).contains(seed) {
-                *seed = *seed - source_start + destination_start;
-                can_map[i] = false; // Unset can_map for this run of mappings
-                // This prevents us from mapping the same seed twice in a given set of mappings
-            }
-        });
+    let mut seeds = almanac.seeds.clone();
+    for map in &almanac.maps { // We will perform all of the mappings (in order)
+        // Can this seed currently be mapped
+        let mut can_map = vec![true; seeds.len()];
+        for &Mapping { destination_start, source_start, length } in map {
+            // Update all of the seeds which fall into this mapping
+            seeds.iter_mut().enumerate().for_each(|(i, seed)| {
+                if can_map[i] && (source_start..(source_start + length)).contains(seed) {
+                    *seed = *seed - source_start + destination_start;
+                    can_map[i] = false; // Unset can_map for this run of mappings
+                    // This prevents us from mapping the same seed twice in a given set of mappings
+                }
+            });
+        }
     }
     // Get the minimum position now that we have completed the mapping
     *seeds.iter().min().unwrap()
 }
 
 /// Solve part2
-fn get_lowest_location_value_range<'a>(mut almanac: impl Iterator<Item=&'a str>) -> usize {
+pub fn get_lowest_location_value_range(almanac: &Almanac) -> usize {
     // This is like the above solution but it considers entire ranges of numbers at a time
     // Unfortunately we can't treat each seed individually as that would take far too long and would
     // take too much memory
@@ -235,56 +295,47 @@ fn get_lowest_location_value_range<'a>(mut almanac: impl Iterator<Item=&'a str>)
     // Since ranges will usually not be fully mapped, we usually need to split ranges
     // So part of a range will be mapped and a new range may need to be created for the unmapped
     // portion
-    let map_pattern: Regex = Regex::new(r"(\d+) (\d+) (\d+)").unwrap();
-    let seeds = almanac.next().unwrap();
-    let seeds = seeds[7..].split(" ").map(|seed| usize::from_str(seed).unwrap()).collect::<Vec<usize>>();
-
-    let mut ranges = seeds.chunks_exact(2).map(|slice| (slice[0], slice[1])).collect::<Vec<(usize, usize)>>();
-    let mut can_map = vec![false; ranges.len()];
-    while let Some(map) = almanac.next() { // We will consume perform all of the mappings (in order)
-        let Some(mapping) = map_pattern.captures(map) else {
-            can_map.iter_mut().for_each(|x| *x = true); // Reset the can map to true when we fail to match
-            continue;
-        };
-        let destination_start = usize::from_str(mapping.get(1).unwrap().as_str()).unwrap();
-        let source_start = usize::from_str(mapping.get(2).unwrap().as_str()).unwrap();
-        let map_length = usize::from_str(mapping.get(3).unwrap().as_str()).unwrap();
-        let source_end = source_start + map_length; // Excluded
-
-        // Update all of the seeds which fall into this mapping
-        let mut new_ranges = vec![];
-        let mut new_can_map = vec![];
-        ranges.iter_mut().enumerate().for_each(|(i, (start_ref, length_ref))| {
-            let start = *start_ref;
-            let length = *length_ref;
-            let end = start + length; // Excluded
-
-            // They intersect if the region that would be mapped is valid
-            let mapped_start = max(start, source_start);
-            let mapped_end = min(end, source_end);
-            let intersects_mapping_range = mapped_start < mapped_end;
-
-            if can_map[i] && intersects_mapping_range {
-                if mapped_start == start { // We are going to map the current range
-                    can_map[i] = false; // Prevent this range from being mapped again
-                    *start_ref = start - source_start + destination_start;
-                    *length_ref = mapped_end - start;
-                } else { // mapped_start == source_start (also start < mapped_start)
-                    // We'll shorten the current range and produce a new, mapped range
-                    // start_ref does not change
-                    *length_ref = mapped_start - start;
-                    new_ranges.push((destination_start, mapped_end - mapped_start));
-                    new_can_map.push(false); // We can't map this new range as its already been mapped
-                }
-                // Regardless of what we did we might need to handle the stuff after the end of the mapped region
-                if mapped_end < end { // We need to split this range
-                    new_ranges.push((mapped_end, end - mapped_end)); // The remainder of the range we didn't map
-                    new_can_map.push(true); // We can still map this new range as it hasn't been mapped yet
+    let mut ranges = almanac.seeds.chunks_exact(2).map(|slice| (slice[0], slice[1])).collect::<Vec<(usize, usize)>>();
+    for map in &almanac.maps { // We will perform all of the mappings (in order)
+        let mut can_map = vec![true; ranges.len()];
+        for &Mapping { destination_start, source_start, length: map_length } in map {
+            let source_end = source_start + map_length; // Excluded
+
+            // Update all of the seeds which fall into this mapping
+            let mut new_ranges = vec![];
+            let mut new_can_map = vec![];
+            ranges.iter_mut().enumerate().for_each(|(i, (start_ref, length_ref))| {
+                let start = *start_ref;
+                let length = *length_ref;
+                let end = start + length; // Excluded
+
+                // They intersect if the region that would be mapped is valid
+                let mapped_start = max(start, source_start);
+                let mapped_end = min(end, source_end);
+                let intersects_mapping_range = mapped_start < mapped_end;
+
+                if can_map[i] && intersects_mapping_range {
+                    if mapped_start == start { // We are going to map the current range
+                        can_map[i] = false; // Prevent this range from being mapped again
+                        *start_ref = start - source_start + destination_start;
+                        *length_ref = mapped_end - start;
+                    } else { // mapped_start == source_start (also start < mapped_start)
+                        // We'll shorten the current range and produce a new, mapped range
+                        // start_ref does not change
+                        *length_ref = mapped_start - start;
+                        new_ranges.push((destination_start, mapped_end - mapped_start));
+                        new_can_map.push(false); // We can't map this new range as its already been mapped
+                    }
+                    // Regardless of what we did we might need to handle the stuff after the end of the mapped region
+                    if mapped_end < end { // We need to split this range
+                        new_ranges.push((mapped_end, end - mapped_end)); // The remainder of the range we didn't map
+                        new_can_map.push(true); // We can still map this new range as it hasn't been mapped yet
+                    }
                 }
-            }
-        });
-        ranges.extend(new_ranges);
-        can_map.extend(new_can_map);
+            });
+            ranges.extend(new_ranges);
+            can_map.extend(new_can_map);
+        }
     }
 
     *ranges.iter().map(|(start, _)| start).min().unwrap()
@@ -295,7 +346,7 @@ fn test_part1() {
     assert_eq!(
         35,
         get_lowest_location_value(
-            r"seeds: 79 14 55 13
+            &preprocess(r"seeds: 79 14 55 13
 
 seed-to-soil map:
 50 98 2
@@ -327,7 +378,7 @@ temperature-to-humidity map:
 
 humidity-to-location map:
 60 56 37
-56 93 4".lines()
+56 93 4".lines()).unwrap()
         )
     )
 }
@@ -338,7 +389,7 @@ fn test_part2() {
     assert_eq!(
         46,
         get_lowest_location_value_range(
-            r"seeds: 79 14 55 13
+            &preprocess(r"seeds: 79 14 55 13
 
 seed-to-soil map:
 50 98 2
@@ -370,7 +421,21 @@ temperature-to-humidity map:
 
 humidity-to-location map:
 60 56 37
-56 93 4".lines()
+56 93 4".lines()).unwrap()
         )
     )
 }
+
+#[test]
+fn test_parse_error() {
+    assert_eq!(
+        Err(ParseError::at(3, 0, "50 98", "a destination start, source start, and length")),
+        preprocess(
+            r"seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98
+52 50 48".lines()
+        )
+    );
+}
@@ -112,23 +112,71 @@ anywhere from 14 to 71516 milliseconds and beat the record, a total of
 How many ways can you beat the record in this one much longer race?
  */
 
-use std::fs;
 use std::str::FromStr;
 
-fn main() {
-    let file = fs::read_to_string("./inputs/06_wait.txt").unwrap();
-    let races = file.lines();
-    println!("{}", get_margin_product(races.clone()));
-    println!("{}", get_combined_race(races));
+use crate::parse::{self, Line, ParseError};
+use crate::Solution;
+
+/// Day 6: Wait For It
+pub struct WaitForIt;
+
+impl Solution for WaitForIt {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Wait For It";
+    const INPUT: &'static str = "./inputs/06_wait.txt";
+
+    type Input<'a> = Races;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        preprocess(input.lines())
+    }
+
+    fn part1(races: &Self::Input<'_>) -> usize {
+        get_margin_product(races)
+    }
+
+    fn part2(races: &Self::Input<'_>) -> usize {
+        get_combined_race(races)
+    }
 }
 
-fn get_next_row_numbers<'a>(races: &mut (impl Iterator<Item=&'a str> + Sized)) -> Vec<usize> {
-    // Get all of the numbers separated by whitespace on the next row
-    races.next().unwrap()
-        .split_whitespace()
-        .skip(1) // Skip the "header"/name of this variable type
-        .map(|time| usize::from_str(time).unwrap())
-        .collect::<Vec<usize>>()
+/// The sheet of paper listing the races
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Races {
+    times: Vec<usize>,
+    distances: Vec<usize>,
+    /// The time of the race when the spaces between the times are ignored
+    combined_time: usize,
+    /// The distance of the race when the spaces between the distances are ignored
+    combined_distance: usize,
+}
+
+/// Preprocess the input into a more useful form
+pub fn preprocess<'a>(races: impl Iterator<Item=&'a str>) -> Result<Races, ParseError> {
+    let mut races = parse::lines(races);
+    let (times, combined_time) = get_row_numbers(races.next().unwrap_or(Line::new(0, "")), "Time:")?;
+    let distances_row = races.next().unwrap_or(Line::new(1, ""));
+    let (distances, combined_distance) = get_row_numbers(distances_row, "Distance:")?;
+    if distances.len() != times.len() {
+        return Err(distances_row.missing(format!("{} distances (one per time)", times.len())));
+    }
+    Ok(Races { times, distances, combined_time, combined_distance })
+}
+
+/// Get all of the numbers separated by whitespace on a row after its "header"/name as well as
+/// the single number we get from ignoring the whitespace
+fn get_row_numbers(row: Line, header: &str) -> Result<(Vec<usize>, usize), ParseError> {
+    let numbers = row.text.strip_prefix(header)
+        .ok_or_else(|| row.error(row.text, format!("`{header}`")))?;
+    let separate = numbers.split_whitespace()
+        .map(|number| row.parse(number, "a number"))
+        .collect::<Result<Vec<usize>, _>>()?;
+    // Every number was valid so this can only fail if the combined number is too big
+    let combined = usize::from_str(&numbers.split_whitespace().collect::<String>())
+        .map_err(|_| row.error(numbers.trim(), "a smaller number when combined"))?;
+    Ok((separate, combined))
 }
 
 // fn binary_search_required_time(total_time: usize, optimal_time: usize, distance: usize) -> usize {
@@ -158,14 +206,9 @@ fn get_next_row_numbers<'a>(races: &mut (impl Iterator<Item=&'a str> + Sized)) -
 //     max_time
 // }
 
-fn get_margin_product<'a>(mut races: impl Iterator<Item=&'a str>) -> usize {
-    // Get all of the times
-    let times = get_next_row_numbers(&mut races);
-    // Get all of the distance
-    let distances = get_next_row_numbers(&mut races);
-
+pub fn get_margin_product(races: &Races) -> usize {
     // Loop over each time, distance pair
-    times.into_iter().zip(distances).map(|(time, distance)| {
+    races.times.iter().zip(&races.distances).map(|(&time, &distance)| {
         // The optimal time is half of the total time
         // Proof:
         // distance = button_time * (total_time - button_time)
@@ -194,19 +237,9 @@ fn get_margin_product<'a>(mut races: impl Iterator<Item=&'a str>) -> usize {
     }).product() // Get the product of all of the values
 }
 
-fn get_next_row_as_number<'a>(races: &mut (impl Iterator<Item=&'a str> + Sized)) -> usize {
-    usize::from_str(&races.next().unwrap()
-        .chars() // Get all of the characters
-        .filter(|x| x.is_numeric()) // grab only the numbers
-        .collect::<String>() // Join it all into a string
-    ).unwrap()
-}
-
-fn get_combined_race<'a>(mut races: impl Iterator<Item=&'a str>) -> usize {
-    // Get the number from the first row
-    let time = get_next_row_as_number(&mut races);
-    // Get the number from the second row
-    let distance = get_next_row_as_number(&mut races);
+pub fn get_combined_race(races: &Races) -> usize {
+    let time = races.combined_time;
+    let distance = races.combined_distance;
 
     // // Perform the same operation as we did above for the same reasons but this time only using
     // // this single long race
@@ -262,8 +295,8 @@ fn test_part1() {
     assert_eq!(
         288,
         get_margin_product(
-            r"Time:      7  15   30
-Distance:  9  40  200".lines()
+            &preprocess(r"Time:      7  15   30
+Distance:  9  40  200".lines()).unwrap()
         )
     )
 }
@@ -274,8 +307,8 @@ fn test_part2() {
     assert_eq!(
         71503,
         get_combined_race(
-            r"Time:      7  15   30
-Distance:  9  40  200".lines()
+            &preprocess(r"Time:      7  15   30
+Distance:  9  40  200".lines()).unwrap()
         )
     )
 }
\ No newline at end of file
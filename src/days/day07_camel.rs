@@ -136,16 +136,50 @@ the new total winnings?
  */
 
 use std::cmp::Ordering;
-use std::fs;
-use std::str::FromStr;
 
 use itertools::Itertools;
 
-fn main() {
-    let file = fs::read_to_string("./inputs/07_camel.txt").unwrap();
-    let hands = file.lines();
-    println!("{}", get_total_winnings(hands.clone()));
-    println!("{}", get_total_winnings_wildcard(hands));
+use crate::parse::{self, ParseError};
+use crate::Solution;
+
+/// Day 7: Camel Cards
+pub struct CamelCards;
+
+impl Solution for CamelCards {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "Camel Cards";
+    const INPUT: &'static str = "./inputs/07_camel.txt";
+
+    type Input<'a> = Vec<(Vec<Card>, usize)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        preprocess(input.lines())
+    }
+
+    fn part1(hands: &Self::Input<'_>) -> usize {
+        get_total_winnings(hands)
+    }
+
+    fn part2(hands: &Self::Input<'_>) -> usize {
+        get_total_winnings_wildcard(hands)
+    }
+}
+
+/// Preprocess the input into each hand's cards (using the normal rules) and bid
+pub fn preprocess<'a>(hands: impl Iterator<Item=&'a str>) -> Result<Vec<(Vec<Card>, usize)>, ParseError> {
+    parse::lines(hands).map(|line| {
+        let (hand, bid) = line.split_once(line.text, " ")?;
+        let cards = hand.char_indices()
+            .map(|(i, card)| Card::normal(card).ok_or_else(|| line.error(&hand[i..(i + card.len_utf8())], "a card")))
+            .collect::<Result<Vec<_>, _>>()?;
+        if cards.len() != 5 {
+            return Err(line.error(hand, "a hand of five cards"));
+        }
+        let bid = line.parse(bid, "a bid")?;
+        Ok((cards, bid))
+    }).collect()
 }
 
 /// Represents a hand (and the information extracted from it)
@@ -157,20 +191,17 @@ struct Hand {
 
 impl Hand {
     /// Construct a hand (part 1/normal rules)
-    fn normal(hand_input: &str) -> Self {
-        Self::construct(hand_input, Card::normal)
+    fn normal((cards, bid): &(Vec<Card>, usize)) -> Self {
+        Self::construct(cards, *bid, |card| card)
     }
     /// Construct a hand (part 2/wildcard rules)
-    fn wildcard(hand_input: &str) -> Self {
-        Self::construct(hand_input, Card::wildcard)
+    fn wildcard((cards, bid): &(Vec<Card>, usize)) -> Self {
+        Self::construct(cards, *bid, Card::wildcard)
     }
 
     /// Common construct logic
-    fn construct(hand_input: &str, card_builder: impl Fn(char) -> Card) -> Self {
-        let mut parts = hand_input.split(" ");
-        let hand = parts.next().unwrap();
-        let hand = hand.chars().map(card_builder).collect();
-        let bid = usize::from_str(parts.next().unwrap()).unwrap();
+    fn construct(cards: &[Card], bid: usize, card_builder: impl Fn(Card) -> Card) -> Self {
+        let hand: Vec<_> = cards.iter().copied().map(card_builder).collect();
         let hand_type = HandType::from(&hand);
         Self {
             hand,
@@ -194,7 +225,7 @@ enum HandType {
 
 impl HandType {
     /// Determine the type of hand from counts of each type of card
-    fn from(cards: &Vec<Card>) -> Self {
+    fn from(cards: &[Card]) -> Self {
         // Map of the counts of each distinct card type
         let mut counts = cards.iter().counts_by(|c| *c);
         // Get the number of wildcards
@@ -208,7 +239,7 @@ impl HandType {
         counts.sort_by(|right, left| left.cmp(right)); // Sort reversed so that it is descending
 
         // Get the count of the most used card (in case we don't have any cards just set this to 0)
-        let top_card = *counts.get(0).unwrap_or(&0);
+        let top_card = *counts.first().unwrap_or(&0);
 
         // We will always try to apply our wildcards to our best type
         if top_card + wildcards == 5 {
@@ -237,7 +268,7 @@ impl HandType {
 /// Not all of these cards will always be used
 /// Most notably both the Joker and a Wildcard will likely not show up in the same game
 #[derive(Debug, Ord, PartialOrd, Eq, PartialEq, Hash, Copy, Clone)]
-enum Card {
+pub enum Card {
     A = 14,
     K = 13,
     Q = 12,
@@ -257,30 +288,30 @@ enum Card {
 
 impl Card {
     /// Convert a character to its card type - assuming a normal game (i.e. J is a J)
-    fn normal(value: char) -> Self {
+    fn normal(value: char) -> Option<Self> {
         match value {
-            'A' => Card::A,
-            'K' => Card::K,
-            'Q' => Card::Q,
-            'J' => Card::J,
-            'T' => Card::T,
-            '9' => Card::Nine,
-            '8' => Card::Eight,
-            '7' => Card::Seven,
-            '6' => Card::Six,
-            '5' => Card::Five,
-            '4' => Card::Four,
-            '3' => Card::Three,
-            '2' => Card::Two,
-            '1' => Card::One,
-            _ => panic!("Unknown Card")
+            'A' => Some(Card::A),
+            'K' => Some(Card::K),
+            'Q' => Some(Card::Q),
+            'J' => Some(Card::J),
+            'T' => Some(Card::T),
+            '9' => Some(Card::Nine),
+            '8' => Some(Card::Eight),
+            '7' => Some(Card::Seven),
+            '6' => Some(Card::Six),
+            '5' => Some(Card::Five),
+            '4' => Some(Card::Four),
+            '3' => Some(Card::Three),
+            '2' => Some(Card::Two),
+            '1' => Some(Card::One),
+            _ => None
         }
     }
-    /// Convert a character to its card type - assuming a wildcard game (i.e. J is a Wildcard)
-    fn wildcard(value: char) -> Self {
-        match value {
-            'J' => Card::Wildcard,
-            _ => Self::normal(value)
+    /// Convert a card from a normal game to its card type in a wildcard game (i.e. J is a Wildcard)
+    fn wildcard(self) -> Self {
+        match self {
+            Card::J => Card::Wildcard,
+            _ => self
         }
     }
 }
@@ -305,14 +336,14 @@ fn determine_total_winnings(mut hands: Vec<Hand>) -> usize {
 }
 
 /// Get the total winnings of a normal game (part 1)
-fn get_total_winnings<'a>(hands: impl Iterator<Item=&'a str>) -> usize {
-    let hands = hands.map(Hand::normal).collect();
+pub fn get_total_winnings(hands: &[(Vec<Card>, usize)]) -> usize {
+    let hands = hands.iter().map(Hand::normal).collect();
     determine_total_winnings(hands)
 }
 
 /// Get the total winnings of a wildcard game (part 2)
-fn get_total_winnings_wildcard<'a>(hands: impl Iterator<Item=&'a str>) -> usize {
-    let hands = hands.map(Hand::wildcard).collect();
+pub fn get_total_winnings_wildcard(hands: &[(Vec<Card>, usize)]) -> usize {
+    let hands = hands.iter().map(Hand::wildcard).collect();
     determine_total_winnings(hands)
 }
 
@@ -321,11 +352,11 @@ fn test_part1() {
     assert_eq!(
         6440,
         get_total_winnings(
-            r"32T3K 765
+            &preprocess(r"32T3K 765
 T55J5 684
 KK677 28
 KTJJT 220
-QQQJA 483".lines()
+QQQJA 483".lines()).unwrap()
         )
     )
 }
@@ -335,11 +366,22 @@ fn test_part2() {
     assert_eq!(
         5905,
         get_total_winnings_wildcard(
-            r"32T3K 765
+            &preprocess(r"32T3K 765
 T55J5 684
 KK677 28
 KTJJT 220
-QQQJA 483".lines()
+QQQJA 483".lines()).unwrap()
+        )
+    )
+}
+
+#[test]
+fn test_parse_error() {
+    assert_eq!(
+        Err(ParseError::at(1, 2, "X", "a card")),
+        preprocess(
+            r"32T3K 765
+T5XJ5 684".lines()
         )
     )
 }
@@ -0,0 +1,442 @@
+/*
+You're still riding a camel across Desert Island when you spot a sandstorm
+quickly approaching. When you turn to warn the Elf, she disappears before
+your eyes! To be fair, she had just finished warning you about ghosts a few
+minutes ago.
+
+One of the camel's pouches is labeled "maps" - sure enough, it's full of
+documents (your puzzle input) about how to navigate the desert. At least,
+you're pretty sure that's what they are; one of the documents contains a
+list of left/right instructions, and the rest of the documents seem to
+describe some kind of network of labeled nodes.
+
+It seems like you're meant to use the left/right instructions to navigate
+the network. Perhaps if you have the camel follow the same instructions,
+you can escape the haunted wasteland!
+
+After examining the maps for a bit, two nodes stick out: AAA and ZZZ. You
+feel like AAA is where you are now, and you have to follow the left/right
+instructions until you reach ZZZ.
+
+This format defines each node of the network individually. For example:
+
+RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)
+
+Starting with AAA, you need to look up the next element based on the next
+left/right instruction in your input. In this example, start with AAA and
+go right (R) by choosing the right element of AAA, CCC. Then, L means to
+choose the left element of CCC, ZZZ. By following the left/right
+instructions, you reach ZZZ in 2 steps.
+
+Of course, you might not find ZZZ right away. If you run out of left/right
+instructions, repeat the whole sequence of instructions as necessary: RL
+really means RLRLRLRLRLRLRLRL... and so on. For example, here is a
+situation that takes 6 steps to reach ZZZ:
+
+LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)
+
+Starting at AAA, follow the left/right instructions. How many steps are
+required to reach ZZZ?
+
+--- Part Two ---
+
+The sandstorm is upon you and you aren't any closer to escaping the
+wasteland. You had the camel follow the instructions, but you've barely
+left your starting position. It's going to take significantly more steps to
+escape!
+
+What if the map isn't for people - what if the map is for ghosts? Are
+ghosts even bound by the laws of spacetime? Only one way to find out.
+
+After examining the maps a bit longer, your attention is drawn to a curious
+fact: the number of nodes with names ending in A is equal to the number
+ending in Z! If you were a ghost, you'd probably just start at every node
+that ends with A and follow all of the paths at the same time until they
+all simultaneously end up at nodes that end with Z.
+
+For example:
+
+LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)
+
+Here, there are two starting nodes, 11A and 22A (because they both end
+with A). As you follow each left/right instruction, use that instruction to
+simultaneously navigate away from both nodes you're currently on. Repeat
+this process until all of the nodes you're currently on end with Z. (If
+only some of the nodes you're on end with Z, they act like any other node
+and you continue as normal.) In this example, you would proceed as follows:
+
+- Step 0: You are at 11A and 22A.
+- Step 1: You choose all of the left paths, leading you to 11B and 22B.
+- Step 2: You choose all of the right paths, leading you to 11Z and 22C.
+- Step 3: You choose all of the left paths, leading you to 11B and 22Z.
+- Step 4: You choose all of the right paths, leading you to 11Z and 22B.
+- Step 5: You choose all of the left paths, leading you to 11B and 22C.
+- Step 6: You choose all of the right paths, leading you to 11Z and 22Z.
+So, in this example, you end up entirely on nodes that end in Z after 6 steps.
+
+Simultaneously start on every node that ends with A. How many steps does it
+take before you're only on nodes that end with Z?
+ */
+
+use std::collections::HashMap;
+
+use itertools::Itertools;
+
+use crate::math::{solve_congruences, Congruence, CongruenceError};
+use crate::parse::{self, Line, ParseError};
+use crate::Solution;
+
+/// Day 8: Haunted Wasteland
+pub struct HauntedWasteland;
+
+impl Solution for HauntedWasteland {
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Haunted Wasteland";
+    const INPUT: &'static str = "./inputs/08_haunted.txt";
+
+    type Input<'a> = Documents;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        preprocess(input.lines())
+    }
+
+    fn part1(documents: &Self::Input<'_>) -> usize {
+        get_num_steps(documents)
+    }
+
+    fn part2(documents: &Self::Input<'_>) -> usize {
+        get_num_steps_ghost(documents)
+    }
+}
+
+#[derive(Ord, PartialOrd, Eq, PartialEq, Hash, Clone)]
+struct Name(String);
+
+struct Node {
+    left: Name,
+    right: Name,
+}
+
+/// Which way to go when leaving a node
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Instruction {
+    Left,
+    Right,
+}
+
+/// The documents: the instructions to follow and the network of nodes
+pub struct Documents {
+    instructions: Vec<Instruction>,
+    graph: HashMap<Name, Node>,
+}
+
+/// Preprocess the input into a more useful form
+pub fn preprocess<'a>(documents: impl Iterator<Item=&'a str>) -> Result<Documents, ParseError> {
+    let mut documents = parse::lines(documents);
+    let instructions_line = documents.next().unwrap_or(Line::new(0, ""));
+    let instructions = instructions_line.text.chars()
+        .enumerate()
+        .map(|(i, instruction)| match instruction {
+            'L' => Ok(Instruction::Left),
+            'R' => Ok(Instruction::Right),
+            _ => Err(ParseError::at(instructions_line.index, i, instruction, "`L` or `R`")),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if instructions.is_empty() {
+        return Err(instructions_line.missing("at least one instruction"));
+    }
+    documents.next(); // Skip the blank line
+    let graph = get_graph(documents)?;
+
+    Ok(Documents { instructions, graph })
+}
+
+fn get_graph<'a>(documents: impl Iterator<Item=Line<'a>>) -> Result<HashMap<Name, Node>, ParseError> {
+    let mut map = HashMap::new();
+    let mut references = vec![];
+
+    for line in documents {
+        // AAA = (BBB, CCC)
+        let (name, edges) = line.split_once(line.text, " = ")?;
+        let edges = edges.strip_prefix('(')
+            .and_then(|edges| edges.strip_suffix(')'))
+            .ok_or_else(|| line.error(edges, "`(<left>, <right>)`"))?;
+        let (left, right) = line.split_once(edges, ", ")?;
+        references.push((line, left));
+        references.push((line, right));
+
+        map.insert(Name(name.into()), Node {
+            left: Name(left.into()),
+            right: Name(right.into()),
+        });
+    }
+
+    // Make sure that every node we can go to actually exists
+    if let Some((line, name)) = references.into_iter().find(|(_, name)| !map.contains_key(&Name(name.to_string()))) {
+        return Err(line.error(name, "the name of a node"));
+    }
+
+    Ok(map)
+}
+
+/// Take a step from a location
+fn step<'a>(graph: &'a HashMap<Name, Node>, location: &Name, instruction: Instruction) -> &'a Name {
+    match instruction {
+        Instruction::Right => &graph[location].right,
+        Instruction::Left => &graph[location].left,
+    }
+}
+
+/// Solve part1
+pub fn get_num_steps(documents: &Documents) -> usize {
+    let mut instructions = documents.instructions.iter().cycle();
+    let graph = &documents.graph;
+
+    let start: Name = Name("AAA".into());
+    let end: Name = Name("ZZZ".into());
+
+    let mut location = &start;
+
+    let mut step_count = 0;
+    while location.0 != end.0 {
+        step_count += 1;
+        location = step(graph, location, *instructions.next().unwrap());
+    }
+
+    step_count
+}
+
+/// The steps at which a ghost is on a node ending in `Z`
+/// Since there are only so many (node, instruction) states, every ghost ends up going around a loop
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct Cycle {
+    /// The finishes before the ghost gets into its loop (these only happen once)
+    transient: Vec<usize>,
+    /// The step at which the ghost first enters its loop
+    start: usize,
+    /// The number of steps to go around the loop
+    period: usize,
+    /// The finishes during the first time around the loop (each repeats every period steps)
+    periodic: Vec<usize>,
+}
+
+impl Cycle {
+    /// Check if the ghost is on a finish after a number of steps
+    fn contains(&self, step_count: usize) -> bool {
+        if step_count < self.start {
+            self.transient.contains(&step_count)
+        } else {
+            self.periodic.iter().any(|&finish| step_count >= finish && (step_count - finish).is_multiple_of(self.period))
+        }
+    }
+}
+
+/// Follow a ghost until it is in a state it has been in before to find its cycle
+fn find_cycle(documents: &Documents, start: &Name) -> Cycle {
+    // The step at which each (node, instruction index) state was first seen
+    let mut seen = HashMap::new();
+    let mut finishes = vec![];
+
+    let mut location = start;
+    let mut step_count = 0;
+    let loop_start = loop {
+        let instruction = step_count % documents.instructions.len();
+        if let Some(&loop_start) = seen.get(&(location, instruction)) {
+            break loop_start;
+        }
+        seen.insert((location, instruction), step_count);
+        if location.0.ends_with('Z') {
+            finishes.push(step_count);
+        }
+        location = step(&documents.graph, location, documents.instructions[instruction]);
+        step_count += 1;
+    };
+
+    let (transient, periodic) = finishes.into_iter().partition(|&finish| finish < loop_start);
+    Cycle {
+        transient,
+        start: loop_start,
+        period: step_count - loop_start,
+        periodic,
+    }
+}
+
+/// Find the first step at which every ghost is on a node ending in `Z` at the same time
+/// Returns None if that never happens, and panics if the answer is too large to work out
+pub fn get_num_steps_ghost_general(documents: &Documents) -> Option<usize> {
+    let cycles = documents.graph.keys()
+        .filter(|node| node.0.ends_with('A'))
+        .map(|start| find_cycle(documents, start))
+        .collect::<Vec<_>>();
+
+    // A finish before some ghost gets into its loop only happens once so just check it directly
+    let transient = cycles.iter()
+        .flat_map(|cycle| cycle.transient.iter().copied())
+        .filter(|&step_count| cycles.iter().all(|cycle| cycle.contains(step_count)))
+        .min();
+    if transient.is_some() {
+        // Any step where every ghost is in its loop comes after every loop start, so it is later
+        return transient;
+    }
+
+    // Otherwise every ghost is in its loop, so pick one of the finishes in each loop and find when
+    // they line up. Each choice is a system of congruences (the periods needn't be coprime)
+    let latest_start = cycles.iter().map(|cycle| cycle.start).max().unwrap_or(0);
+    cycles.iter()
+        .map(|cycle| cycle.periodic.iter().map(|&finish| Congruence::new(finish as u64, cycle.period as u64)))
+        .multi_cartesian_product()
+        .filter_map(|finishes| match solve_congruences(finishes) {
+            Ok(solution) => Some(solution),
+            Err(CongruenceError::NoSolution) => None,
+            Err(error @ CongruenceError::Overflow) => panic!("The ghosts' loops can't be lined up: {error}"),
+        })
+        .map(|solution| solution.smallest_from(latest_start as u64)
+            .and_then(|step_count| usize::try_from(step_count).ok())
+            .expect("The ghosts line up after too many steps to count"))
+        .min()
+        // multi_cartesian_product doesn't produce anything for no ghosts
+        .or(cycles.is_empty().then_some(0))
+}
+
+/// Solve part2
+pub fn get_num_steps_ghost(documents: &Documents) -> usize {
+    // My original solution relied on every ghost taking X steps to first reach a finish and then
+    // going around a loop of exactly X steps where that is the only finish it hits. That happens to
+    // be true of the real input but the general solution is still fast enough
+    get_num_steps_ghost_general(documents).expect("The ghosts are never all on finishes at the same time")
+}
+
+#[test]
+fn test_part1() {
+    assert_eq!(
+        2,
+        get_num_steps(
+            &preprocess(r"RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)".lines()).unwrap()
+        )
+    )
+}
+
+#[test]
+fn test_part1b() {
+    assert_eq!(
+        6,
+        get_num_steps(
+            &preprocess(r"LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)".lines()).unwrap()
+        )
+    )
+}
+
+#[test]
+fn test_part2() {
+    assert_eq!(
+        6,
+        get_num_steps_ghost(
+            &preprocess(r"LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)".lines()).unwrap()
+        )
+    )
+}
+
+#[test]
+fn test_part2_general() {
+    // The first ghost finishes on every odd step and the second finishes twice in a loop of five
+    // (steps 2, 4, 7, 9, ...) after first taking a step to get to the loop
+    assert_eq!(
+        Some(7),
+        get_num_steps_ghost_general(
+            &preprocess(r"L
+
+11A = (11Z, 11Z)
+11Z = (11A, 11A)
+22A = (22B, 22B)
+22B = (22Z, 22Z)
+22Z = (22C, 22C)
+22C = (2ZZ, 2ZZ)
+2ZZ = (22D, 22D)
+22D = (22B, 22B)".lines()).unwrap()
+        )
+    );
+    // The second ghost only finishes once before getting stuck
+    assert_eq!(
+        Some(1),
+        get_num_steps_ghost_general(
+            &preprocess(r"L
+
+11A = (11Z, 11Z)
+11Z = (11A, 11A)
+22A = (22Z, 22Z)
+22Z = (22B, 22B)
+22B = (22B, 22B)".lines()).unwrap()
+        )
+    );
+    // The first ghost finishes on odd steps and the second on even steps
+    assert_eq!(
+        None,
+        get_num_steps_ghost_general(
+            &preprocess(r"L
+
+11A = (11Z, 11Z)
+11Z = (11A, 11A)
+22A = (22B, 22B)
+22B = (22Z, 22Z)
+22Z = (22B, 22B)".lines()).unwrap()
+        )
+    );
+}
+
+#[test]
+fn test_parse_error() {
+    assert_eq!(
+        Err(ParseError::at(3, 7, "DDD", "the name of a node")).map(|_: Documents| ()),
+        preprocess(
+            r"RL
+
+AAA = (BBB, BBB)
+BBB = (DDD, ZZZ)
+ZZZ = (ZZZ, ZZZ)".lines()
+        ).map(|_| ())
+    )
+}
@@ -139,16 +139,47 @@ Analyze your OASIS report again, this time extrapolating the previous value
 for each history. What is the sum of these extrapolated values?
  */
 
-use std::fs;
-use std::str::FromStr;
-
 use itertools::Itertools;
 
-fn main() {
-    let file = fs::read_to_string("./inputs/09_mirage.txt").unwrap();
-    let histories = file.lines();
-    println!("{}", get_extrapolated_forward_sum(histories.clone()));
-    println!("{}", get_extrapolated_backward_sum(histories));
+use crate::parse::{self, ParseError};
+use crate::Solution;
+
+/// Day 9: Mirage Maintenance
+pub struct MirageMaintenance;
+
+impl Solution for MirageMaintenance {
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Mirage Maintenance";
+    const INPUT: &'static str = "./inputs/09_mirage.txt";
+
+    type Input<'a> = Vec<Vec<isize>>;
+    type Part1 = isize;
+    type Part2 = isize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        preprocess(input.lines())
+    }
+
+    fn part1(histories: &Self::Input<'_>) -> isize {
+        get_extrapolated_forward_sum(histories)
+    }
+
+    fn part2(histories: &Self::Input<'_>) -> isize {
+        get_extrapolated_backward_sum(histories)
+    }
+}
+
+/// Preprocess the input into the values of each history
+pub fn preprocess<'a>(histories: impl Iterator<Item=&'a str>) -> Result<Vec<Vec<isize>>, ParseError> {
+    parse::lines(histories).map(|history| {
+        let values = history.text.split_whitespace()
+            .map(|value| history.parse(value, "a value"))
+            .collect::<Result<Vec<isize>, _>>()?;
+        if values.is_empty() {
+            return Err(history.missing("at least one value"));
+        }
+        Ok(values)
+    }).collect()
 }
 
 /// Helper for part 1
@@ -167,13 +198,10 @@ fn extrapolate_history_forward(values: impl Iterator<Item=isize>) -> isize {
 }
 
 /// Solve part1
-fn get_extrapolated_forward_sum<'a>(histories: impl Iterator<Item=&'a str>) -> isize {
+pub fn get_extrapolated_forward_sum(histories: &[Vec<isize>]) -> isize {
     // Extrapolate each history forward and then sum up the values
-    histories.map(|history|
-        extrapolate_history_forward(
-            history.split_whitespace()
-                .map(|val| isize::from_str(val).unwrap())
-        )
+    histories.iter().map(|history|
+        extrapolate_history_forward(history.iter().copied())
     ).sum()
 }
 
@@ -194,13 +222,10 @@ fn extrapolate_history_backward(values: impl Iterator<Item=isize>) -> isize {
 }
 
 /// Solve part2
-fn get_extrapolated_backward_sum<'a>(histories: impl Iterator<Item=&'a str>) -> isize {
+pub fn get_extrapolated_backward_sum(histories: &[Vec<isize>]) -> isize {
     // Extrapolate each history backward and then sum up the values
-    histories.map(|history|
-        extrapolate_history_backward(
-            history.split_whitespace()
-                .map(|val| isize::from_str(val).unwrap())
-        )
+    histories.iter().map(|history|
+        extrapolate_history_backward(history.iter().copied())
     ).sum()
 }
 
@@ -209,9 +234,9 @@ fn test_part1() {
     assert_eq!(
         114,
         get_extrapolated_forward_sum(
-            r"0 3 6 9 12 15
+            &preprocess(r"0 3 6 9 12 15
 1 3 6 10 15 21
-10 13 16 21 30 45".lines()
+10 13 16 21 30 45".lines()).unwrap()
         )
     )
 }
@@ -221,9 +246,9 @@ fn test_part2() {
     assert_eq!(
         2,
         get_extrapolated_backward_sum(
-            r"0 3 6 9 12 15
+            &preprocess(r"0 3 6 9 12 15
 1 3 6 10 15 21
-10 13 16 21 30 45".lines()
+10 13 16 21 30 45".lines()).unwrap()
         )
     )
 }
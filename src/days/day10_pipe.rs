@@ -0,0 +1,573 @@
+/*
+You use the hang glider to ride the hot air from Desert Island all the way
+up to the floating metal island. This island is surprisingly cold and there
+definitely aren't any thermals to glide on, so you leave your hang glider
+behind.
+
+You wander around for a while, but you don't find any people or animals.
+However, you do occasionally find signposts labeled "Hot Springs" pointing
+in a seemingly consistent direction; maybe you can find someone at the hot
+springs and ask them where the desert-machine parts are made.
+
+The landscape here is alien; even the flowers and trees are made of metal.
+As you stop to admire some metal grass, you notice something metallic
+scurry away in your peripheral vision and jump into a big pipe! It didn't
+look like any animal you've ever seen; if you want a better look, you'll
+need to get ahead of it.
+
+Scanning the area, you discover that the entire field you're standing on is
+densely packed with pipes; it was hard to tell at first because they're the
+same metallic silver color as the "ground". You make a quick sketch of all
+of the surface pipes you can see (your puzzle input).
+
+The pipes are arranged in a two-dimensional grid of tiles:
+
+| is a vertical pipe connecting north and south.
+- is a horizontal pipe connecting east and west.
+L is a 90-degree bend connecting north and east.
+J is a 90-degree bend connecting north and west.
+7 is a 90-degree bend connecting south and west.
+F is a 90-degree bend connecting south and east.
+. is ground; there is no pipe in this tile.
+S is the starting position of the animal; there is a pipe on this
+tile, but your sketch doesn't show what shape the pipe has.
+
+Based on the acoustics of the animal's scurrying, you're confident the pipe
+that contains the animal is one large, continuous loop.
+
+For example, here is a square loop of pipe:
+
+.....
+.F-7.
+.|.|.
+.L-J.
+.....
+
+If the animal had entered this loop in the northwest corner, the sketch
+would instead look like this:
+
+.....
+.S-7.
+.|.|.
+.L-J.
+.....
+
+In the above diagram, the S tile is still a 90-degree F bend: you can tell
+because of how the adjacent pipes connect to it.
+
+Unfortunately, there are also many pipes that aren't connected to the loop!
+This sketch shows the same loop as above:
+
+-L|F7
+7S-7|
+L|7||
+-L-J|
+L|-JF
+
+In the above diagram, you can still figure out which pipes form the main
+loop: they're the ones connected to S, pipes those pipes connect to, pipes
+those pipes connect to, and so on. Every pipe in the main loop connects to
+its two neighbors (including S, which will have exactly two pipes
+connecting to it, and which is assumed to connect back to those two pipes).
+
+Here is a sketch that contains a slightly more complex main loop:
+
+..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ...
+Here's the same example sketch with the extra, non-main-loop pipe tiles also shown:
+
+7-F7-
+.FJ|7
+SJLL7
+|F--J
+LJ.LJ
+
+If you want to get out ahead of the animal, you should find the tile in the
+loop that is farthest from the starting position. Because the animal is in
+the pipe, it doesn't make sense to measure this by direct distance.
+Instead, you need to find the tile that would take the longest number of
+steps along the loop to reach from the starting point - regardless of which
+way around the loop the animal went.
+
+In the first example with the square loop:
+
+.....
+.S-7.
+.|.|.
+.L-J.
+.....
+
+You can count the distance each tile in the loop is from the starting point
+like this:
+
+.....
+.012.
+.1.3.
+.234.
+.....
+
+In this example, the farthest point from the start is 4 steps away.
+
+Here's the more complex loop again:
+
+..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ...
+
+Here are the distances for each tile on that loop:
+
+..45.
+.236.
+01.78
+14567
+23...
+
+Find the single giant loop starting at S. How many steps along the loop
+does it take to get from the starting position to the point farthest from
+the starting position?
+
+--- Part Two ---
+
+You quickly reach the farthest point of the loop, but the animal never
+emerges. Maybe its nest is within the area enclosed by the loop?
+
+To determine whether it's even worth taking the time to search for such a
+nest, you should calculate how many tiles are contained within the loop.
+For example:
+
+...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........
+
+The above loop encloses merely four tiles - the two pairs of . in the
+southwest and southeast (marked I below). The middle . tiles (marked O
+below) are not in the loop. Here is the same loop again with those regions
+marked:
+
+...........
+.S-------7.
+.|F-----7|.
+.||OOOOO||.
+.||OOOOO||.
+.|L-7OF-J|.
+.|II|O|II|.
+.L--JOL--J.
+.....O.....
+
+In fact, there doesn't even need to be a full tile path to the outside for
+tiles to count as outside the loop - squeezing between pipes is also
+allowed! Here, I is still within the loop and O is still outside the loop:
+
+..........
+.S------7.
+.|F----7|.
+.||OOOO||.
+.||OOOO||.
+.|L-7F-J|.
+.|II||II|.
+.L--JL--J.
+..........
+
+In both of the above examples, 4 tiles are enclosed by the loop.
+
+Here's a larger example:
+
+.F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...
+
+The above sketch has many random bits of ground, some of which are in the
+loop (I) and some of which are outside it (O):
+
+OF----7F7F7F7F-7OOOO
+O|F--7||||||||FJOOOO
+O||OFJ||||||||L7OOOO
+FJL7L7LJLJ||LJIL-7OO
+L--JOL7IIILJS7F-7L7O
+OOOOF-JIIF7FJ|L7L7L7
+OOOOL7IF7||L7|IL7L7|
+OOOOO|FJLJ|FJ|F7|OLJ
+OOOOFJL-7O||O||||OOO
+OOOOL---JOLJOLJLJOOO
+
+In this larger example, 8 tiles are enclosed by the loop.
+
+Any tile that isn't part of the main loop can count as being enclosed by
+the loop. Here's another example with many bits of junk pipe lying around
+that aren't connected to the main loop at all:
+
+FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L
+
+Here are just the tiles that are enclosed by the loop marked with I:
+
+FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJIF7FJ-
+L---JF-JLJIIIIFJLJJ7
+|F|F-JF---7IIIL7L|7|
+|FFJF7L7F-JF7IIL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L
+
+In this last example, 10 tiles are enclosed by the loop.
+
+Figure out whether you have time to search for the nest by calculating the
+area within the loop. How many tiles are enclosed by the loop?
+
+
+ */
+
+use crate::grid::{Direction, Grid, Position};
+use crate::parse::{self, ParseError};
+use crate::polygon::Polygon;
+use crate::Solution;
+
+/// Day 10: Pipe Maze
+pub struct PipeMaze;
+
+impl Solution for PipeMaze {
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Pipe Maze";
+    const INPUT: &'static str = "./inputs/10_pipe.txt";
+
+    type Input<'a> = PipeMap;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        preprocess_map(input.lines())
+    }
+
+    fn part1(pipe_map: &Self::Input<'_>) -> usize {
+        get_furthest_distance(pipe_map)
+    }
+
+    fn part2(pipe_map: &Self::Input<'_>) -> usize {
+        get_enclosed_tiles(pipe_map)
+    }
+}
+
+/// The connections of every tile of the map along with the starting position
+pub type PipeMap = (Grid<Connection>, Position);
+
+/// Describes the connections offered by a given tile
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct Connection {
+    north: bool,
+    east: bool,
+    south: bool,
+    west: bool,
+}
+
+impl Connection {
+    /// A tile without any connections
+    const NONE: Connection = Connection { north: false, east: false, south: false, west: false };
+
+    /// Get the connection from a character
+    fn from_char(value: char) -> Option<Self> {
+        match value {
+            '|' => Some(Connection { north: true, east: false, south: true, west: false }),
+            '-' => Some(Connection { north: false, east: true, south: false, west: true }),
+            'L' => Some(Connection { north: true, east: true, south: false, west: false }),
+            'J' => Some(Connection { north: true, east: false, south: false, west: true }),
+            '7' => Some(Connection { north: false, east: false, south: true, west: true }),
+            'F' => Some(Connection { north: false, east: true, south: true, west: false }),
+            '.' => Some(Connection::NONE),
+            'S' => Some(Connection::NONE), // Will be determined later
+            _ => None
+        }
+    }
+
+    /// Check if this tile connects to the tile in a given direction
+    fn connects(&self, direction: Direction) -> bool {
+        match direction {
+            Direction::Up => self.north,
+            Direction::Right => self.east,
+            Direction::Down => self.south,
+            Direction::Left => self.west,
+        }
+    }
+}
+
+/// Preprocess the input map into a more useful format
+pub fn preprocess_map<'a>(pipe_map: impl Iterator<Item=&'a str>) -> Result<PipeMap, ParseError> {
+    // We want to convert the characters into lists of our Connection data structures
+    // At the same time we want to remember the starting position since this process will wipe that
+    // data if we don't
+    let pipe_map = pipe_map.collect::<Vec<_>>();
+    let mut map = Grid::parse_with(pipe_map.iter().copied(), "a pipe, `.`, or `S`", Connection::from_char)?;
+    let mut starts = parse::lines(pipe_map.iter().copied())
+        .flat_map(|line| line.text.match_indices('S').map(move |(offset, _)| (line, offset)));
+    let Some((line, offset)) = starts.next() else {
+        return Err(ParseError::at(0, 0, "", "a starting position `S`"));
+    };
+    if let Some((line, offset)) = starts.next() {
+        return Err(line.error(&line.text[offset..=offset], "only one starting position"));
+    }
+    let starting_position = Position::new(line.index, line.column_of(&line.text[offset..]));
+
+    // Now compute the connectivity for the starting position
+    // Basically if the tile exists in the given direction and that tile is pointing into this one
+    // then we say a connection should exist
+    let connects = |direction: Direction| map.step(starting_position, direction)
+        .is_some_and(|neighbor| map[neighbor].connects(direction.opposite()));
+
+    // Set the starting tile as we determined it
+    let start = Connection {
+        north: connects(Direction::Up),
+        east: connects(Direction::Right),
+        south: connects(Direction::Down),
+        west: connects(Direction::Left),
+    };
+    if Direction::ALL.into_iter().filter(|&direction| start.connects(direction)).count() != 2 {
+        return Err(line.error(&line.text[offset..=offset], "an `S` which connects to exactly two pipes"));
+    }
+    map[starting_position] = start;
+
+    // Make sure that following the pipes leads back around to the start
+    let mut position = starting_position;
+    let mut last_direction = None;
+    loop {
+        let direction = get_next_direction(last_direction, &map[position]);
+        let next = map.step(position, direction).filter(|&next| map[next].connects(direction.opposite()));
+        let Some(next) = next else {
+            let tile = pipe_map[position.row].chars().nth(position.column).unwrap_or_default();
+            return Err(ParseError::at(position.row, position.column, tile, "a pipe which continues the loop"));
+        };
+        if next == starting_position {
+            break;
+        }
+        position = next;
+        last_direction = Some(direction);
+    }
+
+    Ok((map, starting_position))
+}
+
+/// Determine the next direction we should move based on connectivity and the last direction we moved
+/// We want to prevent going back the way we came
+fn get_next_direction(last_direction: Option<Direction>, connection: &Connection) -> Direction {
+    if last_direction != Some(Direction::Down) && connection.north {
+        Direction::Up
+    } else if last_direction != Some(Direction::Up) && connection.south {
+        Direction::Down
+    } else if last_direction != Some(Direction::Left) && connection.east {
+        Direction::Right
+    } else {
+        Direction::Left
+    }
+}
+
+/// Move along the loop in a direction
+fn move_in(map: &Grid<Connection>, position: Position, direction: Direction) -> Position {
+    map.step(position, direction).expect("The loop should not leave the map")
+}
+
+/// Walk along the loop and record the corners where it turns
+fn trace_loop(map: &Grid<Connection>, starting_position: Position) -> Polygon {
+    // This will keep walking along this loop until we get back to the starting position
+    let mut corners = vec![];
+    let mut position = starting_position;
+    let mut last_direction = None;
+    loop {
+        // Get the next direction. This must not be cause us to go the opposite that we just walked
+        let direction = get_next_direction(last_direction, &map[position]);
+        if last_direction != Some(direction) {
+            corners.push(position.into());
+        }
+        last_direction = Some(direction);
+        position = move_in(map, position, direction);
+        if position == starting_position {
+            break;
+        }
+    }
+    Polygon::new(corners).expect("The loop only moves horizontally and vertically")
+}
+
+/// Part 1
+pub fn get_furthest_distance((map, start): &PipeMap) -> usize {
+    // The loop length is guaranteed to be even
+    // Each step north requires a corresponding step south
+    // same argument for east/west
+    let loop_length = trace_loop(map, *start).boundary_length() as usize;
+    loop_length / 2
+}
+
+/// Solve part 2
+/// The enclosed tiles are the lattice points strictly inside the loop
+pub fn get_enclosed_tiles((map, start): &PipeMap) -> usize {
+    trace_loop(map, *start).interior_points() as usize
+}
+
+#[test]
+fn test_part1a() {
+    assert_eq!(
+        4,
+        get_furthest_distance(
+            &preprocess_map(r".....
+.S-7.
+.|.|.
+.L-J.
+.....".lines()).unwrap()
+        )
+    )
+}
+
+#[test]
+fn test_part1b() {
+    assert_eq!(
+        8,
+        get_furthest_distance(
+            &preprocess_map(r"..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ...".lines()).unwrap()
+        )
+    )
+}
+
+#[test]
+fn test_part2a() {
+    assert_eq!(
+        4,
+        get_enclosed_tiles(
+            &preprocess_map(r"...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........".lines()).unwrap()
+        )
+    )
+}
+
+#[test]
+fn test_part2b() {
+    assert_eq!(
+        4,
+        get_enclosed_tiles(
+            &preprocess_map(r"..........
+.S------7.
+.|F----7|.
+.||....||.
+.||....||.
+.|L-7F-J|.
+.|..||..|.
+.L--JL--J.
+..........".lines()).unwrap()
+        )
+    )
+}
+
+#[test]
+fn test_part2c() {
+    assert_eq!(
+        8,
+        get_enclosed_tiles(
+            &preprocess_map(r".F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...".lines()).unwrap()
+        )
+    )
+}
+
+#[test]
+fn test_part2d() {
+    assert_eq!(
+        10,
+        get_enclosed_tiles(
+            &preprocess_map(r"FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L".lines()).unwrap()
+        )
+    )
+}
+
+#[test]
+fn test_locate_tiles() {
+    use crate::polygon::Location;
+
+    let (map, start) = preprocess_map(r"...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........".lines()).unwrap();
+    let polygon = trace_loop(&map, start);
+    assert_eq!(Location::Inside, polygon.locate(Position::new(6, 2).into()));
+    assert_eq!(Location::Boundary, polygon.locate(Position::new(5, 4).into()));
+    // Squeezing between the pipes still counts as outside
+    assert_eq!(Location::Outside, polygon.locate(Position::new(6, 5).into()));
+    assert_eq!(Location::Outside, polygon.locate(Position::new(3, 5).into()));
+}
+
+#[test]
+fn test_parse_error() {
+    assert_eq!(
+        Err(ParseError::at(2, 2, "X", "a pipe, `.`, or `S`")),
+        preprocess_map(".....\n.S-7.\n.|X|.\n.L-J.\n.....".lines()).map(|_| ())
+    );
+    assert_eq!(
+        Err(ParseError::at(0, 0, "", "a starting position `S`")),
+        preprocess_map(".....\n.F-7.\n.L-J.".lines()).map(|_| ())
+    );
+    assert_eq!(
+        Err(ParseError::at(0, 0, "S", "an `S` which connects to exactly two pipes")),
+        preprocess_map("S.\n..".lines()).map(|_| ())
+    );
+    // The pipe in the bottom right leads off the loop
+    assert_eq!(
+        Err(ParseError::at(3, 3, "7", "a pipe which continues the loop")),
+        preprocess_map(".....\n.S-7.\n.|.|.\n.L-7.\n.....".lines()).map(|_| ())
+    );
+}
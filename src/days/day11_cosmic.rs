@@ -138,72 +138,69 @@ these new rules, then find the length of the shortest path between every
 pair of galaxies. What is the sum of these lengths?
  */
 
-use std::collections::BTreeSet;
-use std::fs;
-
 use itertools::Itertools;
 
-fn main() {
-    let file = fs::read_to_string("./inputs/11_cosmic.txt").unwrap();
-    let image = file.lines();
-    println!("{}", get_sum_of_lengths(image.clone(), 2));
-    println!("{}", get_sum_of_lengths(image, 1000000));
-}
+use crate::grid::{Grid, Position};
+use crate::parse::ParseError;
+use crate::Solution;
 
-/// A structure to store the position data for a star
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
-struct Star {
-    row: usize,
-    column: usize,
-}
+/// Day 11: Cosmic Expansion
+pub struct CosmicExpansion;
+
+impl Solution for CosmicExpansion {
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Cosmic Expansion";
+    const INPUT: &'static str = "./inputs/11_cosmic.txt";
 
-impl Star {
-    /// Manhattan Distance
-    fn distance_to(&self, other: &Self) -> usize {
-        self.row.abs_diff(other.row) + self.column.abs_diff(other.column)
+    type Input<'a> = Image;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        extract(input.lines())
+    }
+
+    fn part1(image: &Self::Input<'_>) -> usize {
+        get_sum_of_lengths(image, 2)
+    }
+
+    fn part2(image: &Self::Input<'_>) -> usize {
+        get_sum_of_lengths(image, 1000000)
     }
 }
 
+/// The positions of all of the stars along with the rows and columns with no stars
+pub type Image = (Vec<Position>, Vec<usize>, Vec<usize>);
+
 /// Extract the important data from the images
 /// returns
 ///  1. the positions of all of the stars (before accounting for gravitational effects)
 ///  2. the rows with no stars
 ///  3. the columns with no stars
-fn extract<'a>(image: impl Iterator<Item=&'a str>) -> (Vec<Star>, Vec<usize>, Vec<usize>) {
-    let mut stars = vec![];
-    let mut rows = vec![];
-    // Columns are harder to find than rows - so instead we will start with all columns as an option
-    // and remove them. A BTreeSet is used to keep things sorted.
-    let mut columns = BTreeSet::new();
-
-    image.enumerate().for_each(|(i, row)| {
-        if columns.is_empty() {
-            // Set the columns to all of them if it isn't yet set
-            columns = row.char_indices().map(|(j, _)| j).collect();
-        }
-
-        let mut row_empty = true;
-        row.char_indices().for_each(|(j, pixel)| {
-            if pixel == '#' {
-                stars.push(Star {
-                    row: i,
-                    column: j,
-                });
-                row_empty = false;
-                columns.remove(&j); // Also remove from the column
-            }
-        });
-        if row_empty {
-            rows.push(i);
-        }
-    });
-
-    (stars, rows, columns.into_iter().collect())
+pub fn extract<'a>(image: impl Iterator<Item=&'a str>) -> Result<Image, ParseError> {
+    let image = Grid::<bool>::parse_with(image, "`.` or `#`", |pixel| match pixel {
+        '.' => Some(false),
+        '#' => Some(true),
+        _ => None,
+    })?;
+
+    let stars = image.iter()
+        .filter(|(_, &star)| star)
+        .map(|(position, _)| position)
+        .collect();
+    let rows = image.rows()
+        .positions(|row| row.iter().all(|&star| !star))
+        .collect();
+    let columns = image.columns()
+        .positions(|mut column| column.all(|&star| !star))
+        .collect();
+
+    Ok((stars, rows, columns))
 }
 
 /// Update the star positions
 /// rows and columns are assumed to be sorted in ascending order
-fn update_star_positions(stars: &mut Vec<Star>, rows: &Vec<usize>, columns: &Vec<usize>, empty_space_multiplier: usize) {
+fn update_star_positions(stars: &mut [Position], rows: &[usize], columns: &[usize], empty_space_multiplier: usize) {
     let increase_by = empty_space_multiplier.saturating_sub(1); // Saturating just in case
     stars.iter_mut().for_each(|star| {
         // partition point uses a binary search
@@ -218,13 +215,13 @@ fn update_star_positions(stars: &mut Vec<Star>, rows: &Vec<usize>, columns: &Vec
 }
 
 /// Compute the sum of all pairwise distances between stars
-fn compute_sum_pair_distances(stars: &Vec<Star>) -> usize {
+fn compute_sum_pair_distances(stars: &[Position]) -> usize {
     // Iterate over each pair
     stars.iter().combinations(2)
         // Compute the distance
         .map(|stars| {
-            let star1 = stars[0];
-            let star2 = stars[1];
+            let star1 = *stars[0];
+            let star2 = *stars[1];
             star1.distance_to(star2)
         })
         // Add it up
@@ -235,9 +232,9 @@ fn compute_sum_pair_distances(stars: &Vec<Star>) -> usize {
 /// Part 1 is solved with a multiplier of 2
 /// Part 2 is solved with a multiplier of 1000000
 /// Other values are only used for unit tests
-fn get_sum_of_lengths<'a>(image: impl Iterator<Item=&'a str>, empty_space_multiplier: usize) -> usize {
-    let (mut stars, rows, columns) = extract(image);
-    update_star_positions(&mut stars, &rows, &columns, empty_space_multiplier);
+pub fn get_sum_of_lengths((stars, rows, columns): &Image, empty_space_multiplier: usize) -> usize {
+    let mut stars = stars.clone();
+    update_star_positions(&mut stars, rows, columns, empty_space_multiplier);
     compute_sum_pair_distances(&stars)
 }
 
@@ -246,7 +243,7 @@ fn test_part1() {
     assert_eq!(
         374,
         get_sum_of_lengths(
-            r"...#......
+            &extract(r"...#......
 .......#..
 #.........
 ..........
@@ -255,7 +252,7 @@ fn test_part1() {
 .........#
 ..........
 .......#..
-#...#.....".lines(),
+#...#.....".lines()).unwrap(),
             2,
         )
     )
@@ -266,7 +263,7 @@ fn test_part2a() {
     assert_eq!(
         1030,
         get_sum_of_lengths(
-            r"...#......
+            &extract(r"...#......
 .......#..
 #.........
 ..........
@@ -275,7 +272,7 @@ fn test_part2a() {
 .........#
 ..........
 .......#..
-#...#.....".lines(),
+#...#.....".lines()).unwrap(),
             10,
         )
     )
@@ -286,7 +283,7 @@ fn test_part2b() {
     assert_eq!(
         8410,
         get_sum_of_lengths(
-            r"...#......
+            &extract(r"...#......
 .......#..
 #.........
 ..........
@@ -295,9 +292,14 @@ fn test_part2b() {
 .........#
 ..........
 .......#..
-#...#.....".lines(),
+#...#.....".lines()).unwrap(),
             100,
         )
     )
 }
 
+
+#[test]
+fn test_parse_error() {
+    assert_eq!(Err(ParseError::at(1, 3, "*", "`.` or `#`")), extract("...#\n...*".lines()));
+}
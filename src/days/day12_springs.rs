@@ -160,65 +160,86 @@ Unfold your condition records; what is the new sum of possible arrangement
 counts?
  */
 
-use std::fs;
-use std::str::FromStr;
+use crate::parse::{self, Line, ParseError};
+use crate::Solution;
 
-use itertools::Itertools;
+/// Day 12: Hot Springs
+pub struct HotSprings;
 
-fn main() {
-    let file = fs::read_to_string("./inputs/12_springs.txt").unwrap();
-    let condition_records = file.lines();
-    println!("{}", get_total_possible_spring_arrangements(condition_records.clone()));
-    println!("{}", get_total_possible_folded_spring_arrangements(condition_records));
+impl Solution for HotSprings {
+    const DAY: u8 = 12;
+    const TITLE: &'static str = "Hot Springs";
+    const INPUT: &'static str = "./inputs/12_springs.txt";
+
+    type Input<'a> = Vec<Record>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        preprocess(input.lines())
+    }
+
+    fn part1(condition_records: &Self::Input<'_>) -> usize {
+        get_total_possible_spring_arrangements(condition_records)
+    }
+
+    fn part2(condition_records: &Self::Input<'_>) -> usize {
+        get_total_possible_folded_spring_arrangements(condition_records)
+    }
 }
 
 /// An enum to describe the type of a spring/tile/position
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
-enum Spring {
+pub enum Spring {
     Operational,
     Damaged,
     Unknown,
 }
 
-impl From<char> for Spring {
-    fn from(value: char) -> Self {
+impl Spring {
+    /// Get the spring state from a character
+    fn from_char(value: char) -> Option<Self> {
         match value {
-            '.' => Spring::Operational,
-            '#' => Spring::Damaged,
-            '?' => Spring::Unknown,
-            _ => panic!("Unknown Spring State {value}"),
+            '.' => Some(Spring::Operational),
+            '#' => Some(Spring::Damaged),
+            '?' => Some(Spring::Unknown),
+            _ => None,
         }
     }
 }
 
 /// A condition record line from the input
-#[derive(Debug)]
-struct Record {
+#[derive(Debug, Clone)]
+pub struct Record {
     springs: Vec<Spring>,
     damaged_groups: Vec<usize>,
 }
 
 /// Preprocess a record from a string to a more useful type
-fn preprocess_record(condition_record: &str) -> Record {
-    let mut condition_record = condition_record.split(' ');
+fn preprocess_record(condition_record: Line) -> Result<Record, ParseError> {
+    let (springs, damaged_groups) = condition_record.split_once(condition_record.text, " ")?;
     // First section is the springs
-    let springs = condition_record
-        .next()
-        .unwrap()
-        .chars()
-        .map_into()
-        .collect();
+    let springs = springs
+        .char_indices()
+        .map(|(i, spring)| Spring::from_char(spring)
+            .ok_or_else(|| condition_record.error(&springs[i..=i], "`.`, `#`, or `?`")))
+        .collect::<Result<_, _>>()?;
     // Second section is the damaged groups
-    let damaged_groups = condition_record
-        .next()
-        .unwrap()
+    let damaged_groups = damaged_groups
         .split(',')
-        .map(|group_size| usize::from_str(group_size).unwrap())
-        .collect();
-    Record {
+        .map(|group_size| condition_record.parse(group_size, "the size of a damaged group"))
+        .collect::<Result<_, _>>()?;
+    Ok(Record {
         springs,
         damaged_groups,
-    }
+    })
+}
+
+/// Preprocess all of the condition records
+pub fn preprocess<'a>(condition_records: impl Iterator<Item=&'a str>) -> Result<Vec<Record>, ParseError> {
+    parse::lines(condition_records)
+        .map(preprocess_record)
+        .collect()
 }
 
 /// Unfold a record into another with the five copies of itself
@@ -333,23 +354,21 @@ fn get_possible_arrangements(record: Record) -> usize {
                 } else {
                     // We want to make sure that this is a valid spot for a damaged spring
                     let mut is_valid = true;
-                    let mut final_spring_index;
-
                     // Make sure the preceding springs in the damage group are non-operational
                     for i in 1..damage_group {
                         is_valid &= record.springs[spring_index - 1 - i] != Spring::Operational;
                     }
 
                     // If there is a spring right before the damage group
-                    if damage_group < spring_index {
+                    let final_spring_index = if damage_group < spring_index {
                         // Make sure it is not damaged
                         is_valid &= record.springs[spring_index - 1 - damage_group] != Spring::Damaged;
                         // We grab from the spring before final_spring_index as we want to grab
                         // from that last spring AS IF it was operational as it might be unknown
-                        final_spring_index = spring_index - 1 - damage_group;
+                        spring_index - 1 - damage_group
                     } else {
-                        final_spring_index = 0; // only way that there is no spring before it is if it is first
-                    }
+                        0 // only way that there is no spring before it is if it is first
+                    };
 
                     // If this is valid for a run of damaged springs, return that
                     // otherwise 0
@@ -372,17 +391,17 @@ fn get_possible_arrangements(record: Record) -> usize {
 
 
 /// Solve part1
-fn get_total_possible_spring_arrangements<'a>(condition_records: impl Iterator<Item=&'a str>) -> usize {
-    condition_records
-        .map(preprocess_record)
+pub fn get_total_possible_spring_arrangements(condition_records: &[Record]) -> usize {
+    condition_records.iter()
+        .cloned()
         .map(get_possible_arrangements)
         .sum()
 }
 
 /// Solve part2
-fn get_total_possible_folded_spring_arrangements<'a>(condition_records: impl Iterator<Item=&'a str>) -> usize {
-    condition_records
-        .map(preprocess_record)
+pub fn get_total_possible_folded_spring_arrangements(condition_records: &[Record]) -> usize {
+    condition_records.iter()
+        .cloned()
         .map(unfold)
         .map(get_possible_arrangements)
         .sum()
@@ -393,12 +412,12 @@ fn test_part1() {
     assert_eq!(
         21,
         get_total_possible_spring_arrangements(
-            r"???.### 1,1,3
+            &preprocess(r"???.### 1,1,3
 .??..??...?##. 1,1,3
 ?#?#?#?#?#?#?#? 1,3,1,6
 ????.#...#... 4,1,1
 ????.######..#####. 1,6,5
-?###???????? 3,2,1".lines()
+?###???????? 3,2,1".lines()).unwrap()
         )
     )
 }
@@ -408,12 +427,12 @@ fn test_part2() {
     assert_eq!(
         525152,
         get_total_possible_folded_spring_arrangements(
-            r"???.### 1,1,3
+            &preprocess(r"???.### 1,1,3
 .??..??...?##. 1,1,3
 ?#?#?#?#?#?#?#? 1,3,1,6
 ????.#...#... 4,1,1
 ????.######..#####. 1,6,5
-?###???????? 3,2,1".lines()
+?###???????? 3,2,1".lines()).unwrap()
         )
     )
 }
@@ -424,12 +443,25 @@ fn test_part2_input_debug() {
     assert_eq!(
         103175004, // Computed using the non-dynamic version
         get_total_possible_folded_spring_arrangements(
-            r"#??????.??.??? 4,1,1,1".lines()
+            &preprocess(r"#??????.??.??? 4,1,1,1".lines()).unwrap()
         )
     )
 }
 
 
+#[test]
+fn test_parse_error() {
+    assert_eq!(
+        ParseError::at(1, 2, "x", "`.`, `#`, or `?`"),
+        preprocess("???.### 1,1,3\n.?x. 1".lines()).unwrap_err()
+    );
+    assert_eq!(
+        ParseError::at(0, 10, "1;3", "the size of a damaged group"),
+        preprocess("???.### 1,1;3".lines()).unwrap_err()
+    );
+}
+
+
 /*
 Code for an old implementation which was implemented without dynamic programming
 
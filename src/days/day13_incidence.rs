@@ -162,36 +162,58 @@ pattern in your notes?
  */
 
 use std::cmp::min;
-use std::fs;
 
-fn main() {
-    let file = fs::read_to_string("./inputs/13_incidence.txt").unwrap();
-    let patterns = file.lines();
-    println!("{}", get_note_summaries(patterns.clone()));
-    println!("{}", get_note_summaries_smudged(patterns));
+use crate::grid::Grid;
+use crate::parse::{self, ParseError};
+use crate::Solution;
+
+/// Day 13: Point of Incidence
+pub struct PointOfIncidence;
+
+impl Solution for PointOfIncidence {
+    const DAY: u8 = 13;
+    const TITLE: &'static str = "Point of Incidence";
+    const INPUT: &'static str = "./inputs/13_incidence.txt";
+
+    type Input<'a> = Vec<Pattern>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        preprocess(input.lines())
+    }
+
+    fn part1(patterns: &Self::Input<'_>) -> usize {
+        get_note_summaries(patterns)
+    }
+
+    fn part2(patterns: &Self::Input<'_>) -> usize {
+        get_note_summaries_smudged(patterns)
+    }
 }
 
 /// An enum corresponding to the type of tile on the ground
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
-enum Ground {
+pub enum Ground {
     Ash,
     Rocks,
 }
 
-impl From<char> for Ground {
-    fn from(value: char) -> Self {
+impl Ground {
+    /// Get the type of ground from a character
+    fn from_char(value: char) -> Option<Self> {
         match value {
-            '.' => Ground::Ash,
-            '#' => Ground::Rocks,
-            _ => panic!("Unknown Ground {value}")
+            '.' => Some(Ground::Ash),
+            '#' => Some(Ground::Rocks),
+            _ => None
         }
     }
 }
 
 /// A struct to hold a pattern/section in the input
 #[derive(Debug)]
-struct Pattern {
-    pattern: Vec<Vec<Ground>>,
+pub struct Pattern {
+    pattern: Grid<Ground>,
 }
 
 /// Represents a line of reflection
@@ -212,46 +234,51 @@ impl Reflection {
 }
 
 /// Preprocess the input into a vector of patterns
-fn preprocess<'a>(patterns: impl Iterator<Item=&'a str>) -> Vec<Pattern> {
+pub fn preprocess<'a>(patterns: impl Iterator<Item=&'a str>) -> Result<Vec<Pattern>, ParseError> {
     let mut result = vec![];
-    let mut next_pattern = Pattern {
-        pattern: vec![],
-    };
+    // The index of the first line of each pattern along with its lines
+    let mut next_pattern = (0, vec![]);
 
-    for pattern_line in patterns {
-        if pattern_line.is_empty() {
+    for line in parse::lines(patterns) {
+        if line.text.is_empty() {
             result.push(next_pattern);
-            next_pattern = Pattern {
-                pattern: vec![]
-            };
+            next_pattern = (line.index + 1, vec![]);
         } else {
-            next_pattern.pattern.push(pattern_line.chars().map(|char| char.into()).collect())
+            next_pattern.1.push(line.text)
         }
     }
     result.push(next_pattern);
 
-    result
+    result.into_iter()
+        .map(|(first_line, rows)| {
+            Grid::parse_with(rows.into_iter(), "`.` or `#`", Ground::from_char)
+                // The grid only knows about the lines in this pattern
+                .map_err(|error| ParseError { line: error.line + first_line, ..error })
+                .map(|pattern| Pattern { pattern })
+        })
+        .collect()
 }
 
-/// Search for a horizontal reflection line for a given pattern with a certain amount of expected errors
+/// Search for a line of reflection between two rows of a grid with a certain amount of expected errors
+/// Returns the number of rows above the line
 ///
 /// `error_count` is used to allow for part1 and part2 to be solved with the same algorithm
 /// part1 is called with `error_count == 0`
 /// part2 is called with `error_count == 1`
-fn search_horizontal_line_reflection(pattern: &Pattern, error_count: usize) -> Option<Reflection> {
+fn search_row_reflection(grid: &Grid<Ground>, error_count: usize) -> Option<usize> {
     // A label is added here to allow us to quickly break out of all inner loops when we determine that
     // a given location cannot be a valid line of reflection
-    'outer: for second_row in 1..pattern.pattern.len() {
+    'outer: for second_row in 1..grid.height() {
         let first_row = second_row - 1;
         // We are going to loop over each spot between rows
         // first row is the one on top, second row is the one on the bottom
         let mut errors = 0_usize;
         // Now we loop outwards from this pair of rows until we reach the edge
-        for distance in 0..=min(first_row, pattern.pattern.len() - second_row - 1) {
+        for distance in 0..=min(first_row, grid.height() - second_row - 1) {
             // Now we loop across those rows
-            for i in 0..pattern.pattern[0].len() {
+            for (above, below) in grid.row(first_row - distance).iter().zip(grid.row(second_row + distance)) {
                 // If a tile doesn't match, count it as an error
-                if pattern.pattern[first_row - distance][i] != pattern.pattern[second_row + distance][i] {
+                if above != below {
                     errors += 1;
                     // Short circuit this row if we already know it cannot be a reflection
                     // Ideally almost every time we go around the outer loop we hit this very quickly
@@ -267,64 +294,51 @@ fn search_horizontal_line_reflection(pattern: &Pattern, error_count: usize) -> O
         // This is for part2
         // If part2 had a higher error_count than 1, this would be much more computationally intensive
         if errors == error_count {
-            return Some(Reflection::Horizontal(second_row));
+            return Some(second_row);
         }
     }
     None
 }
 
+/// Search for a horizontal reflection line for a given pattern with a certain amount of expected errors
+fn search_horizontal_line_reflection(pattern: &Pattern, error_count: usize) -> Option<Reflection> {
+    search_row_reflection(&pattern.pattern, error_count).map(Reflection::Horizontal)
+}
+
+/// Search for a vertical reflection line for a given pattern with a certain amount of expected errors
 fn search_vertical_line_reflection(pattern: &Pattern, error_count: usize) -> Option<Reflection> {
-    // This is basically the same thing as horizontal, but transposed
-    'outer: for second_column in 1..pattern.pattern[0].len() {
-        let first_column = second_column - 1;
-        let mut errors = 0_usize;
-        for distance in 0..=min(first_column, pattern.pattern[0].len() - second_column - 1) {
-            for i in 0..pattern.pattern.len() {
-                if pattern.pattern[i][first_column - distance] != pattern.pattern[i][second_column + distance] {
-                    errors += 1;
-                    if errors > error_count {
-                        continue 'outer;
-                    }
-                }
-            }
-        }
-        if errors == error_count {
-            return Some(Reflection::Vertical(second_column));
-        }
-    }
-    None
+    // This is the same thing as horizontal, but transposed
+    search_row_reflection(&pattern.pattern.transpose(), error_count).map(Reflection::Vertical)
 }
 
 /// Find a normal reflection
-fn find_reflection(pattern: Pattern) -> Reflection {
+fn find_reflection(pattern: &Pattern) -> Reflection {
     // Try to get a horizontal line
-    search_horizontal_line_reflection(&pattern, 0)
+    search_horizontal_line_reflection(pattern, 0)
         // Otherwise we know its a vertical line (by the nature of the problem)
-        .or_else(|| search_vertical_line_reflection(&pattern, 0))
+        .or_else(|| search_vertical_line_reflection(pattern, 0))
         .unwrap()
 }
 
 /// Solve part1
-fn get_note_summaries<'a>(patterns: impl Iterator<Item=&'a str>) -> usize {
-    let patterns = preprocess(patterns);
-    patterns.into_iter()
+pub fn get_note_summaries(patterns: &[Pattern]) -> usize {
+    patterns.iter()
         .map(find_reflection)
         .map(Reflection::value)
         .sum()
 }
 
 /// Find a smudged reflection
-fn find_reflection_smudged(pattern: Pattern) -> Reflection {
+fn find_reflection_smudged(pattern: &Pattern) -> Reflection {
     // Same as part1 but with error_count of 1
-    search_horizontal_line_reflection(&pattern, 1)
-        .or_else(|| search_vertical_line_reflection(&pattern, 1))
+    search_horizontal_line_reflection(pattern, 1)
+        .or_else(|| search_vertical_line_reflection(pattern, 1))
         .unwrap()
 }
 
 /// Solve part2
-fn get_note_summaries_smudged<'a>(patterns: impl Iterator<Item=&'a str>) -> usize {
-    let patterns = preprocess(patterns);
-    patterns.into_iter()
+pub fn get_note_summaries_smudged(patterns: &[Pattern]) -> usize {
+    patterns.iter()
         .map(find_reflection_smudged)
         .map(Reflection::value)
         .sum()
@@ -335,7 +349,7 @@ fn test_part1() {
     assert_eq!(
         405,
         get_note_summaries(
-            r"#.##..##.
+            &preprocess(r"#.##..##.
 ..#.##.#.
 ##......#
 ##......#
@@ -349,7 +363,7 @@ fn test_part1() {
 #####.##.
 #####.##.
 ..##..###
-#....#..#".lines()
+#....#..#".lines()).unwrap()
         )
     );
 }
@@ -359,7 +373,7 @@ fn test_part2() {
     assert_eq!(
         400,
         get_note_summaries_smudged(
-            r"#.##..##.
+            &preprocess(r"#.##..##.
 ..#.##.#.
 ##......#
 ##......#
@@ -373,8 +387,16 @@ fn test_part2() {
 #####.##.
 #####.##.
 ..##..###
-#....#..#".lines()
+#....#..#".lines()).unwrap()
         )
     );
 }
 
+
+#[test]
+fn test_parse_error() {
+    assert_eq!(
+        ParseError::at(4, 1, "o", "`.` or `#`"),
+        preprocess("#.#\n.#.\n\n#.\n.o".lines()).unwrap_err()
+    );
+}
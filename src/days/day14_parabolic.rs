@@ -142,146 +142,117 @@ on the north support beams?
  */
 
 use std::collections::HashMap;
-use std::fmt::{Debug, Display};
-use std::fs;
-
-fn main() {
-    let file = fs::read_to_string("./inputs/14_parabolic.txt").unwrap();
-    let input = file.lines();
-    println!("{}", get_total_load_north_tilted(input.clone()));
-    println!("{}", get_cycled_load_north(input));
+use std::fmt::Debug;
+
+use crate::grid::{Grid, Position};
+use crate::parse::ParseError;
+use crate::Solution;
+
+/// Day 14: Parabolic Reflector Dish
+pub struct ParabolicReflectorDish;
+
+impl Solution for ParabolicReflectorDish {
+    const DAY: u8 = 14;
+    const TITLE: &'static str = "Parabolic Reflector Dish";
+    const INPUT: &'static str = "./inputs/14_parabolic.txt";
+
+    type Input<'a> = Input;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        preprocess(input.lines())
+    }
+
+    fn part1(input: &Self::Input<'_>) -> usize {
+        get_total_load_north_tilted(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> usize {
+        get_cycled_load_north(input)
+    }
 }
 
 /// Represents a tile in the world
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
-enum Tile {
+pub enum Tile {
     Empty,
     Cube,
     Rounded,
 }
 
-impl From<char> for Tile {
-    fn from(value: char) -> Self {
+impl Tile {
+    /// Get the tile from a character
+    fn from_char(value: char) -> Option<Self> {
         match value {
-            '.' => Tile::Empty,
-            '#' => Tile::Cube,
-            'O' => Tile::Rounded,
-            _ => panic!("Unknown tile {value}"),
+            '.' => Some(Tile::Empty),
+            '#' => Some(Tile::Cube),
+            'O' => Some(Tile::Rounded),
+            _ => None,
         }
     }
 }
 
 /// Represents the input to the problem i.e. the map
-#[derive(Clone, Eq, PartialEq, Hash)]
-struct Input {
-    columns: Vec<Vec<Tile>>,
-}
+pub type Input = Grid<Tile>;
 
 /// Preprocess the string input into a more useful form
-fn preprocess<'a>(input: impl Iterator<Item=&'a str>) -> Input {
-    let mut result = Input {
-        columns: vec![]
-    };
-    for row in input {
-        for (col, tile) in row.char_indices() {
-            while result.columns.len() <= col {
-                result.columns.push(vec![]);
-            }
-            result.columns[col].push(tile.into());
-        }
-    }
-    result
+pub fn preprocess<'a>(input: impl Iterator<Item=&'a str>) -> Result<Input, ParseError> {
+    Grid::parse_with(input, "`.`, `#`, or `O`", Tile::from_char)
 }
 
 /// Compute the total load for a given input
-fn compute_total_load(input: Input) -> usize {
-    input.columns.into_iter().map(|column| {
-        let column_length = column.len();
-        column.into_iter().enumerate().map(|(i, tile)| {
-            match tile {
-                Tile::Empty => 0, // NoOp
-                Tile::Cube => 0,
-                Tile::Rounded => {
-                    // column_length is 1 greater than the index of the last column so this
-                    // is the total amount of rows including this one from the bottom
-                    column_length - i
-                }
+fn compute_total_load(input: &Input) -> usize {
+    input.iter()
+        .filter(|(_, &tile)| tile == Tile::Rounded)
+        // The height is 1 greater than the index of the last row so this is the total amount of
+        // rows including this one from the bottom
+        .map(|(position, _)| input.height() - position.row)
+        .sum()
+}
+
+/// Slide the rounded stones along a line of positions towards the first of them
+fn slide_line(input: &mut Input, line: impl Iterator<Item=Position>) {
+    let line = line.collect::<Vec<_>>();
+    let mut slide_to = 0;
+    for (i, &position) in line.iter().enumerate() {
+        match input[position] {
+            Tile::Empty => {}
+            Tile::Cube => slide_to = i + 1,
+            Tile::Rounded => {
+                input[position] = Tile::Empty;
+                input[line[slide_to]] = Tile::Rounded;
+                slide_to += 1;
             }
-        }).sum::<usize>()
-    }).sum()
+        }
+    }
 }
 
 /// Slide the rounded stones north
 fn slide_north(input: &mut Input) {
-    input.columns.iter_mut().for_each(|column| {
-        let mut slide_to = 0;
-        for i in 0..column.len() {
-            match column[i] {
-                Tile::Empty => {}
-                Tile::Cube => slide_to = i + 1,
-                Tile::Rounded => {
-                    column[i] = Tile::Empty;
-                    column[slide_to] = Tile::Rounded;
-                    slide_to += 1;
-                }
-            }
-        }
-    });
+    for column in 0..input.width() {
+        slide_line(input, (0..input.height()).map(|row| Position::new(row, column)));
+    }
 }
 
 /// Slide the rounded stones south
 fn slide_south(input: &mut Input) {
-    input.columns.iter_mut().for_each(|column| {
-        let mut slide_to = column.len() - 1;
-        for i in 0..column.len() {
-            let i = column.len() - 1 - i;
-            match column[i] {
-                Tile::Empty => {}
-                Tile::Cube => slide_to = i.saturating_sub(1),
-                Tile::Rounded => {
-                    column[i] = Tile::Empty;
-                    column[slide_to] = Tile::Rounded;
-                    slide_to = slide_to.saturating_sub(1);
-                }
-            }
-        }
-    });
+    for column in 0..input.width() {
+        slide_line(input, (0..input.height()).rev().map(|row| Position::new(row, column)));
+    }
 }
 
 /// Slide the rounded stones west
 fn slide_west(input: &mut Input) {
-    for row in 0..input.columns[0].len() {
-        let mut slide_to = 0;
-        for col in 0..input.columns.len() {
-            match input.columns[col][row] {
-                Tile::Empty => {}
-                Tile::Cube => slide_to = col + 1,
-                Tile::Rounded => {
-                    input.columns[col][row] = Tile::Empty;
-                    input.columns[slide_to][row] = Tile::Rounded;
-                    slide_to += 1;
-                }
-            }
-        }
+    for row in 0..input.height() {
+        slide_line(input, (0..input.width()).map(|column| Position::new(row, column)));
     }
 }
 
 /// Slide the rounded stones east
 fn slide_east(input: &mut Input) {
-    for row in 0..input.columns[0].len() {
-        let mut slide_to = input.columns[0].len() - 1;
-        for col in 0..input.columns.len() {
-            let col = input.columns.len() - 1 - col;
-            match input.columns[col][row] {
-                Tile::Empty => {}
-                Tile::Cube => slide_to = col.saturating_sub(1),
-                Tile::Rounded => {
-                    input.columns[col][row] = Tile::Empty;
-                    input.columns[slide_to][row] = Tile::Rounded;
-                    slide_to = slide_to.saturating_sub(1);
-                }
-            }
-        }
+    for row in 0..input.height() {
+        slide_line(input, (0..input.width()).rev().map(|column| Position::new(row, column)));
     }
 }
 
@@ -306,9 +277,9 @@ fn cycle_long(input: &mut Input) {
     let mut visited: HashMap<Input, usize> = HashMap::new();
     let mut cycle_memory: Vec<Input> = vec![];
     for i in 0..GOAL {
-        if visited.contains_key(&input) {
+        if visited.contains_key(input) {
             // We've found a cycle
-            let cycle_start = *visited.get(&input).unwrap();
+            let cycle_start = *visited.get(input).unwrap();
             let cycle_period = i - cycle_start; // Both i and cycle_start
 
             // i == 0 means cycle has not been called
@@ -334,17 +305,17 @@ fn cycle_long(input: &mut Input) {
 }
 
 /// Solve part1
-fn get_total_load_north_tilted<'a>(input: impl Iterator<Item=&'a str>) -> usize {
-    let mut input = preprocess(input);
+pub fn get_total_load_north_tilted(input: &Input) -> usize {
+    let mut input = input.clone();
     slide_north(&mut input);
-    compute_total_load(input)
+    compute_total_load(&input)
 }
 
 /// Solve part2
-fn get_cycled_load_north<'a>(input: impl Iterator<Item=&'a str>) -> usize {
-    let mut input = preprocess(input);
+pub fn get_cycled_load_north(input: &Input) -> usize {
+    let mut input = input.clone();
     cycle_long(&mut input);
-    compute_total_load(input)
+    compute_total_load(&input)
 }
 
 #[test]
@@ -352,7 +323,7 @@ fn test_part1() {
     assert_eq!(
         136,
         get_total_load_north_tilted(
-            r"O....#....
+            &preprocess(r"O....#....
 O.OO#....#
 .....##...
 OO.#O....O
@@ -361,7 +332,7 @@ O.#..O.#.#
 ..O..#O..O
 .......O..
 #....###..
-#OO..#....".lines()
+#OO..#....".lines()).unwrap()
         )
     );
 }
@@ -371,7 +342,7 @@ fn test_part2() {
     assert_eq!(
         64,
         get_cycled_load_north(
-            r"O....#....
+            &preprocess(r"O....#....
 O.OO#....#
 .....##...
 OO.#O....O
@@ -380,7 +351,15 @@ O.#..O.#.#
 ..O..#O..O
 .......O..
 #....###..
-#OO..#....".lines()
+#OO..#....".lines()).unwrap()
         )
     );
 }
+
+#[test]
+fn test_parse_error() {
+    assert_eq!(
+        Some(ParseError::at(1, 0, "0", "`.`, `#`, or `O`")),
+        preprocess("O.#\n0.#".lines()).err()
+    );
+}
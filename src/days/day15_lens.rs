@@ -228,20 +228,51 @@ initialization sequence. What is the focusing power of the resulting lens
 configuration?
  */
 
-use std::fs;
+use crate::parse::{self, Line, ParseError};
+use crate::Solution;
 
-fn main() {
-    let file = fs::read_to_string("./inputs/15_lens.txt").unwrap();
-    let initialization_sequence = file.lines();
-    println!("{}", get_hash_sum(initialization_sequence.clone()));
-    println!("{}", get_focusing_power(initialization_sequence));
+/// Day 15: Lens Library
+pub struct LensLibrary;
+
+impl Solution for LensLibrary {
+    const DAY: u8 = 15;
+    const TITLE: &'static str = "Lens Library";
+    const INPUT: &'static str = "./inputs/15_lens.txt";
+
+    type Input<'a> = Vec<Step<'a>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        preprocess(input.lines())
+    }
+
+    fn part1(steps: &Self::Input<'_>) -> usize {
+        get_hash_sum(steps)
+    }
+
+    fn part2(steps: &Self::Input<'_>) -> usize {
+        get_focusing_power(steps)
+    }
+}
+
+/// A step of the initialization sequence
+pub struct Step<'a> {
+    /// The step exactly as it appears in the input (this is what gets hashed in part 1)
+    text: &'a str,
+    /// What the step does to the boxes
+    action: Action<'a>,
 }
 
 /// Initial preprocessing of the input string into the set of steps
-fn preprocess<'a>(mut initialization_sequence: impl Iterator<Item=&'a str>) -> impl Iterator<Item=&'a str> {
-    let initialization_sequence = initialization_sequence.next().unwrap();
-    let steps = initialization_sequence.split(',');
-    steps
+pub fn preprocess<'a>(initialization_sequence: impl Iterator<Item=&'a str>) -> Result<Vec<Step<'a>>, ParseError> {
+    let initialization_sequence = parse::lines(initialization_sequence).next()
+        .ok_or_else(|| ParseError::at(0, 0, "", "an initialization sequence"))?;
+    let steps = initialization_sequence.text.split(',');
+    steps.map(|step| Ok(Step {
+        text: step,
+        action: get_action(initialization_sequence, step)?,
+    })).collect()
 }
 
 /// Run the hash algorithm on  a string
@@ -254,9 +285,8 @@ fn hash(string: &str) -> u8 {
 }
 
 /// Solve part 1
-fn get_hash_sum<'a>(initialization_sequence: impl Iterator<Item=&'a str>) -> usize {
-    let steps = preprocess(initialization_sequence);
-    steps.map(hash).map(|x| x as usize).sum()
+pub fn get_hash_sum(steps: &[Step]) -> usize {
+    steps.iter().map(|step| hash(step.text)).map(|x| x as usize).sum()
 }
 
 /// Represents one of the possible actions for a step
@@ -279,47 +309,40 @@ struct Item<'a> {
     focal_length: usize,
 }
 
-/// Convert a step into an action
-fn get_action(step: &str) -> Action {
-    if &step[step.len() - 1..] == "-" {
-        let label = &step[0..(step.len() - 1)];
-        Action::Remove {
+/// Convert a step (which is a slice of the line) into an action
+fn get_action<'a>(line: Line<'a>, step: &'a str) -> Result<Action<'a>, ParseError> {
+    if let Some(label) = step.strip_suffix('-') {
+        Ok(Action::Remove {
             from_box: hash(label),
             label,
-        }
+        })
     } else {
-        let label = &step[0..(step.len() - 2)];
-        Action::Insert {
+        let (label, focal_length) = line.split_once(step, "=")
+            .map_err(|_| line.error(step, "a label followed by `-` or `=`"))?;
+        Ok(Action::Insert {
             into_box: hash(label),
             label,
-            // Rust makes accessing a string as if it was an array of characters very difficult
-            // (for good reason *cough* unicode *cough*)
-            // However, I know that this is an ASCII string (which we can take advantage of the fact
-            // that UTF-8 is backwards compatible with ASCII
-            // So we can grab the bytes (which gives us the string as an array of ASCII characters
-            // (since we know we have no non-ASCII characters)
-            // So we can just grab the last byte and parse out of being a digit
-            focal_length: (step.as_bytes()[step.len() - 1] as usize) - ('0' as usize),
-        }
+            focal_length: line.parse(focal_length, "a focal length")?,
+        })
     }
 }
 
 /// Solve part 2
-fn get_focusing_power<'a>(initialization_sequence: impl Iterator<Item=&'a str>) -> usize {
-    // Preprocess
-    let steps = preprocess(initialization_sequence).map(get_action);
+pub fn get_focusing_power(steps: &[Step]) -> usize {
+    // Get the action for each step
+    let steps = steps.iter().map(|step| &step.action);
 
     // Our model for the boxes
     let mut boxes = vec![Vec::<Item>::new(); 256];
 
     // Apply each step to modify the boxes
     steps.for_each(|step| {
-        match step {
+        match *step {
             Action::Remove { from_box, label } => {
                 boxes[from_box as usize].retain(|x| x.label != label);
             }
             Action::Insert { into_box, label, focal_length } => {
-                let mut position = boxes[into_box as usize].iter_mut().find(|x| x.label == label);
+                let position = boxes[into_box as usize].iter_mut().find(|x| x.label == label);
                 if let Some(element) = position {
                     element.focal_length = focal_length;
                 } else {
@@ -345,7 +368,7 @@ fn test_part1() {
     assert_eq!(
         1320,
         get_hash_sum(
-            r"rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7".lines()
+            &preprocess(r"rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7".lines()).unwrap()
         )
     );
 }
@@ -355,7 +378,19 @@ fn test_part2() {
     assert_eq!(
         145,
         get_focusing_power(
-            r"rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7".lines()
+            &preprocess(r"rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7".lines()).unwrap()
         )
     );
 }
+
+#[test]
+fn test_parse_error() {
+    assert_eq!(
+        Some(ParseError::at(0, 9, "cm", "a label followed by `-` or `=`")),
+        preprocess("rn=1,cm-,cm,qp=3".lines()).err()
+    );
+    assert_eq!(
+        Some(ParseError::at(0, 8, "x", "a focal length")),
+        preprocess("rn=1,qp=x".lines()).err()
+    );
+}
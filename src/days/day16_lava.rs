@@ -143,18 +143,39 @@ tiles; how many tiles are energized in that configuration?
 
 use std::cmp::max;
 use std::collections::HashSet;
-use std::fs;
 
-fn main() {
-    let file = fs::read_to_string("./inputs/16_lava.txt").unwrap();
-    let layout = file.lines();
-    println!("{}", get_tile_energized_count(layout.clone()));
-    println!("{}", get_max_tile_energized_count(layout));
+use crate::grid::{Direction, Grid, Position};
+use crate::parse::ParseError;
+use crate::Solution;
+
+/// Day 16: The Floor Will Be Lava
+pub struct FloorWillBeLava;
+
+impl Solution for FloorWillBeLava {
+    const DAY: u8 = 16;
+    const TITLE: &'static str = "The Floor Will Be Lava";
+    const INPUT: &'static str = "./inputs/16_lava.txt";
+
+    type Input<'a> = Grid<Tile>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        preprocess(input.lines())
+    }
+
+    fn part1(layout: &Self::Input<'_>) -> usize {
+        get_tile_energized_count(layout)
+    }
+
+    fn part2(layout: &Self::Input<'_>) -> usize {
+        get_max_tile_energized_count(layout)
+    }
 }
 
 /// An enum to describe the type of a mirror
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
-enum MirrorType {
+pub enum MirrorType {
     TopLeftBottomRight,
     TopRightBottomLeft,
 }
@@ -181,98 +202,44 @@ impl MirrorType {
 
 /// An enum representing an axis
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
-enum Axis {
+pub enum Axis {
     Vertical,
     Horizontal,
 }
 
 /// An enum representing a tile on the layout
 #[derive(Debug, Eq, PartialEq)]
-enum Tile {
+pub enum Tile {
     Empty,
     Mirror(MirrorType),
     Splitter(Axis),
 }
 
-impl From<char> for Tile {
-    fn from(value: char) -> Self {
+impl Tile {
+    /// Get the tile from a character
+    fn from_char(value: char) -> Option<Self> {
         match value {
-            '.' => Tile::Empty,
-            '/' => Tile::Mirror(MirrorType::TopRightBottomLeft),
-            '\\' => Tile::Mirror(MirrorType::TopLeftBottomRight),
-            '-' => Tile::Splitter(Axis::Vertical),
-            '|' => Tile::Splitter(Axis::Horizontal),
-            _ => panic!("Unknown Tile {value}")
+            '.' => Some(Tile::Empty),
+            '/' => Some(Tile::Mirror(MirrorType::TopRightBottomLeft)),
+            '\\' => Some(Tile::Mirror(MirrorType::TopLeftBottomRight)),
+            '-' => Some(Tile::Splitter(Axis::Vertical)),
+            '|' => Some(Tile::Splitter(Axis::Horizontal)),
+            _ => None
         }
     }
 }
 
-/// A beam representing a direction
-#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
-enum Direction {
-    Up,
-    Right,
-    Down,
-    Left,
-}
-
-impl Direction {
-    /// Get the axis which this direction is traveling along
-    fn to_axis(self) -> Axis {
-        match self {
-            Direction::Up => Axis::Vertical,
-            Direction::Right => Axis::Horizontal,
-            Direction::Down => Axis::Vertical,
-            Direction::Left => Axis::Horizontal,
+impl Axis {
+    /// Get the axis which a direction is traveling along
+    fn of(direction: Direction) -> Self {
+        if direction.is_vertical() {
+            Axis::Vertical
+        } else {
+            Axis::Horizontal
         }
     }
 }
 
-/// Represents a position on the world/layout
-#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
-struct Position {
-    x: isize,
-    y: isize,
-}
-
-impl Position {
-    /// Step this position in a given direction
-    fn step(self, direction: Direction) -> Self {
-        match direction {
-            Direction::Up => Self {
-                x: self.x,
-                y: self.y - 1,
-            },
-            Direction::Right => Self {
-                x: self.x + 1,
-                y: self.y,
-            },
-            Direction::Down => Self {
-                x: self.x,
-                y: self.y + 1,
-            },
-            Direction::Left => Self {
-                x: self.x - 1,
-                y: self.y,
-            },
-        }
-    }
-
-    /// Check if this is a valid position on a world with the given size
-    fn is_valid(&self, width: usize, height: usize) -> bool {
-        if self.x < 0 || self.y < 0 {
-            return false;
-        }
-        if (self.x as usize) >= width {
-            return false;
-        }
-        if (self.y as usize) >= height {
-            return false;
-        }
-        true
-    }
-}
-
 /// Represents the state of a beam
 #[derive(Debug, Clone, Hash, Eq, PartialEq)]
 struct Beam {
@@ -282,103 +249,96 @@ struct Beam {
 
 impl Beam {
     /// Step this beam forward
-    fn step(self) -> Self {
-        Self {
-            position: self.position.step(self.direction),
+    /// Returns None if the beam would leave the layout
+    fn step(self, layout: &Grid<Tile>) -> Option<Self> {
+        Some(Self {
+            position: layout.step(self.position, self.direction)?,
             direction: self.direction,
-        }
+        })
     }
 }
 
 /// Preprocess the string input into a set of tiles
-fn preprocess<'a>(layout: impl Iterator<Item=&'a str>) -> Vec<Vec<Tile>> {
-    layout.map(|row| {
-        row.chars()
-            .map(|char| char.into())
-            .collect()
-    }).collect()
+pub fn preprocess<'a>(layout: impl Iterator<Item=&'a str>) -> Result<Grid<Tile>, ParseError> {
+    Grid::parse_with(layout, "`.`, a mirror, or a splitter", Tile::from_char)
 }
 
 /// Compute the amount of energized tiles in a given layout with a given starting beam
-fn compute_energized_count(layout: &Vec<Vec<Tile>>, start: Beam) -> usize {
+fn compute_energized_count(layout: &Grid<Tile>, start: Beam) -> usize {
     // The set of tiles which has been energized
-    let mut energized = vec![vec![false; layout[0].len()]; layout.len()];
+    let mut energized = Grid::filled(layout.width(), layout.height(), false);
     // The states we gave visited (used to prune the search from revisiting states)
     let mut visited: HashSet<Beam> = HashSet::new();
 
     // Used as a stack of tiles which still need to be visited
+    // Beams which go off the map are never added to this
     let mut to_visit = vec![start];
 
     // Grab the next state which has not been visited
     while let Some(mut beam) = to_visit.pop() {
-        if !beam.position.is_valid(layout[0].len(), layout.len()) {
-            continue; // If the beam is off the map we can ignore it
-        }
         if visited.contains(&beam) {
             continue; // Don't revisit the same spot twice
         }
         visited.insert(beam.clone()); // Prevent this tile from being revisited
-        energized[beam.position.y as usize][beam.position.x as usize] = true; // Energize this tile
+        energized[beam.position] = true; // Energize this tile
 
         // Propagate the beam forward
-        match layout[beam.position.y as usize][beam.position.x as usize] {
+        match layout[beam.position] {
             Tile::Empty => {
-                to_visit.push(beam.step());
+                to_visit.extend(beam.step(layout));
             }
             Tile::Mirror(mirror_type) => {
                 beam.direction = mirror_type.reflect(beam.direction);
-                to_visit.push(beam.step());
+                to_visit.extend(beam.step(layout));
             }
             Tile::Splitter(mirror_type) => {
-                if mirror_type == beam.direction.to_axis() {
+                if mirror_type == Axis::of(beam.direction) {
                     for direction in match mirror_type {
                         Axis::Vertical => [Direction::Left, Direction::Right],
                         Axis::Horizontal => [Direction::Up, Direction::Down],
                     } {
                         let mut new_beam = beam.clone();
                         new_beam.direction = direction;
-                        to_visit.push(new_beam.step());
+                        to_visit.extend(new_beam.step(layout));
                     }
                 } else {
-                    to_visit.push(beam.step());
+                    to_visit.extend(beam.step(layout));
                 }
             }
         }
     }
 
     // count the number of true/energized states
-    energized.iter().flatten().filter(|x| **x).count()
+    energized.iter().filter(|(_, &energized)| energized).count()
 }
 
 /// Solve part 1
-fn get_tile_energized_count<'a>(layout: impl Iterator<Item=&'a str>) -> usize {
-    let layout = preprocess(layout);
-    compute_energized_count(&layout, Beam {
-        position: Position { x: 0, y: 0 },
+pub fn get_tile_energized_count(layout: &Grid<Tile>) -> usize {
+    compute_energized_count(layout, Beam {
+        position: Position::new(0, 0),
         direction: Direction::Right,
     })
 }
 
 /// Solve part 2
-fn get_max_tile_energized_count<'a>(layout: impl Iterator<Item=&'a str>) -> usize {
+pub fn get_max_tile_energized_count(layout: &Grid<Tile>) -> usize {
     // Just try all start states to find the best
-    let layout = preprocess(layout);
-    let width = layout[0].len();
-    let height = layout.len();
-    let right = (0..height).map(|y| compute_energized_count(&layout, Beam {
-        position: Position { x: 0, y: y as isize },
+    let width = layout.width();
+    let height = layout.height();
+    let right = (0..height).map(|y| compute_energized_count(layout, Beam {
+        position: Position::new(y, 0),
         direction: Direction::Right,
     })).max().unwrap();
-    let left = (0..height).map(|y| compute_energized_count(&layout, Beam {
-        position: Position { x: width as isize - 1, y: y as isize },
+    let left = (0..height).map(|y| compute_energized_count(layout, Beam {
+        position: Position::new(y, width - 1),
         direction: Direction::Left,
     })).max().unwrap();
-    let down = (0..width).map(|x| compute_energized_count(&layout, Beam {
-        position: Position { x: x as isize, y: 0 },
+    let down = (0..width).map(|x| compute_energized_count(layout, Beam {
+        position: Position::new(0, x),
         direction: Direction::Down,
     })).max().unwrap();
-    let up = (0..width).map(|x| compute_energized_count(&layout, Beam {
-        position: Position { x: x as isize, y: height as isize - 1 },
+    let up = (0..width).map(|x| compute_energized_count(layout, Beam {
+        position: Position::new(height - 1, x),
         direction: Direction::Up,
     })).max().unwrap();
     max(max(right, left), max(down, up))
@@ -389,7 +349,7 @@ fn test_part1() {
     assert_eq!(
         46,
         get_tile_energized_count(
-            r".|...\....
+            &preprocess(r".|...\....
 |.-.\.....
 .....|-...
 ........|.
@@ -398,7 +358,7 @@ fn test_part1() {
 ..../.\\..
 .-.-/..|..
 .|....-|.\
-..//.|....".lines()
+..//.|....".lines()).unwrap()
         )
     );
 }
@@ -408,7 +368,7 @@ fn test_part2() {
     assert_eq!(
         51,
         get_max_tile_energized_count(
-            r".|...\....
+            &preprocess(r".|...\....
 |.-.\.....
 .....|-...
 ........|.
@@ -417,7 +377,7 @@ fn test_part2() {
 ..../.\\..
 .-.-/..|..
 .|....-|.\
-..//.|....".lines()
+..//.|....".lines()).unwrap()
         )
     );
 }
@@ -0,0 +1,424 @@
+/*
+The lava starts flowing rapidly once the Lava Production Facility is
+operational. As you leave, the reindeer offers you a parachute, allowing
+you to quickly reach Gear Island.
+
+As you descend, your bird's-eye view of Gear Island reveals why you had
+trouble finding anyone on your way up: half of Gear Island is empty, but
+the half below you is a giant factory city!
+
+You land near the gradually-filling pool of lava at the base of your new
+lavafall. Lavaducts will eventually carry the lava throughout the city, but
+to make use of it immediately, Elves are loading it into large crucibles on
+wheels.
+
+The crucibles are top-heavy and pushed by hand. Unfortunately, the
+crucibles become very difficult to steer at high speeds, and so it can be
+hard to go in a straight line for very long.
+
+To get Desert Island the machine parts it needs as soon as possible, you'll
+need to find the best way to get the crucible from the lava pool to the
+machine parts factory. To do this, you need to minimize heat loss while
+choosing a route that doesn't require the crucible to go in a straight line
+for too long.
+
+Fortunately, the Elves here have a map (your puzzle input) that uses
+traffic patterns, ambient temperature, and hundreds of other parameters to
+calculate exactly how much heat loss can be expected for a crucible
+entering any particular city block.
+
+For example:
+
+2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533
+
+Each city block is marked by a single digit that represents the amount of
+heat loss if the crucible enters that block. The starting point, the lava
+pool, is the top-left city block; the destination, the machine parts
+factory, is the bottom-right city block. (Because you already start in the
+top-left block, you don't incur that block's heat loss unless you leave
+that block and then return to it.)
+
+Because it is difficult to keep the top-heavy crucible going in a straight
+line for very long, it can move at most three blocks in a single direction
+before it must turn 90 degrees left or right. The crucible also can't
+reverse direction; after entering each city block, it may only turn left,
+continue straight, or turn right.
+
+One way to minimize heat loss is this path:
+
+2>>34^>>>1323
+32v>>>35v5623
+32552456v>>54
+3446585845v52
+4546657867v>6
+14385987984v4
+44578769877v6
+36378779796v>
+465496798688v
+456467998645v
+12246868655<v
+25465488877v5
+43226746555v>
+
+This path never moves more than three consecutive blocks in the same
+direction and incurs a heat loss of only 102.
+
+Directing the crucible from the lava pool to the machine parts factory, but
+not moving more than three consecutive blocks in the same direction, what
+is the least heat loss it can incur?
+
+--- Part Two ---
+
+The crucibles of lava simply aren't large enough to provide an adequate
+supply of lava to the machine parts factory. Instead, the Elves are going
+to upgrade to ultra crucibles.
+
+Ultra crucibles are even more difficult to steer than normal crucibles. Not
+only do they have trouble going in a straight line, but they also have
+trouble turning!
+
+Once an ultra crucible starts moving in a direction, it needs to move a
+minimum of four blocks in that direction before it can turn (or even before
+it can stop at the end). However, it will eventually start to get wobbly:
+an ultra crucible can move a maximum of ten consecutive blocks without
+turning.
+
+In the above example, an ultra crucible could follow this path to minimize
+heat loss:
+
+2>>>>>>>>1323
+32154535v5623
+32552456v4254
+34465858v5452
+45466578v>>>>
+143859879845v
+445787698776v
+363787797965v
+465496798688v
+456467998645v
+122468686556v
+254654888773v
+432267465553v
+
+In the above example, an ultra crucible would incur the minimum possible
+heat loss of 94.
+
+Here's another example:
+
+111111111111
+999999999991
+999999999991
+999999999991
+999999999991
+
+Sadly, an ultra crucible would need to take an unfortunate path like this
+one:
+
+1>>>>>>>1111
+9999999v9991
+9999999v9991
+9999999v9991
+9999999v>>>>
+
+This route causes the ultra crucible to incur the minimum possible heat
+loss of 71.
+
+Directing the ultra crucible from the lava pool to the machine parts
+factory, what is the least heat loss it can incur?
+ */
+
+use crate::grid::{Direction, Grid, Position};
+use crate::parse::ParseError;
+use crate::search::{self, Path};
+use crate::Solution;
+
+/// Day 17: Clumsy Crucible
+pub struct ClumsyCrucible;
+
+impl Solution for ClumsyCrucible {
+    const DAY: u8 = 17;
+    const TITLE: &'static str = "Clumsy Crucible";
+    const INPUT: &'static str = "./inputs/17_crucible.txt";
+
+    type Input<'a> = Grid<usize>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        preprocess(input.lines())
+    }
+
+    fn part1(heat_map: &Self::Input<'_>) -> usize {
+        get_minimum_heat_loss(heat_map)
+    }
+
+    fn part2(heat_map: &Self::Input<'_>) -> usize {
+        get_minimum_heat_loss_ultra(heat_map)
+    }
+}
+
+/// Preprocess the input into a more useful form
+pub fn preprocess<'a>(layout: impl Iterator<Item=&'a str>) -> Result<Grid<usize>, ParseError> {
+    let heat_map = Grid::parse_with(layout, "a digit", |char| char.to_digit(10).map(|digit| digit as usize))?;
+    if heat_map.height() == 0 || heat_map.width() == 0 {
+        return Err(ParseError::at(0, 0, "", "a block for the crucible to start from"));
+    }
+    Ok(heat_map)
+}
+
+/// How far a crucible can travel in a straight line
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct StraightRun {
+    /// The fewest blocks the crucible has to move in a direction before it can turn (or stop)
+    pub min: usize,
+    /// The most blocks the crucible can move in a direction before it has to turn
+    pub max: usize,
+}
+
+impl StraightRun {
+    /// The normal crucible from part 1
+    pub const NORMAL: StraightRun = StraightRun { min: 1, max: 3 };
+    /// The ultra crucible from part 2
+    pub const ULTRA: StraightRun = StraightRun { min: 4, max: 10 };
+}
+
+/// Represents a state in the problem
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct State {
+    pub position: Position,
+    /// The direction the crucible moved to get here
+    pub direction: Direction,
+    /// How many blocks the crucible has moved in a straight line to get here
+    pub direction_count: usize,
+}
+
+impl State {
+    /// Step one unit in the currently set direction
+    /// Returns None if that would leave the map
+    fn step(&self, heat_map: &Grid<usize>) -> Option<Self> {
+        Some(Self {
+            position: heat_map.step(self.position, self.direction)?,
+            direction: self.direction,
+            direction_count: self.direction_count + 1,
+        })
+    }
+
+    /// Get the states the crucible can move to next along with the heat lost by moving there
+    fn successors<'a>(&self, heat_map: &'a Grid<usize>, rule: StraightRun) -> impl Iterator<Item=(State, usize)> + 'a {
+        let straight = (self.direction_count < rule.max).then(|| self.clone());
+        let turns = (self.direction_count >= rule.min)
+            .then(|| [self.direction.turn_left(), self.direction.turn_right()].map(|direction| State {
+                position: self.position,
+                direction,
+                direction_count: 0,
+            }))
+            .into_iter()
+            .flatten();
+        straight.into_iter()
+            .chain(turns)
+            .filter_map(|state| state.step(heat_map))
+            .map(|state| {
+                let heat = heat_map[state.position];
+                (state, heat)
+            })
+    }
+}
+
+/// Find the route which loses the least heat getting from the top left to the bottom right
+/// Returns None if the crucible can't get there
+pub fn get_minimum_heat_loss_route(heat_map: &Grid<usize>, rule: StraightRun) -> Option<Path<State, usize>> {
+    let goal = Position::new(heat_map.height() - 1, heat_map.width() - 1);
+    // Every block loses at least this much heat so it can be used to estimate the rest of the route
+    let min_heat = heat_map.iter().map(|(_, &heat)| heat).min().unwrap_or(0);
+
+    // The crucible can start out moving either right or down
+    let starts = [Direction::Right, Direction::Down].map(|direction| State {
+        position: Position::new(0, 0),
+        direction,
+        direction_count: 0,
+    });
+
+    search::a_star(
+        starts,
+        |state| state.successors(heat_map, rule),
+        |state| state.position.distance_to(goal) * min_heat,
+        // The crucible can only stop once it is allowed to turn (or if it starts out at the goal, which is
+        // the only time it has moved zero blocks)
+        |state| state.position == goal && (state.direction_count >= rule.min || state.direction_count == 0),
+    )
+}
+
+/// Find the least heat lost by a crucible following a straight-run rule
+/// Returns None if the crucible can't get to the bottom right
+pub fn get_minimum_heat_loss_with(heat_map: &Grid<usize>, rule: StraightRun) -> Option<usize> {
+    get_minimum_heat_loss_route(heat_map, rule).map(|path| path.cost)
+}
+
+/// Solve part 1
+pub fn get_minimum_heat_loss(heat_map: &Grid<usize>) -> usize {
+    get_minimum_heat_loss_with(heat_map, StraightRun::NORMAL).expect("No solution found")
+}
+
+/// Part 2
+pub fn get_minimum_heat_loss_ultra(heat_map: &Grid<usize>) -> usize {
+    get_minimum_heat_loss_with(heat_map, StraightRun::ULTRA).expect("No solution found")
+}
+
+/// Draw a route over the heat map with an arrow showing the direction the crucible moved into each
+/// block (like the diagrams in the puzzle)
+pub fn render_ascii(heat_map: &Grid<usize>, route: &[State]) -> String {
+    let mut picture = heat_map.map(|heat| char::from_digit(*heat as u32, 10).unwrap_or('?'));
+    // The crucible doesn't move into the first block so it keeps its digit
+    for state in route.iter().skip(1) {
+        picture[state.position] = match state.direction {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+        };
+    }
+    picture.rows()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
+
+/// Draw a route as an SVG over a heatmap of the heat map
+/// Blocks which lose more heat are drawn darker
+pub fn render_svg(heat_map: &Grid<usize>, route: &[State]) -> String {
+    const CELL: usize = 10;
+    let max_heat = heat_map.iter().map(|(_, &heat)| heat).max().unwrap_or(0).max(1);
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\">\n",
+        heat_map.width() * CELL,
+        heat_map.height() * CELL,
+    );
+    for (position, &heat) in heat_map.iter() {
+        // From pale yellow for no heat loss to dark red for the most
+        let scale = |from: usize, to: usize| from - (from - to) * heat / max_heat;
+        svg += &format!(
+            "<rect x=\"{}\" y=\"{}\" width=\"{CELL}\" height=\"{CELL}\" fill=\"rgb({},{},{})\"><title>{heat}</title></rect>\n",
+            position.column * CELL,
+            position.row * CELL,
+            scale(255, 128),
+            scale(255, 0),
+            scale(204, 0),
+        );
+    }
+    let points = route.iter()
+        .map(|state| format!("{},{}", state.position.column * CELL + CELL / 2, state.position.row * CELL + CELL / 2))
+        .collect::<Vec<_>>()
+        .join(" ");
+    svg += &format!("<polyline points=\"{points}\" fill=\"none\" stroke=\"#1f4fff\" stroke-width=\"2\"/>\n");
+    svg += "</svg>\n";
+    svg
+}
+
+#[test]
+fn test_part1() {
+    assert_eq!(
+        102,
+        get_minimum_heat_loss(
+            &preprocess(r"2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533".lines()).unwrap()
+        )
+    );
+}
+
+#[test]
+fn test_part2a() {
+    assert_eq!(
+        94,
+        get_minimum_heat_loss_ultra(
+            &preprocess(r"2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533".lines()).unwrap()
+        )
+    );
+}
+
+#[test]
+fn test_part2b() {
+    assert_eq!(
+        71,
+        get_minimum_heat_loss_ultra(
+            &preprocess(r"111111111111
+999999999991
+999999999991
+999999999991
+999999999991".lines()).unwrap()
+        )
+    );
+}
+
+#[test]
+fn test_render() {
+    let heat_map = preprocess(r"111111111111
+999999999991
+999999999991
+999999999991
+999999999991".lines()).unwrap();
+    let route = get_minimum_heat_loss_route(&heat_map, StraightRun::ULTRA).unwrap();
+    assert_eq!(71, route.cost);
+    assert_eq!(
+        r"1>>>>>>>1111
+9999999v9991
+9999999v9991
+9999999v9991
+9999999v>>>>
+",
+        render_ascii(&heat_map, &route.states)
+    );
+
+    let svg = render_svg(&heat_map, &route.states);
+    assert_eq!(60, svg.matches("<rect").count());
+    assert!(svg.contains("<polyline points=\"5,5 15,5 25,5 35,5 45,5 55,5 65,5 75,5 75,15 "));
+}
+
+#[test]
+fn test_small_maps() {
+    assert_eq!(Err(ParseError::at(0, 0, "", "a block for the crucible to start from")), preprocess("".lines()));
+
+    // The crucible starts out at the goal
+    let heat_map = preprocess("7".lines()).unwrap();
+    assert_eq!(Some(0), get_minimum_heat_loss_with(&heat_map, StraightRun::NORMAL));
+    assert_eq!(Some(0), get_minimum_heat_loss_with(&heat_map, StraightRun::ULTRA));
+
+    // The ultra crucible can't stop after a single block
+    let heat_map = preprocess("12".lines()).unwrap();
+    assert_eq!(Some(2), get_minimum_heat_loss_with(&heat_map, StraightRun::NORMAL));
+    assert_eq!(None, get_minimum_heat_loss_with(&heat_map, StraightRun::ULTRA));
+}
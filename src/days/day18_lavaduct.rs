@@ -0,0 +1,554 @@
+/*
+Thanks to your efforts, the machine parts factory is one of the first
+factories up and running since the lavafall came back. However, to catch up
+with the large backlog of parts requests, the factory will also need a
+large supply of lava for a while; the Elves have already started creating a
+large lagoon nearby for this purpose.
+
+However, they aren't sure the lagoon will be big enough; they've asked you
+to take a look at the dig plan (your puzzle input). For example:
+
+R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)
+
+The digger starts in a 1 meter cube hole in the ground. They then dig the
+specified number of meters up (U), down (D), left (L), or right (R),
+clearing full 1 meter cubes as they go. The directions are given as seen
+from above, so if "up" were north, then "right" would be east, and so on.
+Each trench is also listed with the color that the edge of the trench
+should be painted as an RGB hexadecimal color code.
+
+When viewed from above, the above example dig plan would result in the
+following loop of trench (#) having been dug out from otherwise ground-
+level terrain (.):
+
+#######
+#.....#
+###...#
+..#...#
+..#...#
+###.###
+#...#..
+##..###
+.#....#
+.######
+
+At this point, the trench could contain 38 cubic meters of lava. However,
+this is just the edge of the lagoon; the next step is to dig out the
+interior so that it is one meter deep as well:
+
+#######
+#######
+#######
+..#####
+..#####
+#######
+#####..
+#######
+.######
+.######
+
+Now, the lagoon can contain a much more respectable 62 cubic meters of
+lava. While the interior is dug out, the edges are also painted according
+to the color codes in the dig plan.
+
+The Elves are concerned the lagoon won't be large enough; if they follow
+their dig plan, how many cubic meters of lava could it hold?
+
+--- Part Two ---
+
+The Elves were right to be concerned; the planned lagoon would be much too
+small.
+
+After a few minutes, someone realizes what happened; someone swapped the
+color and instruction parameters when producing the dig plan. They don't
+have time to fix the bug; one of them asks if you can extract the correct
+instructions from the hexadecimal codes.
+
+Each hexadecimal code is six hexadecimal digits long. The first five
+hexadecimal digits encode the distance in meters as a five-digit
+hexadecimal number. The last hexadecimal digit encodes the direction to
+dig: 0 means R, 1 means D, 2 means L, and 3 means U.
+
+So, in the above example, the hexadecimal codes can be converted into the
+true instructions:
+
+#70c710 = R 461937
+#0dc571 = D 56407
+#5713f0 = R 356671
+#d2c081 = D 863240
+#59c680 = R 367720
+#411b91 = D 266681
+#8ceee2 = L 577262
+#caa173 = U 829975
+#1b58a2 = L 112010
+#caa171 = D 829975
+#7807d2 = L 491645
+#a77fa3 = U 686074
+#015232 = L 5411
+#7a21e3 = U 500254
+
+Digging out this loop and its interior produces a lagoon that can hold an
+impressive 952408144115 cubic meters of lava.
+
+Convert the hexadecimal color codes into the correct instructions; if the
+Elves follow this new dig plan, how many cubic meters of lava could the
+lagoon hold?
+ */
+
+use std::iter;
+
+use itertools::Itertools;
+
+use crate::grid::{Direction, Grid, Position};
+use crate::parse::{self, Line, ParseError};
+use crate::polygon::{Point, Polygon};
+use crate::Solution;
+
+/// Day 18: Lavaduct Lagoon
+pub struct LavaductLagoon;
+
+impl Solution for LavaductLagoon {
+    const DAY: u8 = 18;
+    const TITLE: &'static str = "Lavaduct Lagoon";
+    const INPUT: &'static str = "./inputs/18_lavaduct.txt";
+
+    type Input<'a> = Vec<Instruction>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        preprocess(input.lines())
+    }
+
+    fn part1(dig_plan: &Self::Input<'_>) -> usize {
+        get_total_volume(dig_plan)
+    }
+
+    fn part2(dig_plan: &Self::Input<'_>) -> usize {
+        get_total_volume_swapped(dig_plan)
+    }
+}
+
+/// Get a direction from its letter in the dig plan
+fn direction_from_letter(value: &str) -> Option<Direction> {
+    match value {
+        "U" => Some(Direction::Up),
+        "D" => Some(Direction::Down),
+        "L" => Some(Direction::Left),
+        "R" => Some(Direction::Right),
+        _ => None
+    }
+}
+
+/// A data type to hold a single instruction
+#[derive(Debug, Clone)]
+pub struct Instruction {
+    direction: Direction,
+    amount: usize,
+    color: u32,
+}
+
+impl Instruction {
+    /// Parse an instruction from a line of the dig plan
+    fn from_line(line: Line) -> Result<Self, ParseError> {
+        let (direction, rest) = line.split_once(line.text, " ")?;
+        let direction = direction_from_letter(direction)
+            .ok_or_else(|| line.error(direction, "`U`, `D`, `L`, or `R`"))?;
+        let (amount, color) = line.split_once(rest, " ")?;
+        let amount = line.parse(amount, "a distance")?;
+        // Shop off the stuff we don't care about
+        let hex = color.strip_prefix("(#")
+            .and_then(|color| color.strip_suffix(')'))
+            .ok_or_else(|| line.error(color, "a color like `(#70c710)`"))?;
+        let color = u32::from_str_radix(hex, 16)
+            .map_err(|_| line.error(hex, "a hexadecimal color"))?;
+        // The last digit of the color becomes the direction after the swap
+        if color % 16 > 3 {
+            return Err(line.error(&hex[hex.len() - 1..], "a direction from `0` to `3`"));
+        }
+        Ok(Self {
+            direction,
+            amount,
+            color,
+        })
+    }
+}
+
+impl Instruction {
+    /// Perform the swap from color to direction & amount
+    pub fn swap(self) -> Self {
+        let direction = match self.color % 16 {
+            0 => Direction::Right,
+            1 => Direction::Down,
+            2 => Direction::Left,
+            3 => Direction::Up,
+            _ => panic!("Unknown direction {}", self.color % 16)
+        };
+        let amount = (self.color / 16) as usize;
+        Self {
+            direction,
+            amount,
+            color: self.color,
+        }
+    }
+}
+
+/// Preprocess into a more useful form
+pub fn preprocess<'a>(dig_plan: impl Iterator<Item=&'a str>) -> Result<Vec<Instruction>, ParseError> {
+    let mut last = None;
+    let dig_plan = parse::lines(dig_plan)
+        .inspect(|&line| last = Some(line))
+        .map(Instruction::from_line)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Both parts trace the trench, so both the plan and the swapped plan have to close up
+    if let Some(last) = last {
+        if find_end(dig_plan.iter().cloned()) != Point::default() {
+            return Err(last.missing("more of the dig plan to end where it started"));
+        }
+        if find_end(dig_plan.iter().cloned().map(Instruction::swap)) != Point::default() {
+            return Err(last.missing("more of the dig plan for the colors to end where they started"));
+        }
+    }
+    Ok(dig_plan)
+}
+
+/// Find where following the dig plan ends up (starting from the origin)
+fn find_end(dig_plan: impl Iterator<Item=Instruction>) -> Point {
+    dig_plan.fold(Point::default(), |point, instruction| point.step(instruction.direction, instruction.amount as i64))
+}
+
+/// Trace the outline of the trench which the dig plan digs (starting from the origin)
+pub fn dig_trench(dig_plan: &[Instruction]) -> Polygon {
+    let moves = dig_plan.iter()
+        .map(|instruction| (instruction.direction, instruction.amount as i64));
+    Polygon::trace(Point::default(), moves).expect("The dig plan was checked to end where it started")
+}
+
+/// Solve part 1
+pub fn get_total_volume(dig_plan: &[Instruction]) -> usize {
+    // The lagoon is the trench along with everything inside it
+    dig_trench(dig_plan).lattice_points() as usize
+}
+
+/// Solve part 2
+pub fn get_total_volume_swapped(dig_plan: &[Instruction]) -> usize {
+    let dig_plan = dig_plan.iter()
+        .cloned()
+        .map(Instruction::swap)
+        .collect::<Vec<Instruction>>();
+    get_total_volume(&dig_plan)
+}
+
+/// What a part of the ground looks like once the lagoon is dug
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Ground {
+    Level,
+    /// The trench along with the color it was painted
+    Trench(u32),
+    Interior,
+}
+
+/// A picture of the lagoon
+/// Each row and column covers a range of coordinates (inclusive) which all look the same
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Lagoon {
+    pub rows: Vec<(i64, i64)>,
+    pub columns: Vec<(i64, i64)>,
+    pub ground: Grid<Ground>,
+}
+
+/// Split up the coordinates between the lowest and highest breakpoints into single coordinates at
+/// each breakpoint and the ranges between them
+fn compressed_ranges(mut breakpoints: Vec<i64>) -> Vec<(i64, i64)> {
+    breakpoints.sort_unstable();
+    breakpoints.dedup();
+    let mut ranges = vec![];
+    for (index, &breakpoint) in breakpoints.iter().enumerate() {
+        ranges.push((breakpoint, breakpoint));
+        if let Some(&next) = breakpoints.get(index + 1).filter(|&&next| next > breakpoint + 1) {
+            ranges.push((breakpoint + 1, next - 1));
+        }
+    }
+    ranges
+}
+
+/// Find which of the ranges contains a coordinate
+fn range_index(ranges: &[(i64, i64)], coordinate: i64) -> usize {
+    ranges.partition_point(|&(_, end)| end < coordinate)
+}
+
+/// Work out what the lagoon dug by the plan looks like
+/// With compression only the coordinates where the trench turns get their own row or column, and
+/// everything between them is squashed into one, so that even huge plans can be drawn
+pub fn map_lagoon(dig_plan: &[Instruction], compress: bool) -> Lagoon {
+    let trench = dig_trench(dig_plan);
+    let breakpoints = |coordinate: fn(&Point) -> i64| {
+        let corners = trench.vertices().iter().map(coordinate);
+        if compress {
+            corners.collect()
+        } else {
+            let (low, high) = corners.minmax().into_option().unwrap_or_default();
+            (low..=high).collect()
+        }
+    };
+    let rows = compressed_ranges(breakpoints(|point| point.y));
+    let columns = compressed_ranges(breakpoints(|point| point.x));
+    let mut ground = Grid::filled(columns.len(), rows.len(), Ground::Level);
+
+    // Fill in the inside one row at a time by counting the trench walls to the right of each column
+    for (row, &(y, _)) in rows.iter().enumerate() {
+        let walls = trench.edges()
+            .filter(|(start, end)| start.x == end.x && (start.y.min(end.y)..start.y.max(end.y)).contains(&y))
+            .map(|(start, _)| start.x)
+            .collect::<Vec<_>>();
+        for (column, &(x, _)) in columns.iter().enumerate() {
+            if walls.iter().filter(|&&wall| wall > x).count() % 2 == 1 {
+                ground[Position::new(row, column)] = Ground::Interior;
+            }
+        }
+    }
+
+    // Then paint the trench over it following the plan
+    let mut point = Point::default();
+    for instruction in dig_plan {
+        let next = point.step(instruction.direction, instruction.amount as i64);
+        let (row, column) = (range_index(&rows, point.y), range_index(&columns, point.x));
+        let (next_row, next_column) = (range_index(&rows, next.y), range_index(&columns, next.x));
+        for row in row.min(next_row)..=row.max(next_row) {
+            for column in column.min(next_column)..=column.max(next_column) {
+                ground[Position::new(row, column)] = Ground::Trench(instruction.color);
+            }
+        }
+        point = next;
+    }
+
+    Lagoon { rows, columns, ground }
+}
+
+/// The red, green, and blue of the ground in a picture
+fn ground_color(ground: Ground, colored: bool) -> [u8; 3] {
+    match ground {
+        Ground::Level => [255, 255, 255],
+        Ground::Trench(color) if colored => [(color >> 16) as u8, (color >> 8) as u8, color as u8],
+        Ground::Trench(_) => [64, 64, 64],
+        Ground::Interior => [208, 208, 208],
+    }
+}
+
+/// Draw the lagoon as text using `#` for the trench and `~` for the inside
+pub fn render_ascii(lagoon: &Lagoon) -> String {
+    lagoon.ground.rows()
+        .map(|row| row.iter()
+            .map(|ground| match ground {
+                Ground::Level => '.',
+                Ground::Trench(_) => '#',
+                Ground::Interior => '~',
+            })
+            .chain(['\n'])
+            .collect::<String>())
+        .collect()
+}
+
+/// Draw the lagoon as an SVG, painting the trench with the colors from the plan if `colored`
+pub fn render_svg(lagoon: &Lagoon, colored: bool) -> String {
+    const CELL: usize = 10;
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\">\n",
+        lagoon.ground.width() * CELL,
+        lagoon.ground.height() * CELL,
+    );
+    for (row, tiles) in lagoon.ground.rows().enumerate() {
+        // A rectangle for each run of the same ground keeps the picture small
+        let mut column = 0;
+        for run in tiles.chunk_by(|first, second| first == second) {
+            let length = run.len();
+            let [red, green, blue] = ground_color(run[0], colored);
+            svg += &format!(
+                "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{CELL}\" fill=\"#{red:02x}{green:02x}{blue:02x}\"/>\n",
+                column * CELL,
+                row * CELL,
+                length * CELL,
+            );
+            column += length;
+        }
+    }
+    svg += "</svg>\n";
+    svg
+}
+
+/// Draw the lagoon as a PNG, painting the trench with the colors from the plan if `colored`
+pub fn render_png(lagoon: &Lagoon, colored: bool) -> Vec<u8> {
+    // Scale the picture up until it is about this big
+    const SIZE: usize = 1000;
+    let (width, height) = (lagoon.ground.width(), lagoon.ground.height());
+    let scale = (SIZE / width.max(height).max(1)).max(1);
+
+    let mut pixels = Vec::with_capacity(width * height * scale * scale * 3);
+    for row in lagoon.ground.rows() {
+        let line = row.iter()
+            .flat_map(|&ground| iter::repeat_n(ground_color(ground, colored), scale))
+            .flatten()
+            .collect::<Vec<_>>();
+        (0..scale).for_each(|_| pixels.extend_from_slice(&line));
+    }
+
+    let mut png = vec![];
+    let mut encoder = png::Encoder::new(&mut png, (width * scale) as u32, (height * scale) as u32);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header().expect("Writing to memory should not fail");
+    writer.write_image_data(&pixels).expect("Writing to memory should not fail");
+    writer.finish().expect("Writing to memory should not fail");
+    png
+}
+
+#[test]
+fn test_part1() {
+    assert_eq!(
+        62,
+        get_total_volume(
+            &preprocess(r"R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)".lines()).unwrap()
+        )
+    );
+}
+
+#[test]
+fn test_part2() {
+    assert_eq!(
+        952408144115,
+        get_total_volume_swapped(
+            &preprocess(r"R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)".lines()).unwrap()
+        )
+    );
+}
+
+#[test]
+fn test_render() {
+    let dig_plan = preprocess(r"R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)".lines()).unwrap();
+    let lagoon = map_lagoon(&dig_plan, false);
+    assert_eq!(
+        r"#######
+#~~~~~#
+###~~~#
+..#~~~#
+..#~~~#
+###~###
+#~~~#..
+##~~###
+.#~~~~#
+.######
+",
+        render_ascii(&lagoon)
+    );
+    assert_eq!(Ground::Trench(0x70c710), lagoon.ground[Position::new(0, 3)]);
+
+    // Only the rows and columns where the trench turns (and the gaps between them) are kept
+    let lagoon = map_lagoon(&dig_plan, true);
+    assert_eq!(vec![(0, 0), (1, 1), (2, 2), (3, 4), (5, 5), (6, 6), (7, 7), (8, 8), (9, 9)], lagoon.rows);
+    assert_eq!(vec![(0, 0), (1, 1), (2, 2), (3, 3), (4, 4), (5, 5), (6, 6)], lagoon.columns);
+
+    let svg = render_svg(&lagoon, true);
+    assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"70\" height=\"90\">"));
+    assert!(svg.contains("fill=\"#70c710\""));
+    assert!(!render_svg(&lagoon, false).contains("fill=\"#70c710\""));
+    assert!(render_png(&lagoon, true).starts_with(b"\x89PNG\r\n\x1a\n"));
+}
+
+#[test]
+fn test_touching_trench() {
+    // Two 3x3 lagoons which share a corner
+    assert_eq!(
+        17,
+        get_total_volume(
+            &preprocess(r"R 2 (#000000)
+D 2 (#000000)
+R 2 (#000000)
+D 2 (#000000)
+L 2 (#000000)
+U 2 (#000000)
+L 2 (#000000)
+U 2 (#000000)".lines()).unwrap()
+        )
+    );
+}
+
+#[test]
+fn test_parse_error() {
+    assert_eq!(
+        Some(ParseError::at(1, 0, "X", "`U`, `D`, `L`, or `R`")),
+        preprocess("R 6 (#70c710)\nX 5 (#0dc571)".lines()).err()
+    );
+    assert_eq!(
+        Some(ParseError::at(0, 2, "six", "a distance")),
+        preprocess("R six (#70c710)".lines()).err()
+    );
+    assert_eq!(
+        Some(ParseError::at(0, 4, "70c710", "a color like `(#70c710)`")),
+        preprocess("R 6 70c710".lines()).err()
+    );
+    assert_eq!(
+        Some(ParseError::at(0, 11, "7", "a direction from `0` to `3`")),
+        preprocess("R 6 (#70c717)".lines()).err()
+    );
+    assert_eq!(
+        Some(ParseError::at(0, 13, "", "more of the dig plan to end where it started")),
+        preprocess("R 2 (#000020)".lines()).err()
+    );
+    assert_eq!(
+        Some(ParseError::at(1, 13, "", "more of the dig plan for the colors to end where they started")),
+        preprocess("R 2 (#000020)\nL 2 (#000010)".lines()).err()
+    );
+}
@@ -0,0 +1,873 @@
+/*
+The Elves of Gear Island are thankful for your help and send you on your
+way. They even have a hang glider that someone stole from Desert Island;
+since you're already going that direction, it would help them a lot if you
+would use it to get down there and return it to them.
+
+As you reach the bottom of the relentless avalanche of machine parts, you
+discover that they're already forming a formidable heap. Don't worry,
+though - a group of Elves is already here organizing the parts, and they
+have a system.
+
+To start, each part is rated in each of four categories:
+
+- x: Extremely cool looking
+- m: Musical (it makes a noise when you hit it)
+- a: Aerodynamic
+- s: Shiny
+
+Then, each part is sent through a series of workflows that will ultimately
+accept or reject the part. Each workflow has a name and contains a list of
+rules; each rule specifies a condition and where to send the part if the
+condition is true. The first rule that matches the part being considered is
+applied immediately, and the part moves on to the destination described by
+the rule. (The last rule in each workflow has no condition and always
+applies if reached.)
+
+Consider the workflow ex{x>10:one,m<20:two,a>30:R,A}. This workflow is
+named ex and contains four rules. If workflow ex were considering a
+specific part, it would perform the following steps in order:
+
+- Rule "x>10:one": If the part's x is more than 10, send the part to the
+workflow named one.
+- Rule "m<20:two": Otherwise, if the part's m is less than 20, send the
+part to the workflow named two.
+- Rule "a>30:R": Otherwise, if the part's a is more than 30, the part is
+immediately rejected (R).
+- Rule "A": Otherwise, because no other rules matched the part, the part
+is immediately accepted (A).
+
+If a part is sent to another workflow, it immediately switches to the start
+of that workflow instead and never returns. If a part is accepted (sent to
+A) or rejected (sent to R), the part immediately stops any further
+processing.
+
+The system works, but it's not keeping up with the torrent of weird metal
+shapes. The Elves ask if you can help sort a few parts and give you the
+list of workflows and some part ratings (your puzzle input). For example:
+
+px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}
+
+The workflows are listed first, followed by a blank line, then the ratings
+of the parts the Elves would like you to sort. All parts begin in the
+workflow named in. In this example, the five listed parts go through the
+following workflows:
+
+- {x=787,m=2655,a=1222,s=2876}: in -> qqz -> qs -> lnx -> A
+- {x=1679,m=44,a=2067,s=496}: in -> px -> rfg -> gd -> R
+- {x=2036,m=264,a=79,s=2244}: in -> qqz -> hdj -> pv -> A
+- {x=2461,m=1339,a=466,s=291}: in -> px -> qkq -> crn -> R
+- {x=2127,m=1623,a=2188,s=1013}: in -> px -> rfg -> A
+
+Ultimately, three parts are accepted. Adding up the x, m, a, and s rating
+for each of the accepted parts gives 7540 for the part with x=787, 4623
+for the part with x=2036, and 6951 for the part with x=2127. Adding all of
+the ratings for all of the accepted parts gives the sum total of 19114.
+
+Sort through all of the parts you've been given; what do you get if you add
+together all of the rating numbers for all of the parts that ultimately get
+accepted?
+
+--- Part Two ---
+
+Even with your help, the sorting process still isn't fast enough.
+
+One of the Elves comes up with a new plan: rather than sort parts
+individually through all of these workflows, maybe you can figure out in
+advance which combinations of ratings will be accepted or rejected.
+
+Each of the four ratings (x, m, a, s) can have an integer value ranging
+from a minimum of 1 to a maximum of 4000. Of all possible distinct
+combinations of ratings, your job is to figure out which ones will be
+accepted.
+
+In the above example, there are 167409079868000 distinct combinations of
+ratings that will be accepted.
+
+Consider only your list of workflows; the list of part ratings that the
+Elves wanted you to sort is no longer relevant. How many distinct
+combinations of ratings will be accepted by the Elves' workflows?
+ */
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::ops::RangeInclusive;
+
+use itertools::Itertools;
+use regex::Regex;
+
+use crate::parse::{self, Line, ParseError};
+use crate::Solution;
+
+/// Day 19: Aplenty
+pub struct Aplenty;
+
+impl Solution for Aplenty {
+    const DAY: u8 = 19;
+    const TITLE: &'static str = "Aplenty";
+    const INPUT: &'static str = "./inputs/19_aplenty.txt";
+
+    type Input<'a> = (HashMap<String, Workflow>, Vec<Part>);
+    type Part1 = usize;
+    type Part2 = u128;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        preprocess(input.lines())
+    }
+
+    fn part1(workflows_and_ratings: &Self::Input<'_>) -> usize {
+        get_total_accepted(workflows_and_ratings)
+    }
+
+    fn part2(workflows_and_ratings: &Self::Input<'_>) -> u128 {
+        get_accept_combinations(workflows_and_ratings)
+    }
+}
+
+/// The ratings which parts have and where they start being sorted
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Config {
+    /// The workflow every part starts at
+    pub start: String,
+    /// The name of each rating along with the values it can have
+    pub ratings: Vec<(String, RangeInclusive<usize>)>,
+}
+
+impl Default for Config {
+    /// The puzzle's parts: `x`, `m`, `a`, and `s` ratings from 1 to 4000 which start at `in`
+    fn default() -> Self {
+        Config {
+            start: "in".to_string(),
+            ratings: ["x", "m", "a", "s"].into_iter().map(|name| (name.to_string(), 1..=4000)).collect(),
+        }
+    }
+}
+
+/// Represents a type of rule.
+/// This either checks one of the ratings of a part or always applies
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum RuleType {
+    Rating(String),
+    Always,
+}
+
+/// How a rule compares a rating with its value
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Comparison {
+    Less,
+    LessOrEqual,
+    Equal,
+    GreaterOrEqual,
+    Greater,
+}
+
+impl Comparison {
+    /// Get the comparison from its symbol
+    fn from_symbol(value: &str) -> Option<Self> {
+        match value {
+            "<" => Some(Comparison::Less),
+            "<=" => Some(Comparison::LessOrEqual),
+            "==" => Some(Comparison::Equal),
+            ">=" => Some(Comparison::GreaterOrEqual),
+            ">" => Some(Comparison::Greater),
+            _ => None
+        }
+    }
+
+    /// Check if a rating passes the comparison with a value
+    pub fn matches(self, rating: usize, value: usize) -> bool {
+        match self {
+            Comparison::Less => rating < value,
+            Comparison::LessOrEqual => rating <= value,
+            Comparison::Equal => rating == value,
+            Comparison::GreaterOrEqual => rating >= value,
+            Comparison::Greater => rating > value,
+        }
+    }
+
+    /// Split a range of ratings into the ones which pass the comparison with a value and the ones
+    /// which don't (an equality check can leave the ratings on both sides of the value)
+    /// Any of these ranges can be empty
+    fn split(self, range: &RangeInclusive<usize>, value: usize) -> (RangeInclusive<usize>, Vec<RangeInclusive<usize>>) {
+        let (start, end) = (*range.start(), *range.end());
+        let below = |value: usize| match value.checked_sub(1) {
+            Some(highest) => start..=end.min(highest),
+            None => empty_range(),
+        };
+        let above = |value: usize| match value.checked_add(1) {
+            Some(lowest) => start.max(lowest)..=end,
+            None => empty_range(),
+        };
+        let up_to = |value: usize| start..=end.min(value);
+        let from = |value: usize| start.max(value)..=end;
+        match self {
+            Comparison::Less => (below(value), vec![from(value)]),
+            Comparison::LessOrEqual => (up_to(value), vec![above(value)]),
+            Comparison::Equal => (start.max(value)..=end.min(value), vec![below(value), above(value)]),
+            Comparison::GreaterOrEqual => (from(value), vec![below(value)]),
+            Comparison::Greater => (above(value), vec![up_to(value)]),
+        }
+    }
+}
+
+/// Get a range which contains nothing
+#[allow(clippy::reversed_empty_ranges)]
+fn empty_range() -> RangeInclusive<usize> {
+    1..=0
+}
+
+/// Get the result of a rule
+/// If a rule applies this is "executed"
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum RuleResult {
+    Accepted,
+    Rejected,
+    SendToWorkflow(String),
+}
+
+impl<'a> From<&'a str> for RuleResult {
+    fn from(value: &'a str) -> Self {
+        match value {
+            "A" => RuleResult::Accepted,
+            "R" => RuleResult::Rejected,
+            _ => RuleResult::SendToWorkflow(value.to_string())
+        }
+    }
+}
+
+/// Represents a rule
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Rule {
+    rule_type: RuleType,
+    comparison: Comparison,
+    value: usize,
+    result: RuleResult,
+}
+
+/// Represents a workflow (i.e. a list of steps)
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Workflow {
+    steps: Vec<Rule>,
+}
+
+/// A part along with each of its ratings
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Part {
+    pub ratings: BTreeMap<String, usize>,
+}
+
+/// Parse a rule which is a slice of the line
+fn parse_rule<'a>(line: Line<'a>, rule: &'a str, rule_regex: &Regex) -> Result<Rule, ParseError> {
+    let Some(captures) = rule_regex.captures(rule) else {
+        // Anything that isn't a condition should just be the name of where to send the part (with
+        // the same characters as the names after a condition)
+        if rule.is_empty() || !rule.chars().all(|char| char.is_alphanumeric() || char == '_') {
+            return Err(line.error(rule, "a rule like `a<2006:qkq` or the name of a workflow"));
+        }
+        return Ok(Rule {
+            rule_type: RuleType::Always,
+            comparison: Comparison::Equal, // This does not matter
+            value: 0, // This does not matter
+            result: rule.into(),
+        });
+    };
+    let rule_type = RuleType::Rating(captures.get(1).unwrap().as_str().to_string());
+    // The regex only matches the symbols of comparisons
+    let comparison = Comparison::from_symbol(captures.get(2).unwrap().as_str()).unwrap();
+    let value = line.parse(captures.get(3).unwrap().as_str(), "a rating")?;
+    let result = captures.get(4).unwrap().as_str().into();
+    Ok(Rule {
+        rule_type,
+        comparison,
+        value,
+        result,
+    })
+}
+
+/// Parse a part like `{x=787,m=2655,a=1222,s=2876}` which is the whole line
+fn parse_part(line: Line, part_regex: &Regex) -> Result<Part, ParseError> {
+    line.captures(part_regex, "a part like `{x=787,m=2655,a=1222,s=2876}`")?;
+    let mut ratings = BTreeMap::new();
+    for rating in line.text[1..line.text.len() - 1].split(',') {
+        let (name, value) = line.split_once(rating, "=")?;
+        if ratings.insert(name.to_string(), line.parse(value, "a rating")?).is_some() {
+            return Err(line.error(name, "a rating which the part doesn't already have"));
+        }
+    }
+    Ok(Part { ratings })
+}
+
+/// Preprocess the input into a more useful form
+pub fn preprocess<'a>(workflows_and_ratings: impl Iterator<Item=&'a str>) -> Result<(HashMap<String, Workflow>, Vec<Part>), ParseError> {
+    let mut workflows = HashMap::new();
+
+    let rule_regex = Regex::new(r"^(\w+)(<=|>=|==|<|>)(\d+):(\w+)$").unwrap();
+    let part_regex = Regex::new(r"^\{\w+=\d+(,\w+=\d+)*}$").unwrap();
+
+    let mut workflows_and_ratings = parse::lines(workflows_and_ratings);
+    for workflow in workflows_and_ratings.by_ref() {
+        if workflow.text.is_empty() {
+            break;
+        }
+
+        let (name, rules) = workflow.split_once(workflow.text, "{")?;
+        // Chop off the closing brace
+        let rules = rules.strip_suffix('}').ok_or_else(|| workflow.missing("`}`"))?;
+        workflows.insert(name.into(), Workflow {
+            steps: rules.split(',')
+                .map(|rule| parse_rule(workflow, rule, &rule_regex))
+                .collect::<Result<_, _>>()?,
+        });
+    }
+
+    let parts = workflows_and_ratings
+        .map(|rating| parse_part(rating, &part_regex))
+        .collect::<Result<_, _>>()?;
+
+    Ok((workflows, parts))
+}
+
+/// Where a rule is: the workflow it belongs to and its position in that workflow (from 0)
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct RuleRef {
+    pub workflow: String,
+    pub index: usize,
+}
+
+impl Display for RuleRef {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}[{}]", self.workflow, self.index)
+    }
+}
+
+/// Why the workflows can't be used to sort parts
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum WorkflowError {
+    /// Parts are sent to a workflow which doesn't exist (from a rule, or to start with if there is
+    /// no rule)
+    Missing { workflow: String, from: Option<RuleRef> },
+    /// The workflows can send parts around in a loop which visits these workflows in order
+    Cycle { workflows: Vec<String> },
+    /// A rule checks a rating which parts don't have
+    UnknownRating { rating: String, rule: RuleRef },
+}
+
+impl Display for WorkflowError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WorkflowError::Missing { workflow, from: Some(from) } => write!(f, "the workflow `{workflow}` used by {from} doesn't exist"),
+            WorkflowError::Missing { workflow, from: None } => write!(f, "there is no `{workflow}` workflow to start at"),
+            WorkflowError::Cycle { workflows } => write!(
+                f,
+                "parts can be sent around in a cycle: {} -> {}",
+                workflows.join(" -> "),
+                workflows[0],
+            ),
+            WorkflowError::UnknownRating { rating, rule } => write!(f, "{rule} checks the rating `{rating}` which parts don't have"),
+        }
+    }
+}
+
+impl Error for WorkflowError {}
+
+/// Find the first rule of a workflow which a part matches
+/// Returns the rating of the part which was missing if a rule checks one it doesn't have
+fn evaluate_part<'a>(part: &Part, workflow: &'a Workflow) -> Result<Option<(usize, &'a RuleResult)>, String> {
+    for (index, step) in workflow.steps.iter().enumerate() {
+        let RuleType::Rating(rating) = &step.rule_type else { return Ok(Some((index, &step.result))); };
+        let part_value = *part.ratings.get(rating).ok_or_else(|| rating.clone())?;
+        // If we matched the step then do the result of the step
+        if step.comparison.matches(part_value, step.value) {
+            return Ok(Some((index, &step.result)));
+        }
+    }
+    Ok(None)
+}
+
+/// How a part was sorted by the workflows
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Trace {
+    /// Every rule which the part matched, in order
+    pub rules: Vec<RuleRef>,
+    pub accepted: bool,
+}
+
+/// Follow a part through the workflows to find out why it was accepted or rejected
+pub fn trace(part: &Part, workflows: &HashMap<String, Workflow>, config: &Config) -> Result<Trace, WorkflowError> {
+    let mut rules: Vec<RuleRef> = vec![];
+    let mut workflow = config.start.clone();
+    loop {
+        let Some(current_workflow) = workflows.get(&workflow) else {
+            return Err(WorkflowError::Missing { workflow, from: rules.pop() });
+        };
+        if let Some(first) = rules.iter().position(|rule| rule.workflow == workflow) {
+            return Err(WorkflowError::Cycle { workflows: rules.drain(first..).map(|rule| rule.workflow).collect() });
+        }
+
+        // If nothing matches we just reject - we shouldn't hit this as all workflows end with an
+        // ALWAYS rule
+        let matched = evaluate_part(part, current_workflow).map_err(|rating| {
+            // Point at the rule which needed the rating
+            let index = current_workflow.steps.iter()
+                .position(|step| step.rule_type == RuleType::Rating(rating.clone()))
+                .unwrap_or_default();
+            WorkflowError::UnknownRating { rating, rule: RuleRef { workflow: workflow.clone(), index } }
+        })?;
+        let Some((index, result)) = matched else {
+            return Ok(Trace { rules, accepted: false });
+        };
+        rules.push(RuleRef { workflow, index });
+        match result {
+            RuleResult::Accepted => return Ok(Trace { rules, accepted: true }),
+            RuleResult::Rejected => return Ok(Trace { rules, accepted: false }),
+            RuleResult::SendToWorkflow(next_workflow) => workflow = next_workflow.clone(),
+        }
+    }
+}
+
+/// Check that every workflow which parts can be sent to exists, that every rating which the rules
+/// check is one parts have, and that no part can be sent around in a loop
+pub fn validate(workflows: &HashMap<String, Workflow>, config: &Config) -> Result<(), WorkflowError> {
+    if !workflows.contains_key(&config.start) {
+        return Err(WorkflowError::Missing { workflow: config.start.clone(), from: None });
+    }
+    let names = workflows.keys().sorted().collect::<Vec<_>>();
+    for &name in &names {
+        for (index, step) in workflows[name].steps.iter().enumerate() {
+            let rule = || RuleRef { workflow: name.clone(), index };
+            if let RuleType::Rating(rating) = &step.rule_type {
+                if !config.ratings.iter().any(|(known, _)| known == rating) {
+                    return Err(WorkflowError::UnknownRating { rating: rating.clone(), rule: rule() });
+                }
+            }
+            if let RuleResult::SendToWorkflow(workflow) = &step.result {
+                if !workflows.contains_key(workflow) {
+                    return Err(WorkflowError::Missing { workflow: workflow.clone(), from: Some(rule()) });
+                }
+            }
+        }
+    }
+
+    // A depth-first search finds a cycle when it gets back to a workflow which is on the current path
+    let mut finished = HashSet::new();
+    for &name in &names {
+        let mut path = vec![];
+        find_cycle(name, workflows, &mut path, &mut finished)?;
+    }
+    Ok(())
+}
+
+/// Search for a cycle through the workflows which can be reached from one
+fn find_cycle<'a>(name: &'a String, workflows: &'a HashMap<String, Workflow>, path: &mut Vec<&'a String>, finished: &mut HashSet<&'a String>) -> Result<(), WorkflowError> {
+    if let Some(first) = path.iter().position(|&workflow| workflow == name) {
+        return Err(WorkflowError::Cycle { workflows: path[first..].iter().map(|&workflow| workflow.clone()).collect() });
+    }
+    if !finished.insert(name) {
+        return Ok(());
+    }
+    path.push(name);
+    for step in &workflows[name].steps {
+        if let RuleResult::SendToWorkflow(next) = &step.result {
+            find_cycle(next, workflows, path, finished)?;
+        }
+    }
+    path.pop();
+    Ok(())
+}
+
+/// Part 1
+pub fn get_total_accepted((workflows, parts): &(HashMap<String, Workflow>, Vec<Part>)) -> usize {
+    let config = Config::default();
+    validate(workflows, &config).unwrap_or_else(|error| panic!("Unable to sort the parts: {error}"));
+    parts.iter()
+        // Get the parts which are accepted (the parts don't have to be within the ranges of the
+        // config, so follow each one through the workflows rather than checking the accepted ranges)
+        .filter(|part| trace(part, workflows, &config)
+            .unwrap_or_else(|error| panic!("Unable to sort {part:?}: {error}"))
+            .accepted)
+        // Compute the sum of their stats
+        .map(|part| part.ratings.values().sum::<usize>())
+        // Sum it all up
+        .sum()
+}
+
+/// Represents a range of parts
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PartRange {
+    pub ratings: BTreeMap<String, RangeInclusive<usize>>,
+}
+
+impl PartRange {
+    /// Get the total size of this part range
+    /// Returns None if it is too big to count
+    pub fn get_total_size(&self) -> Option<u128> {
+        self.ratings.values()
+            .map(|range| if range.is_empty() { 0 } else { (range.end() - range.start()) as u128 + 1 })
+            .try_fold(1_u128, u128::checked_mul)
+    }
+
+    /// Check if there are no parts in this range
+    pub fn is_empty(&self) -> bool {
+        self.ratings.values().any(RangeInclusive::is_empty)
+    }
+
+    /// Check if a part is in this range
+    /// A part which doesn't have one of the ratings isn't in it
+    pub fn contains(&self, part: &Part) -> bool {
+        self.ratings.iter()
+            .all(|(name, range)| part.ratings.get(name).is_some_and(|rating| range.contains(rating)))
+    }
+
+    /// Split this range into the parts which match a rule and the parts which don't (which can take
+    /// more than one range)
+    /// Any of these ranges can be empty
+    fn split(&self, rule: &Rule) -> (Self, Vec<Self>) {
+        let RuleType::Rating(rating) = &rule.rule_type else { return (self.clone(), vec![]); };
+        let with = |range| {
+            let mut part_range = self.clone();
+            part_range.ratings.insert(rating.clone(), range);
+            part_range
+        };
+        let (applies, fails) = rule.comparison.split(&self.ratings[rating], rule.value);
+        (with(applies), fails.into_iter().map(with).collect())
+    }
+}
+
+/// Everything the workflows could do to any part, worked out up front
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Analysis {
+    /// The ranges of parts which are accepted (none of them overlap)
+    pub accepted: Vec<PartRange>,
+    /// The rules which no part can match (in order)
+    pub unreachable: Vec<RuleRef>,
+}
+
+impl Analysis {
+    /// Check if a part is accepted
+    pub fn is_accepted(&self, part: &Part) -> bool {
+        self.accepted.iter().any(|range| range.contains(part))
+    }
+
+    /// Count the combinations of ratings which are accepted
+    /// Returns None if there are too many to count
+    pub fn count_accepted(&self) -> Option<u128> {
+        self.accepted.iter().try_fold(0_u128, |total, range| total.checked_add(range.get_total_size()?))
+    }
+}
+
+/// Work out which parts are accepted by sending the whole range of possible parts through the
+/// workflows at once
+pub fn analyze(workflows: &HashMap<String, Workflow>, config: &Config) -> Result<Analysis, WorkflowError> {
+    validate(workflows, config)?;
+    let mut accepted = vec![];
+    let mut reached = HashSet::new();
+    let everything = PartRange { ratings: config.ratings.iter().cloned().collect() };
+    explore(everything, &config.start, 0, workflows, &mut accepted, &mut reached);
+
+    let unreachable = workflows.iter()
+        .flat_map(|(name, workflow)| (0..workflow.steps.len()).map(|index| RuleRef { workflow: name.clone(), index }))
+        .filter(|rule| !reached.contains(rule))
+        .sorted()
+        .collect();
+    Ok(Analysis { accepted, unreachable })
+}
+
+/// Send a range of parts through a workflow starting from one of its rules, recording the ranges
+/// which get accepted and the rules which match any parts
+/// The workflows must have been validated so that this doesn't go around forever
+fn explore(part_range: PartRange, workflow: &str, index: usize, workflows: &HashMap<String, Workflow>, accepted: &mut Vec<PartRange>, reached: &mut HashSet<RuleRef>) {
+    // If nothing matches the range is rejected
+    let Some(step) = workflows[workflow].steps.get(index) else { return; };
+    let (applies, fails) = part_range.split(step);
+    if !applies.is_empty() {
+        reached.insert(RuleRef { workflow: workflow.to_string(), index });
+        match &step.result {
+            RuleResult::Accepted => accepted.push(applies),
+            // If we reject the range - delete it from further consideration and ignore it
+            RuleResult::Rejected => {}
+            RuleResult::SendToWorkflow(next_workflow) => explore(applies, next_workflow, 0, workflows, accepted, reached),
+        }
+    }
+    // Continue trying to apply future steps on the ranges which did not match the current step
+    for fails in fails.into_iter().filter(|fails| !fails.is_empty()) {
+        explore(fails, workflow, index + 1, workflows, accepted, reached);
+    }
+}
+
+/// Part 2
+pub fn get_accept_combinations((workflows, _): &(HashMap<String, Workflow>, Vec<Part>)) -> u128 {
+    analyze(workflows, &Config::default())
+        .unwrap_or_else(|error| panic!("Unable to sort the parts: {error}"))
+        .count_accepted()
+        .expect("The default ratings should have few enough combinations to count")
+}
+
+#[test]
+fn test_part1() {
+    assert_eq!(
+        19114,
+        get_total_accepted(
+            &preprocess(r"px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}".lines()).unwrap()
+        )
+    );
+}
+
+#[test]
+fn test_part1_out_of_range() {
+    // Part 1 follows the parts through the workflows, so ratings outside of 1 to 4000 and missing
+    // ratings which no rule checks still count
+    let input = preprocess("in{A}\n\n{x=5000,m=1,a=1,s=1}\n{x=1,m=1,a=1,s=1}\n{x=1,m=1,a=1}".lines()).unwrap();
+    assert_eq!(5010, get_total_accepted(&input));
+
+    let input = preprocess(r"in{x>4000:big,R}
+big{m<1:R,A}
+
+{x=5000,m=1,a=1,s=1}
+{x=5000,m=0,a=1,s=1}
+{x=4000,m=1,a=1,s=1}".lines()).unwrap();
+    let (workflows, parts) = &input;
+    let traced = parts.iter()
+        .filter(|part| trace(part, workflows, &Config::default()).unwrap().accepted)
+        .map(|part| part.ratings.values().sum::<usize>())
+        .sum::<usize>();
+    assert_eq!(5003, traced);
+    assert_eq!(traced, get_total_accepted(&input));
+}
+
+#[test]
+fn test_part2() {
+    assert_eq!(
+        167409079868000,
+        get_accept_combinations(
+            &preprocess(r"px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}".lines()).unwrap()
+        )
+    );
+}
+
+#[test]
+fn test_trace() {
+    let (workflows, parts) = preprocess(r"px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}".lines()).unwrap();
+    let rule = |workflow: &str, index| RuleRef { workflow: workflow.to_string(), index };
+    assert_eq!(
+        Ok(Trace { rules: vec![rule("in", 1), rule("qqz", 0), rule("qs", 1), rule("lnx", 0)], accepted: true }),
+        trace(&parts[0], &workflows, &Config::default())
+    );
+    assert_eq!(
+        Ok(Trace { rules: vec![rule("in", 0), rule("px", 2), rule("rfg", 0), rule("gd", 1)], accepted: false }),
+        trace(&parts[1], &workflows, &Config::default())
+    );
+}
+
+#[test]
+fn test_analyze() {
+    let (workflows, parts) = preprocess(r"px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}".lines()).unwrap();
+    let analysis = analyze(&workflows, &Config::default()).unwrap();
+    assert_eq!(Some(167409079868000), analysis.count_accepted());
+    assert_eq!(
+        vec![true, false, true, false, true],
+        parts.iter().map(|part| analysis.is_accepted(part)).collect::<Vec<_>>()
+    );
+    assert!(analysis.unreachable.is_empty());
+
+    // The second rule only sees parts with x <= 10 so it can never match
+    let (workflows, _) = preprocess("in{x>10:A,x>20:R,R}".lines()).unwrap();
+    let analysis = analyze(&workflows, &Config::default()).unwrap();
+    assert_eq!(vec![RuleRef { workflow: "in".to_string(), index: 1 }], analysis.unreachable);
+    assert_eq!(11..=4000, analysis.accepted[0].ratings["x"]);
+    assert_eq!(1..=4000, analysis.accepted[0].ratings["s"]);
+    assert_eq!(1, analysis.accepted.len());
+}
+
+#[test]
+fn test_workflow_errors() {
+    let (workflows, parts) = preprocess("in{x>10:ab,R}\nab{m<5:A,ca}\n\n{x=11,m=5,a=1,s=1}".lines()).unwrap();
+    let error = WorkflowError::Missing { workflow: "ca".to_string(), from: Some(RuleRef { workflow: "ab".to_string(), index: 1 }) };
+    assert_eq!(Err(error.clone()), analyze(&workflows, &Config::default()));
+    assert_eq!(Err(error.clone()), trace(&parts[0], &workflows, &Config::default()));
+    assert_eq!("the workflow `ca` used by ab[1] doesn't exist", error.to_string());
+
+    let (workflows, _) = preprocess("ab{A}".lines()).unwrap();
+    assert_eq!(Err(WorkflowError::Missing { workflow: "in".to_string(), from: None }), analyze(&workflows, &Config::default()));
+
+    let (workflows, parts) = preprocess("in{x>10:ab,R}\nab{m<5:A,ca}\nca{in}\n\n{x=11,m=5,a=1,s=1}".lines()).unwrap();
+    let error = WorkflowError::Cycle { workflows: vec!["ab".to_string(), "ca".to_string(), "in".to_string()] };
+    assert_eq!(Err(error.clone()), analyze(&workflows, &Config::default()));
+    assert_eq!("parts can be sent around in a cycle: ab -> ca -> in -> ab", error.to_string());
+    assert_eq!(
+        Err(WorkflowError::Cycle { workflows: vec!["in".to_string(), "ab".to_string(), "ca".to_string()] }),
+        trace(&parts[0], &workflows, &Config::default())
+    );
+}
+
+#[test]
+fn test_extended_rules() {
+    let (workflows, parts) = preprocess(r"start{size<=3:small,size>=8:big,weight==5:A,R}
+small{weight<10:A,R}
+big{weight>=20:R,A}
+
+{size=3,weight=9}
+{size=5,weight=5}
+{size=5,weight=6}
+{size=8,weight=20}
+{size=9,weight=19}".lines()).unwrap();
+    let config = Config {
+        start: "start".to_string(),
+        ratings: vec![("size".to_string(), 1..=10), ("weight".to_string(), 0..=99)],
+    };
+    let analysis = analyze(&workflows, &config).unwrap();
+    assert_eq!(
+        vec![true, true, false, false, true],
+        parts.iter().map(|part| analysis.is_accepted(part)).collect::<Vec<_>>()
+    );
+    // Small parts weighing less than 10, big ones weighing less than 20, and any weighing 5
+    assert_eq!(Some(3 * 10 + 3 * 20 + 4), analysis.count_accepted());
+    assert_eq!(
+        Ok(Trace { rules: vec![RuleRef { workflow: "start".to_string(), index: 2 }], accepted: true }),
+        trace(&parts[1], &workflows, &config)
+    );
+
+    // The puzzle's ratings don't include these
+    assert_eq!(
+        Err(WorkflowError::Missing { workflow: "in".to_string(), from: None }),
+        analyze(&workflows, &Config::default())
+    );
+    let config = Config { start: "start".to_string(), ..Config::default() };
+    assert_eq!(
+        Err(WorkflowError::UnknownRating { rating: "weight".to_string(), rule: RuleRef { workflow: "big".to_string(), index: 0 } }),
+        analyze(&workflows, &config)
+    );
+}
+
+#[test]
+fn test_large_ratings() {
+    let max = usize::MAX;
+    let input = format!(r"in{{x<={max}:edge,R}}
+edge{{x>{max}:R,x=={max}:A,m>=1048576:R,A}}
+
+{{x=1}}");
+    let (workflows, _) = preprocess(input.lines()).unwrap();
+    let config = |ratings: &[&str], range: RangeInclusive<usize>| Config {
+        start: "in".to_string(),
+        ratings: ratings.iter().map(|rating| (rating.to_string(), range.clone())).collect(),
+    };
+
+    // Far more combinations than fit in a u64: any x, any a and s, and m below 2^20
+    let analysis = analyze(&workflows, &config(&["x", "m", "a", "s"], 1..=1 << 20)).unwrap();
+    assert_eq!(Some((1 << 20) * ((1 << 20) - 1) * (1 << 40)), analysis.count_accepted());
+    // The largest x is accepted with any m, and the rest with m below 2^20
+    let size = max as u128 + 1;
+    let analysis = analyze(&workflows, &config(&["x", "m"], 0..=max)).unwrap();
+    assert_eq!(Some(size + (size - 1) * (1 << 20)), analysis.count_accepted());
+    // Too many to count
+    let analysis = analyze(&workflows, &config(&["x", "m", "a", "s"], 0..=max)).unwrap();
+    assert_eq!(None, analysis.count_accepted());
+}
+
+#[test]
+fn test_parse_error() {
+    assert_eq!(
+        Some(ParseError::at(0, 5, "99999999999999999999999", "a rating")),
+        preprocess("px{q<99999999999999999999999:qkq,m>2090:A,rfg}".lines()).err()
+    );
+    assert_eq!(
+        Some(ParseError::at(0, 14, "m=2090:A", "a rule like `a<2006:qkq` or the name of a workflow")),
+        preprocess("px{a<2006:qkq,m=2090:A,rfg}".lines()).err()
+    );
+    assert_eq!(
+        None,
+        preprocess("in{x<5:my_flow,my_flow}\nmy_flow{A}".lines()).err()
+    );
+    assert_eq!(
+        Some(ParseError::at(0, 26, "", "`}`")),
+        preprocess("px{a<2006:qkq,m>2090:A,rfg".lines()).err()
+    );
+    assert_eq!(
+        Some(ParseError::at(2, 0, "{x=787,m=2655,a}", "a part like `{x=787,m=2655,a=1222,s=2876}`")),
+        preprocess("in{A}\n\n{x=787,m=2655,a}".lines()).err()
+    );
+    assert_eq!(
+        Some(ParseError::at(2, 7, "x", "a rating which the part doesn't already have")),
+        preprocess("in{A}\n\n{x=787,x=2655}".lines()).err()
+    );
+}
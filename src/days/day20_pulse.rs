@@ -0,0 +1,909 @@
+/*
+With your help, the Elves manage to find the right parts and fix all of the
+machines. Now, they just need to send the command to boot up the machines
+and get the sand flowing again.
+
+The machines are far apart and wired together with long cables. The cables
+don't connect to the machines directly, but rather to communication modules
+attached to the machines that perform various initialization tasks and also
+act as communication relays.
+
+Modules communicate using pulses. Each pulse is either a high pulse or a
+low pulse. When a module sends a pulse, it sends that type of pulse to each
+module in its list of destination modules.
+
+There are several different types of modules:
+
+Flip-flop modules (prefix %) are either on or off; they are initially off.
+If a flip-flop module receives a high pulse, it is ignored and nothing
+happens. However, if a flip-flop module receives a low pulse, it flips
+between on and off. If it was off, it turns on and sends a high pulse. If
+it was on, it turns off and sends a low pulse.
+
+Conjunction modules (prefix &) remember the type of the most recent pulse
+received from each of their connected input modules; they initially default
+to remembering a low pulse for each input. When a pulse is received, the
+conjunction module first updates its memory for that input. Then, if it
+remembers high pulses for all inputs, it sends a low pulse; otherwise, it
+sends a high pulse.
+
+There is a single broadcast module (named broadcaster). When it receives a
+pulse, it sends the same pulse to all of its destination modules.
+
+Here at Desert Machine Headquarters, there is a module with a single button
+on it called, aptly, the button module. When you push the button, a single
+low pulse is sent directly to the broadcaster module.
+
+After pushing the button, you must wait until all pulses have been
+delivered and fully handled before pushing it again. Never push the button
+if modules are still processing pulses.
+
+Pulses are always processed in the order they are sent. So, if a pulse is
+sent to modules a, b, and c, and then module a processes its pulse and
+sends more pulses, the pulses sent to modules b and c would have to be
+handled first.
+
+The module configuration (your puzzle input) lists each module. The name of
+the module is preceded by a symbol identifying its type, if any. The name
+is then followed by an arrow and a list of its destination modules. For
+example:
+
+broadcaster -> a, b, c
+%a -> b
+%b -> c
+%c -> inv
+&inv -> a
+
+In this module configuration, the broadcaster has three destination modules
+named a, b, and c. Each of these modules is a flip-flop module (as
+indicated by the % prefix). a outputs to b which outputs to c which
+outputs to another module named inv. inv is a conjunction module (as
+indicated by the & prefix) which, because it has only one input, acts like
+an inverter (it sends the opposite of the pulse type it receives); it
+outputs to a.
+
+By pushing the button once, the following pulses are sent:
+
+button -low-> broadcaster
+broadcaster -low-> a
+broadcaster -low-> b
+broadcaster -low-> c
+a -high-> b
+b -high-> c
+c -high-> inv
+inv -low-> a
+a -low-> b
+b -low-> c
+c -low-> inv
+inv -high-> a
+
+After this sequence, the flip-flop modules all end up off, so pushing the
+button again repeats the same sequence.
+
+Here's a more interesting example:
+
+broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output
+
+This module configuration includes the broadcaster, two flip-flops (named a
+and b), a single-input conjunction module (inv), a multi-input conjunction
+module (con), and an untyped module named output (for testing purposes).
+The multi-input conjunction module con watches the two flip-flop modules
+and, if they're both on, sends a low pulse to the output module.
+
+Here's what happens if you push the button once:
+
+button -low-> broadcaster
+broadcaster -low-> a
+a -high-> inv
+a -high-> con
+inv -low-> b
+con -high-> output
+b -high-> con
+con -low-> output
+
+Both flip-flops turn on and a low pulse is sent to output! However, now
+that both flip-flops are on and con remembers a high pulse from each of its
+two inputs, pushing the button a second time does something different:
+
+button -low-> broadcaster
+broadcaster -low-> a
+a -low-> inv
+a -low-> con
+inv -high-> b
+con -high-> output
+Flip-flop a turns off! Now, con remembers a low pulse from module a, and so
+it sends only a high pulse to output.
+
+Push the button a third time:
+
+button -low-> broadcaster
+broadcaster -low-> a
+a -high-> inv
+a -high-> con
+inv -low-> b
+con -low-> output
+b -low-> con
+con -high-> output
+This time, flip-flop a turns on, then flip-flop b turns off. However,
+before b can turn off, the pulse sent to con is handled first, so it
+briefly remembers all high pulses for its inputs and sends a low pulse to
+output. After that, flip-flop b turns off, which causes con to update its
+state and send a high pulse to output.
+
+Finally, with a on and b off, push the button a fourth time:
+
+button -low-> broadcaster
+broadcaster -low-> a
+a -low-> inv
+a -low-> con
+inv -high-> b
+con -high-> output
+
+This completes the cycle: a turns off, causing con to remember only low
+pulses and restoring all modules to their original states.
+
+To get the cables warmed up, the Elves have pushed the button 1000 times.
+How many pulses got sent as a result (including the pulses sent by the
+button itself)?
+
+In the first example, the same thing happens every time the button is
+pushed: 8 low pulses and 4 high pulses are sent. So, after pushing the
+button 1000 times, 8000 low pulses and 4000 high pulses are sent.
+Multiplying these together gives 32000000.
+
+In the second example, after pushing the button 1000 times, 4250 low
+pulses and 2750 high pulses are sent. Multiplying these together gives
+11687500.
+
+Consult your module configuration; determine the number of low pulses and
+high pulses that would be sent after pushing the button 1000 times, waiting
+for all pulses to be fully handled after each push of the button. What do
+you get if you multiply the total number of low pulses sent by the total
+number of high pulses sent?
+
+--- Part Two ---
+
+The final machine responsible for moving the sand down to Island Island has
+a module attached named rx. The machine turns on when a single low pulse is
+sent to rx.
+
+Reset all modules to their default states. Waiting for all pulses to be
+fully handled after each button press, what is the fewest number of button
+presses required to deliver a single low pulse to the module named rx?
+ */
+
+use std::collections::{HashMap, VecDeque};
+use std::fmt::{Display, Formatter};
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use itertools::Itertools;
+
+use crate::math::{solve_congruences, Congruence, CongruenceError};
+use crate::parse::{self, ParseError};
+use crate::Solution;
+
+/// Day 20: Pulse Propagation
+pub struct PulsePropagation;
+
+impl Solution for PulsePropagation {
+    const DAY: u8 = 20;
+    const TITLE: &'static str = "Pulse Propagation";
+    const INPUT: &'static str = "./inputs/20_pulse.txt";
+
+    type Input<'a> = Modules<'a>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        preprocess(input.lines())
+    }
+
+    fn part1(modules: &Self::Input<'_>) -> usize {
+        get_1000_pulse_product(modules)
+    }
+
+    fn part2(modules: &Self::Input<'_>) -> usize {
+        get_fewest_pushes_to_rx(modules)
+    }
+}
+
+/// Represents a type of pulse
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Pulse {
+    Low,
+    High,
+}
+
+/// A signal sent by one of the modules in this problem
+#[derive(Debug, Eq, PartialEq)]
+struct Signal {
+    source: usize,
+    pulse: Pulse,
+    destination: usize,
+}
+
+/// A structure to store a module
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Module {
+    Broadcaster {
+        outputs: Vec<usize>,
+    },
+    FlipFlop {
+        is_on: bool,
+        outputs: Vec<usize>,
+    },
+    Conjunction {
+        received: HashMap<usize, Pulse>,
+        outputs: Vec<usize>,
+    },
+    Null,
+}
+
+/// The modules along with the index of the broadcaster and the index of each named module
+/// Slot 0 is a null module standing in for the button
+pub type Modules<'a> = (Vec<Module>, usize, HashMap<&'a str, usize>);
+
+/// Preprocess the input into a more useful form
+pub fn preprocess<'a>(modules: impl Iterator<Item=&'a str>) -> Result<Modules<'a>, ParseError> {
+    // We need to loop over this twice so store it
+    let modules = parse::lines(modules)
+        .map(|line| Ok((line, line.split_once(line.text, " -> ")?)))
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut result = vec![];
+    let mut broadcast = 0;
+    result.push(Module::Null); // Keep a null module around in slot 0 just to be safe
+
+    let mut mapping = HashMap::<&'a str, usize>::new();
+    for (line, (module, _)) in &modules {
+        if *module == "broadcaster" {
+            mapping.insert(module, result.len());
+            broadcast = result.len();
+            result.push(Module::Broadcaster { outputs: vec![] });
+        } else if let Some(module) = module.strip_prefix('%') {
+            mapping.insert(module, result.len());
+            result.push(Module::FlipFlop { is_on: false, outputs: vec![] });
+        } else if let Some(module) = module.strip_prefix('&') {
+            mapping.insert(module, result.len());
+            result.push(Module::Conjunction { received: HashMap::new(), outputs: vec![] });
+        } else {
+            return Err(line.error(module, "`broadcaster` or a module name starting with `%` or `&`"));
+        }
+    }
+    if broadcast == 0 {
+        return Err(ParseError::at(modules.len(), 0, "", "a `broadcaster` module"));
+    }
+
+    mapping.insert("rx", result.len());
+    result.push(Module::Null);
+
+    modules.iter().enumerate().for_each(|(i, (_, (_, destination)))| {
+        let module_index = i + 1;
+
+        let mut new_outputs = vec![];
+        destination.split(", ").for_each(|recipient| {
+            // Modules which are only ever sent pulses don't do anything, but they still get a name
+            let recipient = *mapping.entry(recipient).or_insert_with(|| {
+                result.push(Module::Null);
+                result.len() - 1
+            });
+
+            new_outputs.push(recipient);
+            if let Module::Conjunction { received, .. } = &mut result[recipient] {
+                received.insert(module_index, Pulse::Low);
+            }
+        });
+
+        match &mut result[module_index] {
+            Module::Broadcaster { outputs } => { *outputs = new_outputs }
+            Module::FlipFlop { outputs, .. } => { *outputs = new_outputs }
+            Module::Conjunction { outputs, .. } => { *outputs = new_outputs }
+            Module::Null => {}
+        };
+    });
+
+    Ok((result, broadcast, mapping))
+}
+
+/// Simulate pushing the button
+fn push_button(modules: &mut [Module], broadcast: usize, mut on_pulse: impl FnMut(Signal)) {
+    let mut signal_queue = VecDeque::<Signal>::new();
+
+    signal_queue.push_back(Signal {
+        source: 0, // Coming from the NULL
+        pulse: Pulse::Low,
+        destination: broadcast,
+    });
+
+    while let Some(Signal { source, pulse, destination }) = signal_queue.pop_front() {
+        on_pulse(Signal {
+            source,
+            pulse,
+            destination,
+        });
+
+        match &mut modules[destination] {
+            Module::Broadcaster { outputs } => {
+                outputs.iter().for_each(|output| {
+                    signal_queue.push_back(Signal { source: destination, pulse, destination: *output });
+                });
+            }
+            Module::FlipFlop { is_on, outputs } => {
+                if pulse == Pulse::Low {
+                    *is_on = !*is_on;
+                    let new_pulse = if *is_on { Pulse::High } else { Pulse::Low };
+                    outputs.iter().for_each(|output| {
+                        signal_queue.push_back(Signal { source: destination, pulse: new_pulse, destination: *output });
+                    });
+                }
+            }
+            Module::Conjunction { received, outputs } => {
+                received.insert(source, pulse);
+                let new_pulse = if received.values().all(|p| *p == Pulse::High) { Pulse::Low } else { Pulse::High };
+                outputs.iter().for_each(|output| {
+                    signal_queue.push_back(Signal { source: destination, pulse: new_pulse, destination: *output });
+                });
+            }
+            Module::Null => {} // NoOp
+        }
+    }
+}
+
+impl Display for Pulse {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Pulse::Low => write!(f, "low"),
+            Pulse::High => write!(f, "high"),
+        }
+    }
+}
+
+/// A pulse sent from one module to another
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Event<'a> {
+    /// Which push of the button sent this pulse (starting from 1)
+    pub press: usize,
+    pub source: &'a str,
+    pub pulse: Pulse,
+    pub destination: &'a str,
+}
+
+impl Display for Event<'_> {
+    /// Show the event the way the puzzle does, like `button -low-> broadcaster`
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} -{}-> {}", self.source, self.pulse, self.destination)
+    }
+}
+
+/// The button was pushed `start` times before the network was first in a state which it got back
+/// to after `length` more pushes
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct StateCycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+/// The modules along with the state they are in after pushing the button some number of times
+#[derive(Debug, Clone)]
+pub struct Network<'a> {
+    modules: Vec<Module>,
+    broadcast: usize,
+    /// The name of each module
+    names: Vec<&'a str>,
+    presses: usize,
+}
+
+impl<'a> Network<'a> {
+    /// Create a network where none of the modules have received any pulses yet
+    pub fn new((modules, broadcast, mapping): &Modules<'a>) -> Self {
+        let mut names = vec!["button"; modules.len()];
+        mapping.iter().for_each(|(&name, &index)| names[index] = name);
+        Network { modules: modules.clone(), broadcast: *broadcast, names, presses: 0 }
+    }
+
+    /// Get the number of times the button has been pushed
+    pub fn presses(&self) -> usize {
+        self.presses
+    }
+
+    /// Push the button once, handing each pulse to `on_pulse` in the order they are processed
+    pub fn press_with(&mut self, mut on_pulse: impl FnMut(Event<'a>)) {
+        self.presses += 1;
+        let (names, press) = (&self.names, self.presses);
+        push_button(&mut self.modules, self.broadcast, |Signal { source, pulse, destination }| {
+            on_pulse(Event { press, source: names[source], pulse, destination: names[destination] });
+        });
+    }
+
+    /// Push the button once and get the log of every pulse it sent
+    pub fn press(&mut self) -> Vec<Event<'a>> {
+        let mut events = vec![];
+        self.press_with(|event| events.push(event));
+        events
+    }
+
+    /// Get the state of every module in a canonical form: whether each flip-flop is on followed by
+    /// whether each conjunction remembers a high pulse from each of its inputs (in index order)
+    pub fn state(&self) -> Vec<bool> {
+        let mut state = vec![];
+        self.modules.iter().for_each(|module| module.push_state(&mut state));
+        state
+    }
+
+    /// Hash the state of every module
+    /// Networks in the same state always have the same hash
+    pub fn state_hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.state().hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Keep pushing the button until the network gets back to a state it has already been in
+    /// Returns None if that doesn't happen within `limit` more pushes
+    pub fn find_state_cycle(&mut self, limit: usize) -> Option<StateCycle> {
+        let mut seen = HashMap::from([(self.state(), self.presses)]);
+        for _ in 0..limit {
+            push_button(&mut self.modules, self.broadcast, |_| {});
+            self.presses += 1;
+            if let Some(start) = seen.insert(self.state(), self.presses) {
+                return Some(StateCycle { start, length: self.presses - start });
+            }
+        }
+        None
+    }
+
+    /// Draw the modules and how they are connected in the DOT language used by Graphviz
+    pub fn to_dot(&self) -> String {
+        let mut dot = "digraph modules {\n".to_string();
+        for (index, module) in self.modules.iter().enumerate() {
+            let style = match module {
+                Module::Broadcaster { .. } => "shape=doublecircle",
+                Module::FlipFlop { .. } => "shape=box, style=filled, fillcolor=\"#ffd27f\", label=\"%\\N\"",
+                Module::Conjunction { .. } => "shape=invhouse, style=filled, fillcolor=\"#9fc5f8\", label=\"&\\N\"",
+                Module::Null if index == 0 => "shape=plaintext",
+                Module::Null => "shape=ellipse, style=dashed",
+            };
+            dot += &format!("    \"{}\" [{style}];\n", self.names[index]);
+        }
+        dot += &format!("    \"button\" -> \"{}\";\n", self.names[self.broadcast]);
+        for (index, module) in self.modules.iter().enumerate() {
+            for &output in module.outputs() {
+                dot += &format!("    \"{}\" -> \"{}\";\n", self.names[index], self.names[output]);
+            }
+        }
+        dot += "}\n";
+        dot
+    }
+}
+
+/// Solve part 1
+pub fn get_1000_pulse_product(modules: &Modules) -> usize {
+    // Brute force - push the button 1000 times
+    let mut network = Network::new(modules);
+
+    let mut low_pulse_count = 0;
+    let mut high_pulse_count = 0;
+
+    // Push the button 1000 times
+    for _ in 0..1000 {
+        network.press_with(|Event { pulse, .. }| {
+            match pulse {
+                Pulse::Low => low_pulse_count += 1,
+                Pulse::High => high_pulse_count += 1,
+            }
+        });
+    }
+
+    low_pulse_count * high_pulse_count
+}
+
+/// The most times the button is pushed while looking for `rx` to get a low pulse
+pub const MAX_PRESSES: usize = 100_000;
+
+impl Module {
+    /// Get the modules this one sends pulses to
+    fn outputs(&self) -> &[usize] {
+        match self {
+            Module::Broadcaster { outputs } | Module::FlipFlop { outputs, .. } | Module::Conjunction { outputs, .. } => outputs,
+            Module::Null => &[],
+        }
+    }
+
+    /// Add the state of this module to a canonical state: whether a flip-flop is on or whether a
+    /// conjunction remembers a high pulse from each of its inputs (in index order)
+    fn push_state(&self, state: &mut Vec<bool>) {
+        match self {
+            Module::FlipFlop { is_on, .. } => state.push(*is_on),
+            Module::Conjunction { received, .. } => state.extend(received.iter()
+                .sorted_by_key(|(&input, _)| input)
+                .map(|(_, &pulse)| pulse == Pulse::High)),
+            Module::Broadcaster { .. } | Module::Null => {}
+        }
+    }
+}
+
+/// Find the strongly connected components of the modules (the groups where every module can send
+/// pulses around to every other) using Tarjan's algorithm
+/// Returns the component each module is in
+fn strongly_connected_components(modules: &[Module]) -> Vec<usize> {
+    struct Search<'a> {
+        modules: &'a [Module],
+        /// The order each module was first visited in along with the earliest module it can get back to
+        visited: Vec<Option<(usize, usize)>>,
+        /// How many modules have been visited so far
+        next_order: usize,
+        stack: Vec<usize>,
+        on_stack: Vec<bool>,
+        components: Vec<usize>,
+        next_component: usize,
+    }
+
+    impl Search<'_> {
+        fn visit(&mut self, module: usize) -> usize {
+            let order = self.next_order;
+            self.next_order += 1;
+            let mut lowest = order;
+            self.visited[module] = Some((order, order));
+            self.stack.push(module);
+            self.on_stack[module] = true;
+            for &output in self.modules[module].outputs() {
+                match self.visited[output] {
+                    None => lowest = lowest.min(self.visit(output)),
+                    Some((output_order, _)) if self.on_stack[output] => lowest = lowest.min(output_order),
+                    Some(_) => {}
+                }
+            }
+            self.visited[module] = Some((order, lowest));
+
+            // This is the first module of its component so everything above it on the stack is in it
+            if lowest == order {
+                while let Some(member) = self.stack.pop() {
+                    self.on_stack[member] = false;
+                    self.components[member] = self.next_component;
+                    if member == module {
+                        break;
+                    }
+                }
+                self.next_component += 1;
+            }
+            lowest
+        }
+    }
+
+    let mut search = Search {
+        modules,
+        visited: vec![None; modules.len()],
+        next_order: 0,
+        stack: vec![],
+        on_stack: vec![false; modules.len()],
+        components: vec![0; modules.len()],
+        next_component: 0,
+    };
+    for module in 0..modules.len() {
+        if search.visited[module].is_none() {
+            search.visit(module);
+        }
+    }
+    search.components
+}
+
+/// A part of the network which feeds one input of the conjunction in front of `rx`
+#[derive(Debug, Clone)]
+struct SubCircuit {
+    /// The module which sends pulses to the final conjunction
+    output: usize,
+    /// Every module which can send pulses (even indirectly) to the output, including it
+    modules: Vec<usize>,
+    /// The presses during which the output sent a high pulse to the final conjunction
+    highs: Vec<usize>,
+    /// The states the modules were in after each press
+    seen: HashMap<Vec<bool>, usize>,
+    /// The cycle of states once it is found
+    cycle: Option<StateCycle>,
+}
+
+impl SubCircuit {
+    /// Get the state of the modules in the sub-circuit
+    fn state(&self, modules: &[Module]) -> Vec<bool> {
+        let mut state = vec![];
+        self.modules.iter().for_each(|&module| modules[module].push_state(&mut state));
+        state
+    }
+}
+
+/// Split the network into the sub-circuits which feed the conjunction in front of `rx`
+/// Returns None unless `rx` is fed by a single conjunction whose inputs are fed by separate groups
+/// of strongly connected components (which only share the broadcaster)
+fn find_sub_circuits(modules: &[Module], broadcast: usize, rx: usize) -> Option<(usize, Vec<SubCircuit>)> {
+    let mut feeding_rx = (0..modules.len()).filter(|&module| modules[module].outputs().contains(&rx));
+    let (Some(last), None) = (feeding_rx.next(), feeding_rx.next()) else { return None; };
+    let Module::Conjunction { received, .. } = &modules[last] else { return None; };
+
+    let components = strongly_connected_components(modules);
+    let mut inputs = (0..modules.len()).map(|_| vec![]).collect::<Vec<_>>();
+    for (module, outputs) in modules.iter().map(Module::outputs).enumerate() {
+        outputs.iter().for_each(|&output| inputs[output].push(module));
+    }
+
+    let mut sub_circuits = vec![];
+    let mut claimed = HashMap::new();
+    for &output in received.keys().sorted() {
+        // Walk backwards from the output to everything which can send pulses to it
+        let mut upstream = vec![output];
+        let mut queue = VecDeque::from([output]);
+        while let Some(module) = queue.pop_front() {
+            for &input in &inputs[module] {
+                if input != broadcast && input != 0 && !upstream.contains(&input) {
+                    upstream.push(input);
+                    queue.push_back(input);
+                }
+            }
+        }
+        // Each strongly connected component can only be part of one sub-circuit
+        for &module in &upstream {
+            if *claimed.entry(components[module]).or_insert(output) != output {
+                return None;
+            }
+        }
+        upstream.sort_unstable();
+        sub_circuits.push(SubCircuit { output, modules: upstream, highs: vec![], seen: HashMap::new(), cycle: None });
+    }
+    Some((last, sub_circuits))
+}
+
+/// Find the fewest pushes of the button needed for `rx` to be sent a low pulse
+///
+/// When `rx` is fed by a conjunction whose inputs each come from an independent sub-circuit, each
+/// sub-circuit is simulated until its state repeats. Then the presses when its output is high form
+/// a pattern that repeats with its period, and the patterns of all of them line up for the first
+/// time at the answer. This assumes the outputs which are high during the same press are high at
+/// the same time.
+/// Otherwise (or if the sub-circuits don't settle into cycles) this falls back to pushing the
+/// button up to `limit` times.
+/// Returns None if `rx` never gets a low pulse, and panics if the answer is too large to work out
+pub fn get_fewest_pushes_to_rx_within((modules, broadcast, mapping): &Modules, limit: usize) -> Option<usize> {
+    let rx = *mapping.get("rx")?;
+    let (mut modules, broadcast) = (modules.clone(), *broadcast);
+    let (last, mut sub_circuits) = find_sub_circuits(&modules, broadcast, rx).unwrap_or((0, vec![]));
+    for sub_circuit in &mut sub_circuits {
+        sub_circuit.seen.insert(sub_circuit.state(&modules), 0);
+    }
+
+    for press in 1..=limit {
+        let mut rx_low = false;
+        push_button(&mut modules, broadcast, |Signal { source, pulse, destination }| {
+            if destination == rx && pulse == Pulse::Low {
+                rx_low = true;
+            }
+            if destination == last && pulse == Pulse::High {
+                if let Some(sub_circuit) = sub_circuits.iter_mut().find(|sub_circuit| sub_circuit.output == source) {
+                    // Once the cycle is known the highs in it have all been seen already
+                    if sub_circuit.cycle.is_none() && sub_circuit.highs.last() != Some(&press) {
+                        sub_circuit.highs.push(press);
+                    }
+                }
+            }
+        });
+        if rx_low {
+            return Some(press);
+        }
+
+        for sub_circuit in sub_circuits.iter_mut().filter(|sub_circuit| sub_circuit.cycle.is_none()) {
+            if let Some(start) = sub_circuit.seen.insert(sub_circuit.state(&modules), press) {
+                sub_circuit.cycle = Some(StateCycle { start, length: press - start });
+            }
+        }
+
+        if !sub_circuits.is_empty() && sub_circuits.iter().all(|sub_circuit| sub_circuit.cycle.is_some()) {
+            // Every press up to here has been checked, so pick one of the high presses in the cycle of
+            // each sub-circuit and find the first time after now that they all line up
+            return sub_circuits.iter()
+                .map(|sub_circuit| {
+                    let StateCycle { start, length } = sub_circuit.cycle.unwrap();
+                    sub_circuit.highs.iter()
+                        .filter(move |&&high| start < high && high <= start + length)
+                        .map(move |&high| Congruence::new(high as u64, length as u64))
+                })
+                .multi_cartesian_product()
+                .filter_map(|highs| match solve_congruences(highs) {
+                    Ok(solution) => Some(solution),
+                    Err(CongruenceError::NoSolution) => None,
+                    Err(error @ CongruenceError::Overflow) => panic!("The sub-circuits can't be lined up: {error}"),
+                })
+                .map(|solution| solution.smallest_from(press as u64 + 1)
+                    .and_then(|press| usize::try_from(press).ok())
+                    .expect("The sub-circuits line up after too many presses to count"))
+                .min();
+        }
+    }
+    None
+}
+
+/// Solve part2
+pub fn get_fewest_pushes_to_rx(modules: &Modules) -> usize {
+    // My original solution found the four counters in front of rx by hand, which meant it only
+    // worked on inputs with exactly the same names. Now they are found from the shape of the network
+    get_fewest_pushes_to_rx_within(modules, MAX_PRESSES).expect("rx never gets a low pulse")
+}
+
+#[test]
+fn test_part1a() {
+    assert_eq!(
+        32000000,
+        get_1000_pulse_product(
+            &preprocess(r"broadcaster -> a, b, c
+%a -> b
+%b -> c
+%c -> inv
+&inv -> a".lines()).unwrap()
+        )
+    );
+}
+
+#[test]
+fn test_part1b() {
+    assert_eq!(
+        11687500,
+        get_1000_pulse_product(
+            &preprocess(r"broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output".lines()).unwrap()
+        )
+    );
+}
+
+#[test]
+fn test_trace() {
+    let modules = preprocess(r"broadcaster -> a, b, c
+%a -> b
+%b -> c
+%c -> inv
+&inv -> a".lines()).unwrap();
+    let mut network = Network::new(&modules);
+    assert_eq!(
+        vec![
+            "button -low-> broadcaster",
+            "broadcaster -low-> a",
+            "broadcaster -low-> b",
+            "broadcaster -low-> c",
+            "a -high-> b",
+            "b -high-> c",
+            "c -high-> inv",
+            "inv -low-> a",
+            "a -low-> b",
+            "b -low-> c",
+            "c -low-> inv",
+            "inv -high-> a",
+        ],
+        network.press().iter().map(Event::to_string).collect::<Vec<_>>()
+    );
+    assert!(network.press().iter().all(|event| event.press == 2));
+}
+
+#[test]
+fn test_state_cycle() {
+    let modules = preprocess(r"broadcaster -> a, b, c
+%a -> b
+%b -> c
+%c -> inv
+&inv -> a".lines()).unwrap();
+    let mut network = Network::new(&modules);
+    let initial = network.state_hash();
+    // Everything is back to how it started after each push
+    assert_eq!(Some(StateCycle { start: 0, length: 1 }), network.find_state_cycle(10));
+    assert_eq!(initial, network.state_hash());
+
+    let modules = preprocess(r"broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output".lines()).unwrap();
+    let mut network = Network::new(&modules);
+    assert_eq!(Some(StateCycle { start: 0, length: 4 }), network.find_state_cycle(10));
+    assert_eq!(4, network.presses());
+    assert_eq!(None, Network::new(&modules).find_state_cycle(3));
+}
+
+#[test]
+fn test_to_dot() {
+    let modules = preprocess(r"broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output".lines()).unwrap();
+    let dot = Network::new(&modules).to_dot();
+    assert!(dot.starts_with("digraph modules {\n"));
+    assert!(dot.contains("    \"a\" [shape=box, style=filled, fillcolor=\"#ffd27f\", label=\"%\\N\"];\n"));
+    assert!(dot.contains("    \"con\" [shape=invhouse, style=filled, fillcolor=\"#9fc5f8\", label=\"&\\N\"];\n"));
+    assert!(dot.contains("    \"button\" -> \"broadcaster\";\n"));
+    assert!(dot.contains("    \"con\" -> \"output\";\n"));
+    assert_eq!(7, dot.matches(" -> ").count());
+}
+
+#[test]
+fn test_strongly_connected_components() {
+    let (modules, broadcast, mapping) = preprocess(r"broadcaster -> a, b, c
+%a -> b
+%b -> c
+%c -> inv
+&inv -> a".lines()).unwrap();
+    let components = strongly_connected_components(&modules);
+    let component = |name| components[mapping[name]];
+    assert!(["b", "c", "inv"].into_iter().all(|name| component(name) == component("a")));
+    assert_ne!(component("a"), components[broadcast]);
+    assert_ne!(components[0], components[broadcast]);
+}
+
+#[test]
+fn test_part2_simulated() {
+    // rx is fed by a flip-flop so there is no structure to find and it just gets simulated
+    let modules = preprocess("broadcaster -> a\n%a -> rx".lines()).unwrap();
+    assert_eq!(Some(2), get_fewest_pushes_to_rx_within(&modules, 10));
+    let modules = preprocess("broadcaster -> a\n%a -> b".lines()).unwrap();
+    assert_eq!(None, get_fewest_pushes_to_rx_within(&modules, 10));
+}
+
+#[test]
+fn test_part2_sub_circuits() {
+    // A 2 bit counter which is high on 3 mod 4 and a 3 bit counter which is high on 5 mod 8
+    let modules = preprocess(r"broadcaster -> a1, b1
+%a1 -> a2, ca
+%a2 -> ca
+&ca -> na
+&na -> final
+%b1 -> b2, cb
+%b2 -> b3, nb2
+%b3 -> cb
+&nb2 -> cb
+&cb -> nb
+&nb -> final
+&final -> rx".lines()).unwrap();
+    let (last, sub_circuits) = find_sub_circuits(&modules.0, modules.1, modules.2["rx"]).unwrap();
+    assert_eq!(modules.2["final"], last);
+    assert_eq!(vec![4, 6], sub_circuits.iter().map(|sub_circuit| sub_circuit.modules.len()).collect::<Vec<_>>());
+    // They are never high together
+    assert_eq!(None, get_fewest_pushes_to_rx_within(&modules, MAX_PRESSES));
+}
+
+#[test]
+fn test_part2_lined_up() {
+    // Counters which reset (and send a high pulse) every 3 and every 5 presses
+    let modules = preprocess(r"broadcaster -> a1, b1
+%a1 -> a2, ca
+%a2 -> ca
+&ca -> a1, na
+&na -> final
+%b1 -> b2, cb
+%b2 -> b3
+%b3 -> cb
+&cb -> b1, b2, nb
+&nb -> final
+&final -> rx".lines()).unwrap();
+    let mut network = Network::new(&modules);
+    let simulated = (1..=100).find(|_| network.press().iter().any(|event| event.destination == "rx" && event.pulse == Pulse::Low));
+    assert_eq!(Some(15), simulated);
+    // The cycles are found long before they line up, so this doesn't need to simulate that far
+    assert_eq!(Some(15), get_fewest_pushes_to_rx_within(&modules, 10));
+}
+
+#[test]
+fn test_parse_error() {
+    assert_eq!(
+        Some(ParseError::at(1, 0, "a", "`broadcaster` or a module name starting with `%` or `&`")),
+        preprocess("broadcaster -> a\na -> b".lines()).err()
+    );
+    assert_eq!(
+        Some(ParseError::at(1, 0, "%a", "` -> `")),
+        preprocess("broadcaster -> a\n%a".lines()).err()
+    );
+    assert_eq!(
+        Some(ParseError::at(1, 0, "", "a `broadcaster` module")),
+        preprocess("%a -> b".lines()).err()
+    );
+}
+
@@ -0,0 +1,805 @@
+/*
+You manage to catch the airship right as it's dropping someone else off on
+their all-expenses-paid trip to Desert Island! It even helpfully drops you
+off near the gardener and his massive farm.
+
+"You got the sand flowing again! Great work! Now we just need to wait until
+we have enough sand to filter the water for Snow Island and we'll have snow
+again in no time."
+
+While you wait, one of the Elves that works with the gardener heard how
+good you are at solving problems and would like your help. He needs to get
+his steps in for the day, and so he'd like to know which garden plots he
+can reach with exactly his remaining 64 steps.
+
+He gives you an up-to-date map (your puzzle input) of his starting position
+(S), garden plots (.), and rocks (#). For example:
+
+...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........
+
+The Elf starts at the starting position (S) which also counts as a garden
+plot. Then, he can take one step north, south, east, or west, but only onto
+tiles that are garden plots. This would allow him to reach any of the tiles
+marked O:
+
+...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#O#....
+.##.OS####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........
+
+Then, he takes a second step. Since at this point he could be at either
+tile marked O, his second step would allow him to reach any garden plot
+that is one step north, south, east, or west of any tile that he could have
+reached after the first step:
+
+...........
+.....###.#.
+.###.##..#.
+..#.#O..#..
+....#.#....
+.##O.O####.
+.##.O#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........
+
+After two steps, he could be at any of the tiles marked O above, including
+the starting position (either by going north-then-south or by going west-
+then-east).
+
+A single third step leads to even more possibilities:
+
+...........
+.....###.#.
+.###.##..#.
+..#.#.O.#..
+...O#O#....
+.##.OS####.
+.##O.#...#.
+....O..##..
+.##.#.####.
+.##..##.##.
+...........
+
+He will continue like this until his steps for the day have been exhausted.
+After a total of 6 steps, he could reach any of the garden plots marked O:
+
+...........
+.....###.#.
+.###.##.O#.
+.O#O#O.O#..
+O.O.#.#.O..
+.##O.O####.
+.##.O#O..#.
+.O.O.O.##..
+.##.#.####.
+.##O.##.##.
+...........
+
+In this example, if the Elf's goal was to get exactly 6 more steps today,
+he could use them to reach any of 16 garden plots.
+
+However, the Elf actually needs to get 64 steps today, and the map he's
+handed you is much larger than the example map.
+
+Starting from the garden plot marked S on your map, how many garden plots
+could the Elf reach in exactly 64 steps?
+
+--- Part Two ---
+
+The Elf seems confused by your answer until he realizes his mistake: he was
+reading from a list of his favorite numbers that are both perfect squares
+and perfect cubes, not his step counter.
+
+The actual number of steps he needs to get today is exactly 26501365.
+
+He also points out that the garden plots and rocks are set up so that the
+map repeats infinitely in every direction.
+
+So, if you were to look one additional map-width or map-height out from the
+edge of the example map above, you would find that it keeps repeating:
+
+.................................
+.....###.#......###.#......###.#.
+.###.##..#..###.##..#..###.##..#.
+..#.#...#....#.#...#....#.#...#..
+....#.#........#.#........#.#....
+.##...####..##...####..##...####.
+.##..#...#..##..#...#..##..#...#.
+.......##.........##.........##..
+.##.#.####..##.#.####..##.#.####.
+.##..##.##..##..##.##..##..##.##.
+.................................
+.................................
+.....###.#......###.#......###.#.
+.###.##..#..###.##..#..###.##..#.
+..#.#...#....#.#...#....#.#...#..
+....#.#........#.#........#.#....
+.##...####..##..S####..##...####.
+.##..#...#..##..#...#..##..#...#.
+.......##.........##.........##..
+.##.#.####..##.#.####..##.#.####.
+.##..##.##..##..##.##..##..##.##.
+.................................
+.................................
+.....###.#......###.#......###.#.
+.###.##..#..###.##..#..###.##..#.
+..#.#...#....#.#...#....#.#...#..
+....#.#........#.#........#.#....
+.##...####..##...####..##...####.
+.##..#...#..##..#...#..##..#...#.
+.......##.........##.........##..
+.##.#.####..##.#.####..##.#.####.
+.##..##.##..##..##.##..##..##.##.
+.................................
+
+This is just a tiny three-map-by-three-map slice of the inexplicably-
+infinite farm layout; garden plots and rocks repeat as far as you can see.
+The Elf still starts on the one middle tile marked S, though - every other
+repeated S is replaced with a normal garden plot (.).
+
+Here are the number of reachable garden plots in this new infinite version
+of the example map for different numbers of steps:
+
+- In exactly 6 steps, he can still reach 16 garden plots.
+- In exactly 10 steps, he can reach any of 50 garden plots.
+- In exactly 50 steps, he can reach 1594 garden plots.
+- In exactly 100 steps, he can reach 6536 garden plots.
+- In exactly 500 steps, he can reach 167004 garden plots.
+- In exactly 1000 steps, he can reach 668697 garden plots.
+- In exactly 5000 steps, he can reach 16733044 garden plots.
+
+However, the step count the Elf needs is much larger! Starting from the
+garden plot marked S on your infinite map, how many garden plots could the
+Elf reach in exactly 26501365 steps?
+ */
+
+use std::collections::{HashMap, VecDeque};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use crate::grid::{Grid, Position};
+use crate::parse::ParseError;
+use crate::Solution;
+
+/// Day 21: Step Counter
+pub struct StepCounter;
+
+impl Solution for StepCounter {
+    const DAY: u8 = 21;
+    const TITLE: &'static str = "Step Counter";
+    const INPUT: &'static str = "./inputs/21_step_counter.txt";
+
+    type Input<'a> = Map;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        preprocess(input.lines())
+    }
+
+    fn part1(map: &Self::Input<'_>) -> usize {
+        get_destination_count(map, 64)
+    }
+
+    fn part2(map: &Self::Input<'_>) -> usize {
+        get_wrapping_destination_count(map, 26501365)
+    }
+}
+
+/// Represents a tile on the map
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Tile {
+    Garden,
+    Rocks,
+}
+
+/// The map along with the starting position
+pub type Map = (Grid<Tile>, Position);
+
+/// Preprocess the input into a more useful form
+pub fn preprocess<'a>(map: impl Iterator<Item=&'a str>) -> Result<Map, ParseError> {
+    // Each tile is paired with whether it is the starting position
+    let tiles = Grid::parse_with(map, "`.`, `#`, or `S`", |c| match c {
+        '.' => Some((Tile::Garden, false)),
+        '#' => Some((Tile::Rocks, false)),
+        'S' => Some((Tile::Garden, true)),
+        _ => None
+    })?;
+
+    let mut position = None;
+    for (start, _) in tiles.iter().filter(|(_, (_, is_start))| *is_start) {
+        if position.is_some() {
+            return Err(ParseError::at(start.row, start.column, "S", "only one starting position"));
+        }
+        position = Some(start);
+    }
+    let position = position.ok_or_else(|| ParseError::at(0, 0, "", "a starting position `S`"))?;
+
+    Ok((tiles.map(|&(tile, _)| tile), position))
+}
+
+/// The distances from the start to every plot on the map
+/// This only has to be computed once to answer any number of questions about how many plots can
+/// be reached
+#[derive(Debug, Clone)]
+pub struct DistanceField {
+    /// The distance to each plot (None for rocks and plots which can't be reached)
+    distances: Grid<Option<usize>>,
+    /// The distances to every reachable plot in ascending order split by whether they are even or odd
+    sorted: [Vec<usize>; 2],
+}
+
+/// How many plots can be reached with a number of steps
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Reachable {
+    pub steps: usize,
+    /// The plots where the walk can end after exactly this many steps
+    pub exactly: usize,
+    /// The plots which can be visited with at most this many steps
+    pub at_most: usize,
+}
+
+impl DistanceField {
+    /// Find the distance to every plot from the start
+    pub fn new((map, start): &Map) -> Self {
+        let mut distances = Grid::filled(map.width(), map.height(), None);
+
+        // Breadth-first search so that each plot is first reached by a shortest path
+        distances[*start] = Some(0);
+        let mut queue = VecDeque::from([(*start, 0)]);
+        while let Some((position, distance)) = queue.pop_front() {
+            for (_, next) in map.neighbors(position) {
+                if distances[next].is_none() && map[next] == Tile::Garden {
+                    distances[next] = Some(distance + 1);
+                    queue.push_back((next, distance + 1));
+                }
+            }
+        }
+
+        let mut sorted = [vec![], vec![]];
+        for distance in distances.iter().filter_map(|(_, distance)| *distance) {
+            sorted[distance % 2].push(distance);
+        }
+        sorted.iter_mut().for_each(|distances| distances.sort_unstable());
+
+        DistanceField { distances, sorted }
+    }
+
+    /// Get the distance to a plot
+    /// Returns None for rocks, plots which can't be reached, and positions off the map
+    pub fn get(&self, position: Position) -> Option<usize> {
+        self.distances.get(position).copied().flatten()
+    }
+
+    /// Count the plots where a walk of exactly `steps` steps can end
+    /// The spare steps can be spent walking back and forth, so any plot which is close enough and
+    /// has the same parity can be reached
+    pub fn count_exactly(&self, steps: usize) -> usize {
+        let same_parity = &self.sorted[steps % 2];
+        same_parity.partition_point(|&distance| distance <= steps)
+    }
+
+    /// Count the plots which can be visited with at most `steps` steps
+    pub fn count_at_most(&self, steps: usize) -> usize {
+        self.sorted.iter()
+            .map(|distances| distances.partition_point(|&distance| distance <= steps))
+            .sum()
+    }
+
+    /// Count the reachable plots for each number of steps
+    pub fn reachable(&self, steps: &[usize]) -> Vec<Reachable> {
+        steps.iter()
+            .map(|&steps| Reachable {
+                steps,
+                exactly: self.count_exactly(steps),
+                at_most: self.count_at_most(steps),
+            })
+            .collect()
+    }
+}
+
+/// Solve part1
+pub fn get_destination_count(map: &Map, steps: usize) -> usize {
+    DistanceField::new(map).count_exactly(steps)
+}
+
+/// Why the distances on the infinitely repeating map couldn't be extrapolated
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum TilingError {
+    /// Even with this many copies of the map in each direction, the distances to the outermost
+    /// copies weren't just the distances to the copies inside of them plus the size of the map
+    ///
+    /// This always happens when rocks force the shortest paths to double back while crossing a
+    /// copy of the map. Then each copy further out costs more steps than the size of the map (and
+    /// the copies beyond the corners of the square don't add up from the lines going out from it),
+    /// so the distances never settle. For example the shortest path across each copy of
+    /// `.#..\n##S.\n.##.\n...#` is 6 steps instead of 4
+    Unsettled { radius: usize },
+}
+
+impl Display for TilingError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TilingError::Unsettled { radius } => write!(
+                f,
+                "the distances didn't settle into repeating within {radius} copies of the map in each direction",
+            ),
+        }
+    }
+}
+
+impl Error for TilingError {}
+
+/// The distances from the start to every plot on the infinitely repeating map
+///
+/// Only a square of copies of the map around the start is searched. Far enough away from the start
+/// the shortest paths all run through the copy next to the one they end in, so each copy further
+/// out in a direction is exactly the size of the map further away than the one before it. The
+/// square is grown until that holds at its edges
+#[derive(Debug, Clone)]
+pub struct TiledDistances {
+    width: usize,
+    height: usize,
+    /// The number of copies of the map searched in each direction from the one with the start
+    radius: usize,
+    /// The distances over the searched copies (None for rocks and plots which can't be reached)
+    distances: Grid<Option<usize>>,
+}
+
+impl TiledDistances {
+    /// The first radius to try
+    const INITIAL_RADIUS: usize = 2;
+    /// The largest radius to try before giving up
+    const MAX_RADIUS: usize = 8;
+
+    /// Find the distances to every plot on the infinitely repeating map
+    pub fn new((map, start): &Map) -> Result<Self, TilingError> {
+        let mut radius = Self::INITIAL_RADIUS;
+        loop {
+            let distances = Self::search(map, *start, radius);
+            if distances.is_settled() {
+                return Ok(distances);
+            }
+            if radius >= Self::MAX_RADIUS {
+                return Err(TilingError::Unsettled { radius });
+            }
+            radius *= 2;
+        }
+    }
+
+    /// Search the square of copies with a given radius
+    fn search(map: &Grid<Tile>, start: Position, radius: usize) -> Self {
+        let copies = 2 * radius + 1;
+        let (width, height) = (map.width(), map.height());
+        let mut distances = Grid::filled(width * copies, height * copies, None);
+
+        // Breadth-first search so that each plot is first reached by a shortest path
+        let start = Position::new(start.row + radius * height, start.column + radius * width);
+        distances[start] = Some(0);
+        let mut queue = VecDeque::from([(start, 0)]);
+        while let Some((position, distance)) = queue.pop_front() {
+            let neighbors = distances.neighbors(position).collect::<Vec<_>>();
+            for (_, next) in neighbors {
+                if distances[next].is_none() && map[Position::new(next.row % height, next.column % width)] == Tile::Garden {
+                    distances[next] = Some(distance + 1);
+                    queue.push_back((next, distance + 1));
+                }
+            }
+        }
+
+        TiledDistances { width, height, radius, distances }
+    }
+
+    /// Get the distance to a plot in one of the searched copies of the map
+    /// The copy is given as the number of copies down and to the right of the one with the start
+    fn get(&self, position: Position, (rows, columns): (isize, isize)) -> Option<usize> {
+        let row = (self.radius as isize + rows) as usize * self.height + position.row;
+        let column = (self.radius as isize + columns) as usize * self.width + position.column;
+        self.distances[Position::new(row, column)]
+    }
+
+    /// The copies on the edge of the searched square along with the copy inside of each of them
+    /// and the size of the map in the direction between them
+    fn edges(&self) -> impl Iterator<Item=((isize, isize), (isize, isize), usize)> + '_ {
+        let radius = self.radius as isize;
+        (-radius..=radius).flat_map(move |i| [
+            ((-radius, i), (1 - radius, i), self.height),
+            ((radius, i), (radius - 1, i), self.height),
+            ((i, -radius), (i, 1 - radius), self.width),
+            ((i, radius), (i, radius - 1), self.width),
+        ])
+    }
+
+    /// Check that every copy on the edge of the square is the size of the map further away than
+    /// the copy inside of it
+    fn is_settled(&self) -> bool {
+        (0..self.height)
+            .flat_map(|row| (0..self.width).map(move |column| Position::new(row, column)))
+            .all(|position| self.edges().all(|(edge, inside, size)| {
+                match (self.get(position, edge), self.get(position, inside)) {
+                    (Some(edge), Some(inside)) => edge == inside + size,
+                    (None, None) => true,
+                    _ => false,
+                }
+            }))
+    }
+
+    /// Count the plots which can be reached in exactly a number of steps
+    /// A plot can be reached if it is close enough and the spare steps can be spent walking back
+    /// and forth
+    pub fn count_exactly(&self, steps: usize) -> usize {
+        let radius = self.radius as isize;
+        let reachable = |distance: usize| distance <= steps && (steps - distance).is_multiple_of(2);
+
+        // Many plots are the same distance from the start so group them to avoid repeating work
+        let mut inside = 0;
+        let mut lines = HashMap::<(usize, usize), usize>::new();
+        let mut corners = HashMap::<usize, usize>::new();
+        for row in 0..self.height {
+            for column in 0..self.width {
+                let position = Position::new(row, column);
+                for rows in -radius..=radius {
+                    for columns in -radius..=radius {
+                        inside += self.get(position, (rows, columns)).filter(|&distance| reachable(distance)).map_or(0, |_| 1);
+                    }
+                }
+                // Copies beyond an edge (but not beyond a corner) are in a line going straight out
+                for (edge, _, size) in self.edges() {
+                    let is_corner = edge.0.abs() == radius && edge.1.abs() == radius;
+                    if let Some(distance) = self.get(position, edge).filter(|_| !is_corner) {
+                        *lines.entry((distance, size)).or_default() += 1;
+                    }
+                }
+                // Copies beyond a corner of the square are in a line going straight out from it in
+                // both directions or spread out between those lines
+                for corner in [(-radius, -radius), (-radius, radius), (radius, -radius), (radius, radius)] {
+                    if let Some(distance) = self.get(position, corner) {
+                        *lines.entry((distance, self.height)).or_default() += 1;
+                        *lines.entry((distance, self.width)).or_default() += 1;
+                        *corners.entry(distance).or_default() += 1;
+                    }
+                }
+            }
+        }
+
+        let lines = lines.into_iter()
+            .map(|((distance, size), count)| count * count_line(distance, size, steps))
+            .sum::<usize>();
+        let corners = corners.into_iter()
+            .map(|(distance, count)| {
+                // Each copy going down from the corner starts its own line going across
+                let area = (1..)
+                    .map(|copies| distance + copies * self.height)
+                    .take_while(|&distance| distance + self.width <= steps)
+                    .map(|distance| count_line(distance, self.width, steps))
+                    .sum::<usize>();
+                count * area
+            })
+            .sum::<usize>();
+        inside + lines + corners
+    }
+}
+
+/// Count the copies of a plot in a line going out from a copy at `distance` where each copy is
+/// `size` further away which can be reached in exactly `steps` steps (not counting the first one)
+fn count_line(distance: usize, size: usize, steps: usize) -> usize {
+    if distance + size > steps {
+        return 0;
+    }
+    let copies = (steps - distance) / size;
+    let spare = steps - distance;
+    match (size.is_multiple_of(2), spare.is_multiple_of(2)) {
+        // Every copy is the same parity
+        (true, true) => copies,
+        (true, false) => 0,
+        // Every other copy is the same parity as the first one
+        (false, true) => copies / 2,
+        (false, false) => copies.div_ceil(2),
+    }
+}
+
+/// Solve part2
+pub fn get_wrapping_destination_count(map: &Map, steps: usize) -> usize {
+    // My original solution relied on the map being a square with an odd size, the start being in
+    // the center, and the row and column of the start and the border being completely clear
+    // (which is true of the real input but not the example). This works for any map
+    match TiledDistances::new(map) {
+        Ok(distances) => distances.count_exactly(steps),
+        Err(error) => panic!("Unable to count the plots on the infinite map: {error}"),
+    }
+}
+
+#[test]
+fn test_part1() {
+    assert_eq!(
+        16,
+        get_destination_count(
+            &preprocess(r"...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........".lines()).unwrap(),
+            6,
+        )
+    );
+}
+
+#[test]
+fn test_parse_error() {
+    assert_eq!(
+        Some(ParseError::at(1, 2, "S", "only one starting position")),
+        preprocess("S..\n.#S".lines()).err()
+    );
+    assert_eq!(
+        Some(ParseError::at(0, 0, "", "a starting position `S`")),
+        preprocess("...\n.#.".lines()).err()
+    );
+}
+
+#[test]
+fn test_part2a() {
+    assert_eq!(
+        16,
+        get_wrapping_destination_count(
+            &preprocess(r"...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........".lines()).unwrap(),
+            6
+        )
+    );
+}
+
+#[test]
+fn test_part2b() {
+    assert_eq!(
+        50,
+        get_wrapping_destination_count(
+            &preprocess(r"...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........".lines()).unwrap(),
+            10
+        )
+    );
+}
+
+#[test]
+fn test_part2c() {
+    assert_eq!(
+        1594,
+        get_wrapping_destination_count(
+            &preprocess(r"...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........".lines()).unwrap(),
+            50
+        )
+    );
+}
+
+#[test]
+fn test_part2d() {
+    assert_eq!(
+        6536,
+        get_wrapping_destination_count(
+            &preprocess(r"...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........".lines()).unwrap(),
+            100
+        )
+    );
+}
+
+#[test]
+fn test_part2e() {
+    assert_eq!(
+        167004,
+        get_wrapping_destination_count(
+            &preprocess(r"...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........".lines()).unwrap(),
+            500
+        )
+    );
+}
+
+#[test]
+fn test_part2f() {
+    assert_eq!(
+        668697,
+        get_wrapping_destination_count(
+            &preprocess(r"...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........".lines()).unwrap(),
+            1000
+        )
+    );
+}
+
+
+#[test]
+fn test_part2g() {
+    assert_eq!(
+        16733044,
+        get_wrapping_destination_count(
+            &preprocess(r"...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........".lines()).unwrap(),
+            5000
+        )
+    );
+}
+
+#[test]
+fn test_distance_field() {
+    let map = preprocess(r"...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........".lines()).unwrap();
+    let distances = DistanceField::new(&map);
+    assert_eq!(Some(0), distances.get(map.1));
+    assert_eq!(None, distances.get(Position::new(1, 5)));
+    assert_eq!(
+        vec![
+            Reachable { steps: 0, exactly: 1, at_most: 1 },
+            Reachable { steps: 1, exactly: 2, at_most: 3 },
+            Reachable { steps: 2, exactly: 4, at_most: 6 },
+            Reachable { steps: 6, exactly: 16, at_most: 16 + distances.count_exactly(5) },
+        ],
+        distances.reachable(&[0, 1, 2, 6])
+    );
+    // Every plot on the map can be reached eventually
+    assert_eq!(81, distances.count_at_most(1000));
+}
+
+#[test]
+fn test_count_line() {
+    // Copies at 7, 10, 13, 16, and 19 where only every other one has the right parity
+    assert_eq!(3, count_line(4, 3, 19));
+    assert_eq!(2, count_line(4, 3, 20));
+    // With an even size either all of them or none of them are reachable
+    assert_eq!(4, count_line(4, 4, 20));
+    assert_eq!(0, count_line(4, 4, 21));
+    assert_eq!(0, count_line(4, 4, 7));
+}
+
+#[test]
+fn test_enclosed_start() {
+    // The start can't leave its copy of the map so it is the same as the finite map
+    let map = preprocess("#####\n#...#\n#.S.#\n#...#\n#####".lines()).unwrap();
+    let distances = TiledDistances::new(&map).unwrap();
+    assert_eq!(get_destination_count(&map, 4), distances.count_exactly(4));
+    assert_eq!(4, distances.count_exactly(1001));
+}
+
+/// Count the plots on the infinite map which can be reached in exactly `steps` steps by taking
+/// every step one at a time (only fast enough for a small number of steps)
+#[cfg(test)]
+fn count_by_walking((map, start): &Map, steps: usize) -> usize {
+    use std::collections::HashSet;
+
+    let (width, height) = (map.width() as isize, map.height() as isize);
+    let is_garden = |(row, column): (isize, isize)| {
+        map[Position::new(row.rem_euclid(height) as usize, column.rem_euclid(width) as usize)] == Tile::Garden
+    };
+    let mut plots = HashSet::from([(start.row as isize, start.column as isize)]);
+    for _ in 0..steps {
+        plots = plots.into_iter()
+            .flat_map(|(row, column)| [(row - 1, column), (row + 1, column), (row, column - 1), (row, column + 1)])
+            .filter(|&plot| is_garden(plot))
+            .collect();
+    }
+    plots.len()
+}
+
+#[test]
+fn test_part2_non_square() {
+    let map = preprocess(".S....\n..#...\n....#.".lines()).unwrap();
+    for steps in [0, 1, 2, 5, 10, 25, 40] {
+        assert_eq!(count_by_walking(&map, steps), get_wrapping_destination_count(&map, steps));
+    }
+}
+
+#[test]
+fn test_part2_off_center() {
+    let map = preprocess("...#\n.S..\n#...\n..#.".lines()).unwrap();
+    for steps in [0, 1, 2, 5, 10, 25, 40] {
+        assert_eq!(count_by_walking(&map, steps), get_wrapping_destination_count(&map, steps));
+    }
+}
+
+#[test]
+fn test_unsettled() {
+    // Every copy of this map takes 6 steps to cross instead of 4
+    let map = preprocess(".#..\n##S.\n.##.\n...#".lines()).unwrap();
+    assert_eq!(
+        Some(TilingError::Unsettled { radius: TiledDistances::MAX_RADIUS }),
+        TiledDistances::new(&map).err()
+    );
+}
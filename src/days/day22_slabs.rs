@@ -0,0 +1,648 @@
+/*
+Enough sand has fallen; it can finally filter water for Snow Island.
+
+Well, almost.
+
+The sand has been falling as large compacted bricks of sand, piling up to
+form an impressive stack here near the edge of Island Island. In order to
+make use of the sand to filter water, some of the bricks will need to be
+broken apart - nay, disintegrated - back into freely flowing sand.
+
+The stack is tall enough that you'll have to be careful about choosing
+which bricks to disintegrate; if you disintegrate the wrong brick, large
+portions of the stack could topple, which sounds pretty dangerous.
+
+The Elves responsible for water filtering operations took a snapshot of the
+bricks while they were still falling (your puzzle input) which should let
+you work out which bricks are safe to disintegrate. For example:
+
+1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9
+
+Each line of text in the snapshot represents the position of a single brick
+at the time the snapshot was taken. The position is given as two x,y,z
+coordinates - one for each end of the brick - separated by a tilde (~).
+Each brick is made up of a single straight line of cubes, and the Elves
+were even careful to choose a time for the snapshot that had all of the
+free-falling bricks at integer positions above the ground, so the whole
+snapshot is aligned to a three-dimensional cube grid.
+
+A line like 2,2,2~2,2,2 means that both ends of the brick are at the same
+coordinate - in other words, that the brick is a single cube.
+
+Lines like 0,0,10~1,0,10 or 0,0,10~0,1,10 both represent bricks that are
+two cubes in volume, both oriented horizontally. The first brick extends in
+the x direction, while the second brick extends in the y direction.
+
+A line like 0,0,1~0,0,10 represents a ten-cube brick which is oriented
+vertically. One end of the brick is the cube located at 0,0,1, while the
+other end of the brick is located directly above it at 0,0,10.
+
+The ground is at z=0 and is perfectly flat; the lowest z value a brick can
+have is therefore 1. So, 5,5,1~5,6,1 and 0,2,1~0,2,5 are both resting on
+the ground, but 3,3,2~3,3,3 was above the ground at the time of the
+snapshot.
+
+Because the snapshot was taken while the bricks were still falling, some
+bricks will still be in the air; you'll need to start by figuring out where
+they will end up. Bricks are magically stabilized, so they never rotate,
+even in weird situations like where a long horizontal brick is only
+supported on one end. Two bricks cannot occupy the same position, so a
+falling brick will come to rest upon the first other brick it encounters.
+
+Here is the same example again, this time with each brick given a letter so
+it can be marked in diagrams:
+
+1,0,1~1,2,1   <- A
+0,0,2~2,0,2   <- B
+0,2,3~2,2,3   <- C
+0,0,4~0,2,4   <- D
+2,0,5~2,2,5   <- E
+0,1,6~2,1,6   <- F
+1,1,8~1,1,9   <- G
+
+At the time of the snapshot, from the side so the x axis goes left to
+right, these bricks are arranged like this:
+
+ x
+012
+.G. 9
+.G. 8
+... 7
+FFF 6
+..E 5 z
+D.. 4
+CCC 3
+BBB 2
+.A. 1
+--- 0
+
+Rotating the perspective 90 degrees so the y axis now goes left to right,
+the same bricks are arranged like this:
+
+ y
+012
+.G. 9
+.G. 8
+... 7
+.F. 6
+EEE 5 z
+DDD 4
+..C 3
+B.. 2
+AAA 1
+--- 0
+
+Once all of the bricks fall downward as far as they can go, the stack looks
+like this, where ? means bricks are hidden behind other bricks at that
+location:
+
+ x
+012
+.G. 6
+.G. 5
+FFF 4
+D.E 3 z
+??? 2
+.A. 1
+--- 0
+
+Again from the side:
+
+ y
+012
+.G. 6
+.G. 5
+.F. 4
+??? 3 z
+B.C 2
+AAA 1
+--- 0
+Now that all of the bricks have settled, it becomes easier to tell which
+bricks are supporting which other bricks:
+
+- Brick A is the only brick supporting bricks B and C.
+- Brick B is one of two bricks supporting brick D and brick E.
+- Brick C is the other brick supporting brick D and brick E.
+- Brick D supports brick F.
+- Brick E also supports brick F.
+- Brick F supports brick G.
+- Brick G isn't supporting any bricks.
+
+Your first task is to figure out which bricks are safe to disintegrate. A
+brick can be safely disintegrated if, after removing it, no other bricks
+would fall further directly downward. Don't actually disintegrate any
+bricks - just determine what would happen if, for each brick, only that
+brick were disintegrated. Bricks can be disintegrated even if they're
+completely surrounded by other bricks; you can squeeze between bricks if
+you need to.
+
+In this example, the bricks can be disintegrated as follows:
+
+- Brick A cannot be disintegrated safely; if it were disintegrated,
+bricks B and C would both fall.
+- Brick B can be disintegrated; the bricks above it (D and E) would
+still be supported by brick C.
+- Brick C can be disintegrated; the bricks above it (D and E) would
+still be supported by brick B.
+- Brick D can be disintegrated; the brick above it (F) would still be
+supported by brick E.
+- Brick E can be disintegrated; the brick above it (F) would still be
+supported by brick D.
+- Brick F cannot be disintegrated; the brick above it (G) would fall.
+- Brick G can be disintegrated; it does not support any other bricks.
+
+So, in this example, 5 bricks can be safely disintegrated.
+
+Figure how the blocks will settle based on the snapshot. Once they've
+settled, consider disintegrating a single brick; how many bricks could be
+safely chosen as the one to get disintegrated?
+
+--- Part Two ---
+
+Disintegrating bricks one at a time isn't going to be fast enough. While it
+might sound dangerous, what you really need is a chain reaction.
+
+You'll need to figure out the best brick to disintegrate. For each brick,
+determine how many other bricks would fall if that brick were
+disintegrated.
+
+Using the same example as above:
+
+- Disintegrating brick A would cause all 6 other bricks to fall.
+- Disintegrating brick F would cause only 1 other brick, G, to fall.
+
+Disintegrating any other brick would cause no other bricks to fall. So, in
+this example, the sum of the number of other bricks that would fall as a
+result of disintegrating each brick is 7.
+
+For each brick, determine how many other bricks would fall if that brick
+were disintegrated. What is the sum of the number of other bricks that
+would fall?
+ */
+
+use std::cmp::{max, min, Reverse};
+use std::collections::{BTreeMap, BinaryHeap, HashMap};
+
+use itertools::Itertools;
+
+use crate::parse::{self, Line, ParseError};
+use crate::Solution;
+
+/// Day 22: Sand Slabs
+pub struct SandSlabs;
+
+impl Solution for SandSlabs {
+    const DAY: u8 = 22;
+    const TITLE: &'static str = "Sand Slabs";
+    const INPUT: &'static str = "./inputs/22_slabs.txt";
+
+    type Input<'a> = Vec<Block>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        preprocess(input.lines())
+    }
+
+    fn part1(snapshot: &Self::Input<'_>) -> usize {
+        get_disintegration_count(snapshot)
+    }
+
+    fn part2(snapshot: &Self::Input<'_>) -> usize {
+        get_chain_reaction_count(snapshot)
+    }
+}
+
+/// Represents a position in 3D space
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Position {
+    x: i32,
+    y: i32,
+    z: i32,
+}
+
+impl Position {
+    /// Parse a position from its comma separated coordinates which are a slice of the line
+    fn from_coordinates(line: Line, value: &str) -> Result<Self, ParseError> {
+        let coordinates = value.split(',')
+            .map(|coordinate| line.parse(coordinate, "a coordinate"))
+            .collect::<Result<Vec<i32>, _>>()?;
+        let [x, y, z] = coordinates[..] else {
+            return Err(line.error(value, "three comma separated coordinates"));
+        };
+        Ok(Self { x, y, z })
+    }
+}
+
+/// Represents a column of 3D space
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+struct Column {
+    x: i32,
+    y: i32,
+}
+
+impl From<&Position> for Column {
+    fn from(value: &Position) -> Self {
+        Self { x: value.x, y: value.y }
+    }
+}
+
+/// Represents a block
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Block {
+    ends: [Position; 2],
+    id: usize, // A unique identifier
+}
+
+impl Block {
+    /// Get the unique identifier of this block
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    /// Iterate on the columns which this block occupies
+    fn iter_columns(&self) -> impl Iterator<Item=Column> {
+        let col1: Column = (&self.ends[0]).into();
+        let col2: Column = (&self.ends[1]).into();
+
+        (min(col1.x, col2.x)..=max(col1.x, col2.x))
+            .cartesian_product(min(col1.y, col2.y)..=max(col1.y, col2.y))
+            .map(|(x, y)| Column { x, y })
+    }
+
+    /// Get the lowest Z coordinate of this block
+    /// Due to the constraint that this block is a 1x1xN line of blocks in any of the 3 axes,
+    /// this lowest coordinate is also the lowest Z coordinate of ALL columns this block falls into
+    pub fn lowest_z(&self) -> i32 {
+        min(self.ends[0].z, self.ends[1].z)
+    }
+
+    /// Get the highest Z coordinate of this block
+    /// Due to the constraint that this block is a 1x1xN line of blocks in any of the 3 axes,
+    /// this lowest coordinate is also the highest Z coordinate of ALL columns this block falls into
+    pub fn highest_z(&self) -> i32 {
+        max(self.ends[0].z, self.ends[1].z)
+    }
+
+    /// Move this block down
+    fn fall(&mut self, distance: i32) {
+        self.ends.iter_mut().for_each(|end| end.z -= distance);
+    }
+}
+
+/// Preprocess the input into a more useful form
+pub fn preprocess<'a>(snapshot: impl Iterator<Item=&'a str>) -> Result<Vec<Block>, ParseError> {
+    parse::lines(snapshot).map(|line| {
+        let (start, end) = line.split_once(line.text, "~")?;
+        let ends = [Position::from_coordinates(line, start)?, Position::from_coordinates(line, end)?];
+        // Everything else relies on blocks only extending along a single axis
+        let [first, second] = &ends;
+        let axes = [first.x != second.x, first.y != second.y, first.z != second.z];
+        if axes.into_iter().filter(|&differs| differs).count() > 1 {
+            return Err(line.error(line.text, "a block which only extends along one axis"));
+        }
+        Ok(Block {
+            ends,
+            id: line.index,
+        })
+    }).collect()
+}
+
+/// A block which moved when the stack settled again
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Moved {
+    pub id: usize,
+    /// How far the block fell
+    pub distance: i32,
+}
+
+/// A stack of blocks which have all settled as far down as they can fall
+#[derive(Debug, Clone, Default)]
+pub struct SlabStack {
+    /// The blocks in their settled positions indexed by their id (None once a block is removed)
+    blocks: Vec<Option<Block>>,
+    /// The blocks in each column indexed by the lowest z coordinate which they occupy in the column
+    columns: HashMap<Column, BTreeMap<i32, usize>>,
+}
+
+impl SlabStack {
+    /// Let all the blocks of a snapshot fall until they have settled
+    /// The blocks keep the ids which they had in the snapshot
+    pub fn new(snapshot: &[Block]) -> Self {
+        let mut stack = SlabStack {
+            blocks: vec![None; snapshot.iter().map(|block| block.id + 1).max().unwrap_or(0)],
+            columns: HashMap::new(),
+        };
+
+        // The lower blocks will land first and so if we resolve landing in ascending height order,
+        // every block lands on blocks which have already settled
+        let mut blocks = snapshot.to_vec();
+        blocks.sort_by_key(|block| block.lowest_z());
+        for mut block in blocks {
+            block.fall(stack.fall_distance(&block));
+            stack.insert(block);
+        }
+        stack
+    }
+
+    /// Get a block which is in the stack
+    pub fn get(&self, id: usize) -> Option<&Block> {
+        self.blocks.get(id)?.as_ref()
+    }
+
+    /// Iterate on the blocks which are in the stack
+    pub fn iter(&self) -> impl Iterator<Item=&Block> {
+        self.blocks.iter().flatten()
+    }
+
+    /// Get the number of blocks in the stack
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    /// Check if there are no blocks in the stack
+    pub fn is_empty(&self) -> bool {
+        self.iter().next().is_none()
+    }
+
+    /// Get the ids of the blocks which a block is resting on (in ascending order)
+    /// A block resting on the ground isn't supported by any blocks
+    pub fn supported_by(&self, id: usize) -> Vec<usize> {
+        let Some(block) = self.get(id) else { return vec![]; };
+        let mut supports = block.iter_columns()
+            .filter_map(|column| self.below(&column, block.lowest_z()))
+            .filter(|below| below.highest_z() + 1 == block.lowest_z())
+            .map(|below| below.id)
+            .collect::<Vec<_>>();
+        supports.sort_unstable();
+        supports.dedup();
+        supports
+    }
+
+    /// Get the ids of the blocks which are resting on a block (in ascending order)
+    pub fn directly_supports(&self, id: usize) -> Vec<usize> {
+        let Some(block) = self.get(id) else { return vec![]; };
+        let mut supported = block.iter_columns()
+            .filter_map(|column| self.columns.get(&column)?.get(&(block.highest_z() + 1)).copied())
+            .collect::<Vec<_>>();
+        supported.sort_unstable();
+        supported.dedup();
+        supported
+    }
+
+    /// Drop a new block into the stack from where it currently is
+    /// Returns the id given to the block, or None if it overlaps a block which is already in the stack
+    pub fn add(&mut self, block: &Block) -> Option<usize> {
+        let overlaps = block.iter_columns()
+            .filter_map(|column| self.below(&column, block.highest_z() + 1))
+            .any(|below| below.highest_z() >= block.lowest_z());
+        if overlaps {
+            return None;
+        }
+
+        let mut block = Block { ends: block.ends.clone(), id: self.blocks.len() };
+        self.blocks.push(None);
+        block.fall(self.fall_distance(&block));
+        let id = block.id;
+        self.insert(block);
+        Some(id)
+    }
+
+    /// Remove a block and let everything which was resting on it fall until the stack settles again
+    /// Returns the blocks which moved in the order they landed, or None if there is no such block
+    pub fn remove(&mut self, id: usize) -> Option<Vec<Moved>> {
+        let removed = self.take(id)?;
+
+        // Only blocks above a block which moved can fall, and they can only land on blocks which
+        // are lower than them, so going through them from the bottom up settles each of them once
+        let mut queue = BinaryHeap::<Reverse<(i32, usize)>>::new();
+        let mut moved = vec![];
+        self.push_above(&mut queue, &removed);
+        while let Some(Reverse((_, id))) = queue.pop() {
+            let Some(block) = self.get(id) else { continue; };
+            let distance = self.fall_distance(block);
+            if distance == 0 {
+                continue;
+            }
+            let mut block = self.take(id)?;
+            self.push_above(&mut queue, &block);
+            block.fall(distance);
+            self.insert(block);
+            moved.push(Moved { id, distance });
+        }
+        Some(moved)
+    }
+
+    /// Count how many other blocks would fall if each block were disintegrated
+    ///
+    /// The support graph is a DAG rooted at the ground, and a block falls when another is removed
+    /// exactly when every path from the ground to it goes through the removed block (i.e. the
+    /// removed block dominates it). So the count for a block is the size of its subtree in the
+    /// dominator tree, which can be found for every block at once.
+    pub fn chain_reaction_counts(&self) -> HashMap<usize, usize> {
+        // Blocks are always supported by lower blocks, so going from the bottom up visits every
+        // block after all of its supports
+        let mut order = self.iter().map(Block::id).collect::<Vec<_>>();
+        order.sort_by_key(|&id| self.blocks[id].as_ref().map(Block::lowest_z));
+
+        // The immediate dominator of each block (None for the ground) and its depth in the tree
+        let mut dominators = HashMap::<usize, (Option<usize>, usize)>::new();
+        for &id in &order {
+            let dominator = self.supported_by(id).into_iter()
+                .map(Some)
+                .reduce(|first, second| common_dominator(&dominators, first, second))
+                .flatten();
+            let depth = dominator.map_or(0, |dominator| dominators[&dominator].1) + 1;
+            dominators.insert(id, (dominator, depth));
+        }
+
+        // Add the size of each subtree to its parent, from the top down so that each is complete
+        let mut counts = order.iter().map(|&id| (id, 0)).collect::<HashMap<_, _>>();
+        for &id in order.iter().rev() {
+            if let Some(dominator) = dominators[&id].0 {
+                let falling = counts[&id] + 1;
+                *counts.entry(dominator).or_default() += falling;
+            }
+        }
+        counts
+    }
+
+    /// Get the highest block in a column which is below a height
+    fn below(&self, column: &Column, z: i32) -> Option<&Block> {
+        let (_, &id) = self.columns.get(column)?.range(..z).next_back()?;
+        self.get(id)
+    }
+
+    /// Determine how far a block can fall before it lands on the ground or another block
+    fn fall_distance(&self, block: &Block) -> i32 {
+        block.iter_columns()
+            .map(|column| self.below(&column, block.lowest_z()).map_or(0, Block::highest_z))
+            .map(|floor| block.lowest_z() - floor - 1)
+            .min()
+            .unwrap_or(0)
+    }
+
+    /// Queue up the blocks which are resting on a block
+    fn push_above(&self, queue: &mut BinaryHeap<Reverse<(i32, usize)>>, block: &Block) {
+        for column in block.iter_columns() {
+            if let Some(&above) = self.columns.get(&column).and_then(|blocks| blocks.get(&(block.highest_z() + 1))) {
+                queue.push(Reverse((block.highest_z() + 1, above)));
+            }
+        }
+    }
+
+    /// Put a block into the columns it occupies
+    fn insert(&mut self, block: Block) {
+        for column in block.iter_columns() {
+            self.columns.entry(column).or_default().insert(block.lowest_z(), block.id);
+        }
+        let id = block.id;
+        self.blocks[id] = Some(block);
+    }
+
+    /// Take a block out of the columns it occupies
+    fn take(&mut self, id: usize) -> Option<Block> {
+        let block = self.blocks.get_mut(id)?.take()?;
+        for column in block.iter_columns() {
+            if let Some(blocks) = self.columns.get_mut(&column) {
+                blocks.remove(&block.lowest_z());
+            }
+        }
+        Some(block)
+    }
+}
+
+/// Find the closest block (None for the ground) which dominates both of two blocks in the
+/// dominator tree built so far
+fn common_dominator(dominators: &HashMap<usize, (Option<usize>, usize)>, mut first: Option<usize>, mut second: Option<usize>) -> Option<usize> {
+    let depth = |block: Option<usize>| block.map_or(0, |block| dominators[&block].1);
+    let parent = |block: Option<usize>| block.and_then(|block| dominators[&block].0);
+    while first != second {
+        // Walk up from the deeper of the two until they meet
+        if depth(first) >= depth(second) {
+            first = parent(first);
+        } else {
+            second = parent(second);
+        }
+    }
+    first
+}
+
+/// Solve part1
+pub fn get_disintegration_count(snapshot: &[Block]) -> usize {
+    let stack = SlabStack::new(snapshot);
+    // A block can be disintegrated if it isn't the only support of any block
+    stack.iter()
+        .filter(|block| stack.directly_supports(block.id).into_iter()
+            .all(|above| stack.supported_by(above).len() > 1))
+        .count()
+}
+
+/// Solve part2
+pub fn get_chain_reaction_count(snapshot: &[Block]) -> usize {
+    SlabStack::new(snapshot).chain_reaction_counts().values().sum()
+}
+
+#[test]
+fn test_part1() {
+    assert_eq!(
+        5,
+        get_disintegration_count(
+            &preprocess(r"1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9".lines()).unwrap()
+        )
+    );
+}
+
+#[test]
+fn test_part2() {
+    assert_eq!(
+        7,
+        get_chain_reaction_count(
+            &preprocess(r"1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9".lines()).unwrap()
+        )
+    );
+}
+
+#[test]
+fn test_slab_stack() {
+    let snapshot = preprocess(r"1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9".lines()).unwrap();
+    let mut stack = SlabStack::new(&snapshot);
+    assert_eq!(7, stack.len());
+    assert_eq!(Vec::<usize>::new(), stack.supported_by(0));
+    assert_eq!(vec![1, 2], stack.directly_supports(0));
+    assert_eq!(vec![1, 2], stack.supported_by(3));
+    assert_eq!(vec![6], stack.directly_supports(5));
+    assert_eq!((5, 6), (stack.get(6).unwrap().lowest_z(), stack.get(6).unwrap().highest_z()));
+
+    // A new block lands on top of G, but it can't be added where a block already is
+    let falling = preprocess("1,1,20~1,1,20".lines()).unwrap();
+    assert_eq!(Some(7), stack.add(&falling[0]));
+    assert_eq!(vec![6], stack.supported_by(7));
+    let overlapping = preprocess("1,1,1~1,1,1".lines()).unwrap();
+    assert_eq!(None, stack.add(&overlapping[0]));
+
+    // B isn't needed but removing A drops everything else
+    assert_eq!(Some(vec![]), stack.remove(1));
+    assert_eq!(
+        Some((2..=7).map(|id| Moved { id, distance: 1 }).collect()),
+        stack.remove(0)
+    );
+    assert_eq!(None, stack.remove(0));
+    assert_eq!(6, stack.len());
+    assert_eq!(Vec::<usize>::new(), stack.supported_by(2));
+    assert_eq!(vec![2], stack.supported_by(3));
+}
+
+#[test]
+fn test_chain_reaction_counts() {
+    let snapshot = preprocess(r"1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9".lines()).unwrap();
+    let stack = SlabStack::new(&snapshot);
+    let counts = stack.chain_reaction_counts();
+    assert_eq!(HashMap::from([(0, 6), (1, 0), (2, 0), (3, 0), (4, 0), (5, 1), (6, 0)]), counts);
+    // The same blocks fall when the stack is actually knocked over
+    for (id, count) in counts {
+        assert_eq!(Some(count), stack.clone().remove(id).map(|moved| moved.len()));
+    }
+}
+
+#[test]
+fn test_parse_error() {
+    assert_eq!(
+        Some(ParseError::at(1, 6, "1,2", "three comma separated coordinates")),
+        preprocess("1,0,1~1,2,1\n0,0,2~1,2".lines()).err()
+    );
+    assert_eq!(
+        Some(ParseError::at(0, 0, "0,0,2~2,2,2", "a block which only extends along one axis")),
+        preprocess("0,0,2~2,2,2".lines()).err()
+    );
+}
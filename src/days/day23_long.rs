@@ -0,0 +1,807 @@
+/*
+The Elves resume water filtering operations! Clean water starts flowing
+over the edge of Island Island.
+
+They offer to help you go over the edge of Island Island, too! Just hold on
+tight to one end of this impossibly long rope and they'll lower you down a
+safe distance from the massive waterfall you just created.
+
+As you finally reach Snow Island, you see that the water isn't really
+reaching the ground: it's being absorbed by the air itself. It looks like
+you'll finally have a little downtime while the moisture builds up to snow-
+producing levels. Snow Island is pretty scenic, even without any snow; why
+not take a walk?
+
+There's a map of nearby hiking trails (your puzzle input) that indicates
+paths (.), forest (#), and steep slopes (^, >, v, and <).
+
+For example:
+
+#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#
+
+You're currently on the single path tile in the top row; your goal is to
+reach the single path tile in the bottom row. Because of all the mist from
+the waterfall, the slopes are probably quite icy; if you step onto a slope
+tile, your next step must be downhill (in the direction the arrow is
+pointing). To make sure you have the most scenic hike possible, never step
+onto the same tile twice. What is the longest hike you can take?
+
+In the example above, the longest hike you can take is marked with O, and
+your starting position is marked S:
+
+#S#####################
+#OOOOOOO#########...###
+#######O#########.#.###
+###OOOOO#OOO>.###.#.###
+###O#####O#O#.###.#.###
+###OOOOO#O#O#.....#...#
+###v###O#O#O#########.#
+###...#O#O#OOOOOOO#...#
+#####.#O#O#######O#.###
+#.....#O#O#OOOOOOO#...#
+#.#####O#O#O#########v#
+#.#...#OOO#OOO###OOOOO#
+#.#.#v#######O###O###O#
+#...#.>.#...>OOO#O###O#
+#####v#.#.###v#O#O###O#
+#.....#...#...#O#O#OOO#
+#.#########.###O#O#O###
+#...###...#...#OOO#O###
+###.###.#.###v#####O###
+#...#...#.#.>.>.#.>O###
+#.###.###.#.###.#.#O###
+#.....###...###...#OOO#
+#####################O#
+
+This hike contains 94 steps. (The other possible hikes you could have taken
+were 90, 86, 82, 82, and 74 steps long.)
+
+Find the longest hike you can take through the hiking trails listed on your
+map. How many steps long is the longest hike?
+
+--- Part Two ---
+
+As you reach the trailhead, you realize that the ground isn't as slippery
+as you expected; you'll have no problem climbing up the steep slopes.
+
+Now, treat all slopes as if they were normal paths (.). You still want to
+make sure you have the most scenic hike possible, so continue to ensure
+that you never step onto the same tile twice. What is the longest hike you
+can take?
+
+In the example above, this increases the longest hike to 154 steps:
+
+#S#####################
+#OOOOOOO#########OOO###
+#######O#########O#O###
+###OOOOO#.>OOO###O#O###
+###O#####.#O#O###O#O###
+###O>...#.#O#OOOOO#OOO#
+###O###.#.#O#########O#
+###OOO#.#.#OOOOOOO#OOO#
+#####O#.#.#######O#O###
+#OOOOO#.#.#OOOOOOO#OOO#
+#O#####.#.#O#########O#
+#O#OOO#...#OOO###...>O#
+#O#O#O#######O###.###O#
+#OOO#O>.#...>O>.#.###O#
+#####O#.#.###O#.#.###O#
+#OOOOO#...#OOO#.#.#OOO#
+#O#########O###.#.#O###
+#OOO###OOO#OOO#...#O###
+###O###O#O###O#####O###
+#OOO#OOO#O#OOO>.#.>O###
+#O###O###O#O###.#.#O###
+#OOOOO###OOO###...#OOO#
+#####################O#
+
+Find the longest hike you can take through the surprisingly dry hiking
+trails listed on your map. How many steps long is the longest hike?
+ */
+
+use std::cmp::{max, Reverse};
+use std::collections::{HashMap, HashSet};
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+use itertools::Itertools;
+
+use crate::grid::{Direction, Grid, Position};
+use crate::parse::ParseError;
+use crate::Solution;
+
+/// Day 23: A Long Walk
+pub struct ALongWalk;
+
+impl Solution for ALongWalk {
+    const DAY: u8 = 23;
+    const TITLE: &'static str = "A Long Walk";
+    const INPUT: &'static str = "./inputs/23_long.txt";
+
+    type Input<'a> = Grid<char>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        preprocess_map(input.lines())
+    }
+
+    fn part1(map: &Self::Input<'_>) -> usize {
+        get_longest_hike(map)
+    }
+
+    fn part2(map: &Self::Input<'_>) -> usize {
+        // Part2 is solved using a pruned brute force (on the reduced graph) split between threads
+        get_longest_hike_no_slopes(map)
+    }
+}
+
+/// A structure to hold a node in the graph
+struct Node {
+    position: Position,
+    edges: HashMap<usize, usize>,
+}
+
+/// Check that the input is a valid map with somewhere to start and exit
+pub fn preprocess_map<'a>(snapshot: impl Iterator<Item=&'a str>) -> Result<Grid<char>, ParseError> {
+    let snapshot = Grid::parse_with(snapshot, "`#`, `.`, or a slope", |c| {
+        matches!(c, '#' | '.' | '^' | '>' | 'v' | '<').then_some(c)
+    })?;
+    if snapshot.height() == 0 || !snapshot.row(0).contains(&'.') {
+        return Err(ParseError::at(0, 0, "", "a path in the first row to start from"));
+    }
+    if !snapshot.row(snapshot.height() - 1).contains(&'.') {
+        return Err(ParseError::at(snapshot.height() - 1, 0, "", "a path in the last row to exit from"));
+    }
+    Ok(snapshot)
+}
+
+/// Get the direction which a slope tile forces you to walk in
+fn slope_direction(tile: char) -> Option<Direction> {
+    match tile {
+        '^' => Some(Direction::Up),
+        '>' => Some(Direction::Right),
+        'v' => Some(Direction::Down),
+        '<' => Some(Direction::Left),
+        _ => None,
+    }
+}
+
+/// Preprocess the input into a more useful form
+/// If `treat_slopes_as_path` then slopes are treated identically to paths
+/// Returns None if the exit can't be reached
+fn preprocess(snapshot: &Grid<char>, treat_slopes_as_path: bool) -> Option<(Vec<Node>, usize, usize)> {
+    let height = snapshot.height();
+    let start_col = snapshot.row(0).iter().position(|x| *x == '.').unwrap();
+    let exit = Position::new(height - 1, snapshot.row(height - 1).iter().position(|x| *x == '.').unwrap());
+
+    let mut nodes: Vec<Node> = vec![];
+
+    let mut stack: Vec<(Position, (usize, usize))> = vec![];
+    stack.push((Position::new(0, start_col), (0, 0)));
+    let mut visited = HashSet::new();
+
+    while let Some((pos, (last, distance))) = stack.pop() {
+        let adjacent = snapshot.neighbors(pos)
+            .filter(|(_, pos)| snapshot[*pos] != '#')
+            .collect::<Vec<_>>();
+        let this = snapshot[pos];
+        // Whether walking onto a tile in a direction is allowed
+        let can_enter = |direction: Direction, pos: Position| {
+            snapshot[pos] == '.' || slope_direction(snapshot[pos]) == Some(direction) || treat_slopes_as_path
+        };
+
+        if visited.contains(&pos) {
+            // This might also be a conjunction
+            // Find this tile's node (this is how we will know it is a conjunction)
+            if let Some((node_index, _)) = nodes.iter().find_position(|node| node.position == pos) {
+                if node_index != last { // No self-references
+                    nodes[last].edges.insert(node_index, distance);
+                    if treat_slopes_as_path {
+                        nodes[node_index].edges.insert(last, distance);
+                    }
+                }
+            }
+            continue;
+        }
+        visited.insert(pos);
+
+        if this == '.' || treat_slopes_as_path {
+            if adjacent.len() == 2 {
+                for (d, pos) in adjacent {
+                    if can_enter(d, pos) {
+                        stack.push((pos, (last, distance + 1)));
+                    }
+                }
+            } else {
+                let new_node = nodes.len();
+
+                nodes.push(Node {
+                    position: pos,
+                    edges: HashMap::new(),
+                });
+
+                if distance != 0 {
+                    nodes[last].edges.insert(new_node, distance);
+                    if treat_slopes_as_path {
+                        nodes[new_node].edges.insert(last, distance);
+                    }
+                }
+                for (d, pos) in adjacent {
+                    if can_enter(d, pos) {
+                        stack.push((pos, (new_node, 1)));
+                    }
+                }
+            }
+        } else if let Some(next) = slope_direction(this).and_then(|direction| snapshot.step(pos, direction)) {
+            stack.push((next, (last, distance + 1)));
+        }
+    }
+
+    let end_node = nodes.iter().find_position(|node| node.position == exit)?.0;
+
+    // A hike can't walk back onto the start or carry on past the exit (these edges only show up
+    // when a junction is right next to one of them)
+    for node in &mut nodes {
+        node.edges.remove(&0);
+    }
+    nodes[end_node].edges.clear();
+
+    Some((nodes, 0, end_node))
+}
+
+/// Create a topological ordering of the junctions which can be reached from `start`
+/// Returns None if the slopes let a hike walk around in a loop (so the graph isn't a DAG)
+fn create_topological_ordering(graph: &Vec<Node>, start: usize) -> Option<Vec<usize>> {
+    /// Helper function for performing the ordering
+    /// A junction which is still on the stack when it's reached again closes a loop
+    fn recurse_topological_ordering(graph: &Vec<Node>, node: usize, result: &mut Vec<usize>, marked: &mut HashSet<usize>, on_stack: &mut HashSet<usize>) -> Option<()> {
+        if on_stack.contains(&node) {
+            return None;
+        }
+        if marked.contains(&node) {
+            return Some(());
+        }
+        marked.insert(node);
+        on_stack.insert(node);
+        for to in graph[node].edges.keys() {
+            recurse_topological_ordering(graph, *to, result, marked, on_stack)?;
+        }
+        on_stack.remove(&node);
+        result.push(node);
+        Some(())
+    }
+
+    let mut result = vec![];
+    let mut marked = HashSet::new();
+    let mut on_stack = HashSet::new();
+
+    recurse_topological_ordering(graph, start, &mut result, &mut marked, &mut on_stack)?;
+
+    result.reverse();
+    Some(result)
+}
+
+/// A hike from the start to the exit
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Hike {
+    /// The junctions which the hike goes through in order (including the start and the exit)
+    pub junctions: Vec<Position>,
+    /// Every tile which is walked on in order (including the start and the exit)
+    pub tiles: Vec<Position>,
+}
+
+impl Hike {
+    /// Get the number of steps taken
+    pub fn steps(&self) -> usize {
+        self.tiles.len() - 1
+    }
+}
+
+/// Walk down the corridor from one junction to the next
+/// Returns the tiles after `from` up to and including `to`, or None if there is no corridor of that length
+fn walk_corridor(map: &Grid<char>, junctions: &HashSet<Position>, from: Position, to: Position, distance: usize, treat_slopes_as_path: bool) -> Option<Vec<Position>> {
+    // Which directions can be walked in from a tile
+    let directions = |pos: Position| match slope_direction(map[pos]) {
+        Some(direction) if !treat_slopes_as_path => vec![direction],
+        _ => Direction::ALL.to_vec(),
+    };
+    let can_enter = |direction: Direction, pos: Position| {
+        map[pos] == '.' || slope_direction(map[pos]) == Some(direction) || (treat_slopes_as_path && map[pos] != '#')
+    };
+
+    for first in directions(from) {
+        let mut tiles = vec![];
+        let mut previous = from;
+        let mut next = map.step(from, first).filter(|&pos| can_enter(first, pos));
+        while let Some(pos) = next {
+            tiles.push(pos);
+            if junctions.contains(&pos) || tiles.len() > distance {
+                break;
+            }
+            next = directions(pos).into_iter()
+                .filter_map(|direction| map.step(pos, direction).map(|next| (direction, next)))
+                .find(|&(direction, next)| next != previous && can_enter(direction, next))
+                .map(|(_, next)| next);
+            previous = pos;
+        }
+        if tiles.len() == distance && tiles.last() == Some(&to) {
+            return Some(tiles);
+        }
+    }
+    None
+}
+
+/// Turn a sequence of junctions into the hike which goes through them
+fn expand_hike(map: &Grid<char>, nodes: &[Node], junctions: &[usize], treat_slopes_as_path: bool) -> Hike {
+    let positions = nodes.iter().map(|node| node.position).collect::<HashSet<_>>();
+    let mut tiles = vec![nodes[junctions[0]].position];
+    for (&from, &to) in junctions.iter().tuple_windows() {
+        let (from, to, distance) = (nodes[from].position, nodes[to].position, nodes[from].edges[&to]);
+        tiles.extend(
+            walk_corridor(map, &positions, from, to, distance, treat_slopes_as_path)
+                .expect("Every edge of the reduced graph should be a corridor")
+        );
+    }
+    Hike {
+        junctions: junctions.iter().map(|&junction| nodes[junction].position).collect(),
+        tiles,
+    }
+}
+
+/// Find the longest hike where slopes can only be walked down
+/// Returns None if the exit can't be reached or if the slopes let a hike walk around in a loop
+pub fn find_longest_hike(map: &Grid<char>) -> Option<Hike> {
+    // Preprocess the input map into a reduced graph (paths with no forks are reduced to edges)
+    let (nodes, start, end) = preprocess(map, false)?;
+
+    // If the map is a DAG we can efficiently find the longest path by traversing it in topological
+    // order (remembering where the longest path into each junction came from)
+    let ordering = create_topological_ordering(&nodes, start)?;
+    let mut distances = vec![0_usize; nodes.len()];
+    let mut previous = vec![None; nodes.len()];
+    for node in ordering {
+        for (to, distance) in nodes[node].edges.iter() {
+            if previous[*to].is_none() || distances[node] + *distance > distances[*to] {
+                distances[*to] = distances[node] + *distance;
+                previous[*to] = Some(node);
+            }
+        }
+    }
+
+    let mut junctions = vec![end];
+    while let Some(node) = previous[junctions[junctions.len() - 1]] {
+        junctions.push(node);
+    }
+    junctions.reverse();
+    (junctions[0] == start).then(|| expand_hike(map, &nodes, &junctions, false))
+}
+
+/// Solve part 1
+pub fn get_longest_hike(map: &Grid<char>) -> usize {
+    find_longest_hike(map).expect("The exit should be reachable without walking in a loop").steps()
+}
+
+/// The most junctions which fit in the visited sets of the search
+const MAX_JUNCTIONS: usize = u128::BITS as usize;
+
+/// The reduced graph in a form which is quick to search for the longest path
+struct HikeSearch {
+    /// The junctions which can be walked to from each junction (longest first) and how far away they are
+    edges: Vec<Vec<(usize, usize)>>,
+    /// The longest path into each junction (no hike can gain more than this by visiting it)
+    entry: Vec<usize>,
+    /// Once this junction is reached the rest of the hike is forced
+    target: usize,
+    /// The length of the forced rest of the hike
+    remainder: usize,
+}
+
+/// A partly walked hike through the reduced graph
+#[derive(Debug, Copy, Clone)]
+struct Walk {
+    node: usize,
+    visited: u128,
+    length: usize,
+    /// The most the rest of the hike could add (the sum of `entry` over the unvisited junctions)
+    bound: usize,
+}
+
+/// The longest hike found by the search so far
+struct Longest {
+    /// One more than the length of the longest hike (or zero if none have been found)
+    /// This is kept outside of the lock so that the threads can prune their searches without waiting
+    length: AtomicUsize,
+    /// The junctions visited by the longest hike (up to the target)
+    junctions: Mutex<Vec<usize>>,
+}
+
+impl HikeSearch {
+    /// Prepare to search for the longest path between two junctions
+    /// Returns None if there are too many junctions
+    fn new(nodes: &[Node], start: usize, end: usize) -> Option<Self> {
+        if nodes.len() > MAX_JUNCTIONS {
+            return None;
+        }
+        let edges = nodes.iter()
+            .map(|node| {
+                let mut edges = node.edges.iter().map(|(&to, &distance)| (to, distance)).collect::<Vec<_>>();
+                edges.sort_unstable_by_key(|&(to, distance)| (Reverse(distance), to));
+                edges
+            })
+            .collect::<Vec<_>>();
+        let mut entry = vec![0; nodes.len()];
+        edges.iter().flatten().for_each(|&(to, distance)| entry[to] = max(entry[to], distance));
+
+        // If the exit can only be reached through a single junction then going anywhere else from that
+        // junction would cut off the exit, so the hike has to go straight there
+        let incoming = edges.iter().enumerate()
+            .filter_map(|(from, edges)| edges.iter().find(|&&(to, _)| to == end).map(|&(_, distance)| (from, distance)))
+            .collect::<Vec<_>>();
+        let (target, remainder) = match incoming[..] {
+            [(from, distance)] if start != end => (from, distance),
+            _ => (end, 0),
+        };
+
+        Some(HikeSearch { edges, entry, target, remainder })
+    }
+
+    /// Start a hike from a junction
+    fn start(&self, start: usize, end: usize) -> Walk {
+        let mut visited = 1 << start;
+        if self.target != end {
+            // The exit is only walked to at the very end
+            visited |= 1 << end;
+        }
+        let bound = (0..self.entry.len())
+            .filter(|node| visited & (1 << node) == 0)
+            .map(|node| self.entry[node])
+            .sum();
+        Walk { node: start, visited, length: 0, bound }
+    }
+
+    /// Get the hikes which continue on from a hike to each junction it can go to next
+    fn next(&self, walk: Walk) -> impl Iterator<Item=Walk> + '_ {
+        self.edges[walk.node].iter()
+            .filter(move |&&(to, _)| walk.visited & (1 << to) == 0)
+            .map(move |&(to, distance)| Walk {
+                node: to,
+                visited: walk.visited | 1 << to,
+                length: walk.length + distance,
+                bound: walk.bound - self.entry[to],
+            })
+    }
+
+    /// Search for the longest way to finish a hike which has gone through the `junctions` so far
+    /// The longest hike is shared so that hikes found on other threads prune this search too
+    fn search(&self, walk: Walk, junctions: &mut Vec<usize>, longest: &Longest) {
+        if walk.node == self.target {
+            let length = walk.length + self.remainder + 1;
+            if longest.length.fetch_max(length, Ordering::Relaxed) < length {
+                let mut longest_junctions = longest.junctions.lock().unwrap();
+                // Another thread might have found a longer one while waiting for the lock
+                if longest.length.load(Ordering::Relaxed) == length {
+                    longest_junctions.clone_from(junctions);
+                }
+            }
+            return;
+        }
+        for next in self.next(walk) {
+            // Skip the hikes which couldn't beat the best one even if they went everywhere that's left
+            if next.length + next.bound + self.remainder < longest.length.load(Ordering::Relaxed) {
+                continue;
+            }
+            junctions.push(next.node);
+            self.search(next, junctions, longest);
+            junctions.pop();
+        }
+    }
+}
+
+/// Find the longest path through the reduced graph which doesn't visit any junction twice
+/// The first few steps are split between `threads` threads (it is all done on this one if `threads` is 1)
+/// Returns the junctions on the path, or None if there is no path or if there are too many junctions
+fn search_longest_hike(nodes: &[Node], start: usize, end: usize, threads: usize) -> Option<Vec<usize>> {
+    let search = HikeSearch::new(nodes, start, end)?;
+    let longest = Longest { length: AtomicUsize::new(0), junctions: Mutex::new(vec![]) };
+
+    // Walk the first few steps of every hike so that there is enough work to share between the threads
+    let mut walks = vec![(search.start(start, end), vec![start])];
+    while threads > 1 && walks.len() < threads * 8 {
+        let (finished, walking): (Vec<_>, Vec<_>) = walks.into_iter().partition(|(walk, _)| walk.node == search.target);
+        if walking.is_empty() {
+            walks = finished;
+            break;
+        }
+        walks = finished.into_iter()
+            .chain(walking.into_iter().flat_map(|(walk, junctions)| {
+                let search = &search;
+                search.next(walk).map(move |next| (next, junctions.iter().copied().chain([next.node]).collect()))
+            }))
+            .collect();
+    }
+
+    let next_walk = AtomicUsize::new(0);
+    let work = || {
+        while let Some((walk, junctions)) = walks.get(next_walk.fetch_add(1, Ordering::Relaxed)) {
+            search.search(*walk, &mut junctions.clone(), &longest);
+        }
+    };
+    thread::scope(|scope| {
+        for _ in 1..threads.min(walks.len()) {
+            scope.spawn(work);
+        }
+        work();
+    });
+
+    longest.length.into_inner().checked_sub(1)?;
+    let mut junctions = longest.junctions.into_inner().unwrap();
+    if search.target != end {
+        junctions.push(end);
+    }
+    Some(junctions)
+}
+
+/// Find the longest hike where slopes are treated as paths using the given number of threads
+/// Returns None if there is no way to the exit or if the map has too many junctions to search
+pub fn find_longest_hike_no_slopes(map: &Grid<char>, threads: usize) -> Option<Hike> {
+    // Preprocess the input map into a reduced graph (paths with no forks are reduced to edges)
+    let (nodes, start, end) = preprocess(map, true)?;
+
+    let junctions = search_longest_hike(&nodes, start, end, threads.max(1))?;
+    Some(expand_hike(map, &nodes, &junctions, true))
+}
+
+/// Solve part 2 using the given number of threads
+/// Returns None if there is no way to the exit or if the map has too many junctions to search
+pub fn get_longest_hike_no_slopes_with(map: &Grid<char>, threads: usize) -> Option<usize> {
+    find_longest_hike_no_slopes(map, threads).map(|hike| hike.steps())
+}
+
+/// Solve part 2
+pub fn get_longest_hike_no_slopes(map: &Grid<char>) -> usize {
+    let threads = thread::available_parallelism().map_or(1, NonZeroUsize::get);
+    get_longest_hike_no_slopes_with(map, threads)
+        .unwrap_or_else(|| panic!("The map should have a hike to the exit through at most {MAX_JUNCTIONS} junctions"))
+}
+
+/// Draw a hike over the map with an `O` on every tile stepped onto (like the diagrams in the puzzle)
+pub fn render_hike(map: &Grid<char>, hike: &Hike) -> String {
+    let mut picture = map.clone();
+    if let Some((&start, steps)) = hike.tiles.split_first() {
+        picture[start] = 'S';
+        steps.iter().for_each(|&tile| picture[tile] = 'O');
+    }
+    picture.rows()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
+
+#[test]
+fn test_part1() {
+    assert_eq!(
+        94,
+        get_longest_hike(
+            &preprocess_map(r"#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#".lines()).unwrap()
+        )
+    );
+}
+
+#[test]
+fn test_part1_loop() {
+    // Without slopes the hike could walk around the loop forever
+    let map = preprocess_map("#.#####\n#.....#\n#.###.#\n#.....#\n#####.#".lines()).unwrap();
+    assert_eq!(None, find_longest_hike(&map));
+    // Slopes which only let the loop be walked one way around make it a DAG again
+    let map = preprocess_map("#.#####\n#.>...#\n#v###.#\n#...>.#\n#####.#".lines()).unwrap();
+    assert_eq!(Some(8), find_longest_hike(&map).map(|hike| hike.steps()));
+}
+
+#[test]
+fn test_part2() {
+    assert_eq!(
+        154,
+        get_longest_hike_no_slopes(
+            &preprocess_map(r"#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#".lines()).unwrap()
+        )
+    );
+}
+
+#[test]
+fn test_part2_threads() {
+    let map = preprocess_map(r"#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#".lines()).unwrap();
+    for threads in [1, 2, 3, 16, 100] {
+        assert_eq!(Some(154), get_longest_hike_no_slopes_with(&map, threads));
+    }
+
+    // A straight corridor has no junctions in between
+    assert_eq!(Some(2), get_longest_hike_no_slopes_with(&preprocess_map("#.#\n#.#\n#.#".lines()).unwrap(), 4));
+    // The exit can't be reached
+    assert_eq!(None, get_longest_hike_no_slopes_with(&preprocess_map("#.#\n###\n#.#".lines()).unwrap(), 1));
+}
+
+#[test]
+fn test_render_hike() {
+    let map = preprocess_map(r"#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#".lines()).unwrap();
+
+    let hike = find_longest_hike(&map).unwrap();
+    assert_eq!(94, hike.steps());
+    assert_eq!(Position::new(0, 1), hike.junctions[0]);
+    assert_eq!(Some(&Position::new(22, 21)), hike.junctions.last());
+    assert_eq!(r"#S#####################
+#OOOOOOO#########...###
+#######O#########.#.###
+###OOOOO#OOO>.###.#.###
+###O#####O#O#.###.#.###
+###OOOOO#O#O#.....#...#
+###v###O#O#O#########.#
+###...#O#O#OOOOOOO#...#
+#####.#O#O#######O#.###
+#.....#O#O#OOOOOOO#...#
+#.#####O#O#O#########v#
+#.#...#OOO#OOO###OOOOO#
+#.#.#v#######O###O###O#
+#...#.>.#...>OOO#O###O#
+#####v#.#.###v#O#O###O#
+#.....#...#...#O#O#OOO#
+#.#########.###O#O#O###
+#...###...#...#OOO#O###
+###.###.#.###v#####O###
+#...#...#.#.>.>.#.>O###
+#.###.###.#.###.#.#O###
+#.....###...###...#OOO#
+#####################O#
+", render_hike(&map, &hike));
+
+    for threads in [1, 4] {
+        let hike = find_longest_hike_no_slopes(&map, threads).unwrap();
+        assert_eq!(154, hike.steps());
+        // Every tile is only visited once
+        assert_eq!(155, hike.tiles.iter().collect::<HashSet<_>>().len());
+        assert_eq!(r"#S#####################
+#OOOOOOO#########OOO###
+#######O#########O#O###
+###OOOOO#.>OOO###O#O###
+###O#####.#O#O###O#O###
+###O>...#.#O#OOOOO#OOO#
+###O###.#.#O#########O#
+###OOO#.#.#OOOOOOO#OOO#
+#####O#.#.#######O#O###
+#OOOOO#.#.#OOOOOOO#OOO#
+#O#####.#.#O#########O#
+#O#OOO#...#OOO###...>O#
+#O#O#O#######O###.###O#
+#OOO#O>.#...>O>.#.###O#
+#####O#.#.###O#.#.###O#
+#OOOOO#...#OOO#.#.#OOO#
+#O#########O###.#.#O###
+#OOO###OOO#OOO#...#O###
+###O###O#O###O#####O###
+#OOO#OOO#O#OOO>.#.>O###
+#O###O###O#O###.#.#O###
+#OOOOO###OOO###...#OOO#
+#####################O#
+", render_hike(&map, &hike));
+    }
+}
+
+#[test]
+fn test_parse_error() {
+    assert_eq!(Err(ParseError::at(1, 1, "o", "`#`, `.`, or a slope")), preprocess_map("#.#\n#o#\n#.#".lines()));
+    assert_eq!(
+        Err(ParseError::at(2, 0, "", "a path in the last row to exit from")),
+        preprocess_map("#.#\n#.#\n###".lines())
+    );
+}
@@ -148,27 +148,52 @@ so that it perfectly collides with every hailstone. What do you get if you
 add up the X, Y, and Z coordinates of that initial position?
  */
 
-use std::fs;
 use std::ops::{Add, Mul, Sub};
-use std::str::FromStr;
 
 use itertools::Itertools;
-use ndarray::prelude::*;
-use ndarray_linalg::Solve;
-use num::Float;
+use num::rational::Ratio;
+use num::{BigInt, BigRational, Float, One, Signed, ToPrimitive, Zero};
 use regex::Regex;
 
-fn main() {
-    let file = fs::read_to_string("./inputs/24_odds.txt").unwrap();
-    let hailstones = file.lines();
-    println!("{}", get_intersection_count(hailstones.clone(), 200000000000000.0, 400000000000000.0));
-    println!("{}", get_initial_position(hailstones));
+use crate::parse::{self, ParseError};
+use crate::Solution;
+
+/// Day 24: Never Tell Me The Odds
+pub struct NeverTellMeTheOdds;
+
+impl Solution for NeverTellMeTheOdds {
+    const DAY: u8 = 24;
+    const TITLE: &'static str = "Never Tell Me The Odds";
+    const INPUT: &'static str = "./inputs/24_odds.txt";
+
+    type Input<'a> = Vec<Hailstone>;
+    type Part1 = usize;
+    type Part2 = isize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        preprocess(input.lines())
+    }
+
+    fn part1(hailstones: &Self::Input<'_>) -> usize {
+        get_intersection_count(hailstones, 200000000000000.0, 400000000000000.0)
+    }
+
+    fn part2(hailstones: &Self::Input<'_>) -> isize {
+        get_initial_position(hailstones)
+    }
 }
 
 /// A data type to represent a vector/position in space
-/// I ended up writing this before I added the linalg package
-#[derive(Debug, Clone, PartialEq)]
-struct Vector([f64; 3]);
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Vector(pub [i64; 3]);
+
+impl Vector {
+    /// Get the vector as floating point numbers
+    /// The inputs are small enough that this is exact
+    fn to_f64(self) -> [f64; 3] {
+        self.0.map(|value| value as f64)
+    }
+}
 
 impl Add for Vector {
     type Output = Vector;
@@ -186,38 +211,31 @@ impl Sub for Vector {
     }
 }
 
-impl Mul<f64> for Vector {
+impl Mul<i64> for Vector {
     type Output = Vector;
 
-    fn mul(self, rhs: f64) -> Self::Output {
+    fn mul(self, rhs: i64) -> Self::Output {
         Vector([self.0[0] * rhs, self.0[1] * rhs, self.0[2] * rhs])
     }
 }
 
 /// Represents a hailstone
-#[derive(Debug)]
-struct Hailstone {
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Hailstone {
     position: Vector,
     velocity: Vector,
 }
 
 /// Preprocess the input into a more useful form
-fn preprocess<'a>(hailstones: impl Iterator<Item=&'a str>) -> Vec<Hailstone> {
+pub fn preprocess<'a>(hailstones: impl Iterator<Item=&'a str>) -> Result<Vec<Hailstone>, ParseError> {
     let regex = Regex::new(r"^(-?\d+),\s*(-?\d+),\s*(-?\d+)\s*@\s*(-?\d+),\s*(-?\d+),\s*(-?\d+)$").unwrap();
-    hailstones.map(|line| {
-        let captures = regex.captures(line).unwrap();
-        Hailstone {
-            position: Vector([
-                f64::from_str(captures.get(1).unwrap().as_str()).unwrap(),
-                f64::from_str(captures.get(2).unwrap().as_str()).unwrap(),
-                f64::from_str(captures.get(3).unwrap().as_str()).unwrap(),
-            ]),
-            velocity: Vector([
-                f64::from_str(captures.get(4).unwrap().as_str()).unwrap(),
-                f64::from_str(captures.get(5).unwrap().as_str()).unwrap(),
-                f64::from_str(captures.get(6).unwrap().as_str()).unwrap(),
-            ]),
-        }
+    parse::lines(hailstones).map(|line| {
+        let captures = line.captures(&regex, "a hailstone like `19, 13, 30 @ -2, 1, -2`")?;
+        let value = |index| line.parse(captures.get(index).unwrap().as_str(), "a number");
+        Ok(Hailstone {
+            position: Vector([value(1)?, value(2)?, value(3)?]),
+            velocity: Vector([value(4)?, value(5)?, value(6)?]),
+        })
     }).collect()
 }
 
@@ -227,8 +245,10 @@ fn preprocess<'a>(hailstones: impl Iterator<Item=&'a str>) -> Vec<Hailstone> {
 fn get_x_y_intersect(a: &Hailstone, b: &Hailstone) -> (f64, f64) {
     const X: usize = 0;
     const Y: usize = 1;
+    let (a_position, a_velocity) = (a.position.to_f64(), a.velocity.to_f64());
+    let (b_position, b_velocity) = (b.position.to_f64(), b.velocity.to_f64());
 
-    if a.velocity.0[Y] / a.velocity.0[X] == b.velocity.0[Y] / b.velocity.0[X] {
+    if a_velocity[Y] / a_velocity[X] == b_velocity[Y] / b_velocity[X] {
         return (f64::nan(), f64::nan());
     }
 
@@ -237,8 +257,8 @@ fn get_x_y_intersect(a: &Hailstone, b: &Hailstone) -> (f64, f64) {
 
     // a.x - b.x = b.dx*tb - a.dx*ta
     // a.y - b.y = b.dy*tb - a.dy*ta
-    let delta_x = a.position.0[X] - b.position.0[X];
-    let delta_y = a.position.0[Y] - b.position.0[Y];
+    let delta_x = a_position[X] - b_position[X];
+    let delta_y = a_position[Y] - b_position[Y];
 
     // delta_x = b.dx*tb - a.dx*ta
     // delta_y = b.dy*tb - a.dy*ta
@@ -251,9 +271,9 @@ fn get_x_y_intersect(a: &Hailstone, b: &Hailstone) -> (f64, f64) {
     // ta = (b.dx*(delta_y + a.dy*ta)/b.dy - delta_y)/a.dx
     // ta = (b.dx*delta_y/b.dy + ta*b.dx*a.dy/b.dy - delta_x)/a.dx
     // ta = b.dx*delta_y/b.dy/a.dx + ta*b.dx*a.dy/b.dy/a.dx - delta_y/a.dx
-    let term_1 = b.velocity.0[X] * delta_y / b.velocity.0[Y] / a.velocity.0[X];
-    let factor = b.velocity.0[X] * a.velocity.0[Y] / b.velocity.0[Y] / a.velocity.0[X];
-    let term_2 = delta_x / a.velocity.0[X];
+    let term_1 = b_velocity[X] * delta_y / b_velocity[Y] / a_velocity[X];
+    let factor = b_velocity[X] * a_velocity[Y] / b_velocity[Y] / a_velocity[X];
+    let term_2 = delta_x / a_velocity[X];
     // ta = term_1 + ta*factor - term_2
     let term_3 = term_1 - term_2;
     // ta = term_3 + ta*factor
@@ -263,24 +283,20 @@ fn get_x_y_intersect(a: &Hailstone, b: &Hailstone) -> (f64, f64) {
     let ta = term_3 / (1.0 - factor);
 
     // (delta_y + a.dy*ta)/b.dy = tb
-    let tb = (delta_y + a.velocity.0[Y] * ta) / b.velocity.0[Y];
+    let tb = (delta_y + a_velocity[Y] * ta) / b_velocity[Y];
 
     if ta < 0.0 || tb < 0.0 {
         return (f64::nan(), f64::nan());
     }
 
-    let result = (
-        a.position.0[X] + a.velocity.0[X] * ta,
-        a.position.0[Y] + a.velocity.0[Y] * ta,
-    );
-
-    result
+    (
+        a_position[X] + a_velocity[X] * ta,
+        a_position[Y] + a_velocity[Y] * ta,
+    )
 }
 
 /// Solve part1
-fn get_intersection_count<'a>(hailstones: impl Iterator<Item=&'a str>, min: f64, max: f64) -> usize {
-    let hailstones = preprocess(hailstones);
-
+pub fn get_intersection_count(hailstones: &[Hailstone], min: f64, max: f64) -> usize {
     hailstones.iter()
         .combinations(2)
         .map(|pair| get_x_y_intersect(pair[0], pair[1]))
@@ -289,13 +305,84 @@ fn get_intersection_count<'a>(hailstones: impl Iterator<Item=&'a str>, min: f64,
         .count()
 }
 
-/// Solve part2
-fn get_initial_position<'a>(hailstones: impl Iterator<Item=&'a str>) -> isize {
-    const X: usize = 0;
-    const Y: usize = 1;
-    const Z: usize = 2;
-    let hailstones = preprocess(hailstones);
+/// Where the rock has to be thrown from and how fast it has to go to hit every hailstone
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Throw {
+    pub position: Vector,
+    pub velocity: Vector,
+}
+
+/// Get the linear equations in the rock's [P0.x, P0.y, P0.z, V0.x, V0.y, V0.z] given by it hitting
+/// two hailstones (one equation for each pair of axes)
+fn pair_equations(first: &Hailstone, second: &Hailstone) -> [([BigRational; 6], BigRational); 3] {
+    [(0, 1), (0, 2), (1, 2)].map(|(i, j)| {
+        let value = |value: i64| BigRational::from_integer(BigInt::from(value));
+        let (p1, v1) = (first.position.0, first.velocity.0);
+        let (p2, v2) = (second.position.0, second.velocity.0);
+
+        // See the derivation in find_throw using (i, j) in place of (x, y)
+        let mut row = [(); 6].map(|_| BigRational::zero());
+        row[i] = value(v1[j]) - value(v2[j]);
+        row[j] = value(v2[i]) - value(v1[i]);
+        row[3 + i] = value(p2[j]) - value(p1[j]);
+        row[3 + j] = value(p1[i]) - value(p2[i]);
+        let constant = (value(p2[j]) * value(v2[i]) - value(p1[j]) * value(v1[i]))
+            - (value(p2[i]) * value(v2[j]) - value(p1[i]) * value(v1[j]));
+        (row, constant)
+    })
+}
+
+/// Solve a system of linear equations exactly using Gaussian elimination
+/// Returns None if the system doesn't have exactly one solution
+fn solve_linear<const N: usize>(mut equations: Vec<([BigRational; N], BigRational)>) -> Option<[BigRational; N]> {
+    for column in 0..N {
+        // Find an equation to eliminate this variable from all the others with
+        let pivot = (column..equations.len()).find(|&row| !equations[row].0[column].is_zero())?;
+        equations.swap(column, pivot);
+
+        let (coefficients, constant) = equations[column].clone();
+        let scale = BigRational::one() / &coefficients[column];
+        for (row, equation) in equations.iter_mut().enumerate() {
+            if row == column || equation.0[column].is_zero() {
+                continue;
+            }
+            let factor = &equation.0[column] * &scale;
+            for (coefficient, pivot_coefficient) in equation.0.iter_mut().zip(&coefficients) {
+                *coefficient -= &factor * pivot_coefficient;
+            }
+            equation.1 -= factor * &constant;
+        }
+    }
+    Some(std::array::from_fn(|i| &equations[i].1 / &equations[i].0[i]))
+}
+
+/// Check if a throw hits a hailstone (at some time which isn't in the past)
+fn hits(throw: &Throw, hailstone: &Hailstone) -> bool {
+    // throw.position + throw.velocity * t = hailstone.position + hailstone.velocity * t
+    // (throw.position - hailstone.position) = (hailstone.velocity - throw.velocity) * t
+    let mut time: Option<Ratio<i128>> = None;
+    for axis in 0..3 {
+        let distance = throw.position.0[axis] as i128 - hailstone.position.0[axis] as i128;
+        let speed = hailstone.velocity.0[axis] as i128 - throw.velocity.0[axis] as i128;
+        if speed == 0 {
+            // They move together along this axis so they had better be lined up
+            if distance != 0 {
+                return false;
+            }
+            continue;
+        }
+        let axis_time = Ratio::new(distance, speed);
+        if time.is_some_and(|time| time != axis_time) {
+            return false;
+        }
+        time = Some(axis_time);
+    }
+    time.is_none_or(|time| !time.is_negative())
+}
 
+/// Find the throw which hits every hailstone
+/// Returns None if there isn't one (or the hailstones don't pin down a single throw)
+pub fn find_throw(hailstones: &[Hailstone]) -> Option<Throw> {
     // There exists an P={X}{Y}{Z},V={VX}{VY}{VZ} s.t.
     //   For all Hailstones H {H.P, H.V},
     //     There exists a t s.t.
@@ -357,61 +444,40 @@ fn get_initial_position<'a>(hailstones: impl Iterator<Item=&'a str>) -> isize {
     // [(V1.y - V2.y), -(V1.x - V2.x), 0, -(P1.y - P2.y), +(P1.x - P2.x), 0] [P0.x, P0.y, P0.z, V0.x, V0.y, V0.z]^T
     // = (V2.xP2.y - V1.xP1.y) - (P2.xV2.y - P1.xV1.y)
 
-    let p1x = hailstones[0].position.0[X];
-    let p1y = hailstones[0].position.0[Y];
-    let p1z = hailstones[0].position.0[Z];
-    let v1x = hailstones[0].velocity.0[X];
-    let v1y = hailstones[0].velocity.0[Y];
-    let v1z = hailstones[0].velocity.0[Z];
-
-    // Interestingly not all inputs here give me the same result
-    // This one happens to work, but that doesnt seem guaranteed for all sets of 3 inputs
-    // I suspect this has less numerical errors if the inputs are sufficiently far apart
-    let p2x = hailstones[2].position.0[X];
-    let p2y = hailstones[2].position.0[Y];
-    let p2z = hailstones[2].position.0[Z];
-    let v2x = hailstones[2].velocity.0[X];
-    let v2y = hailstones[2].velocity.0[Y];
-    let v2z = hailstones[2].velocity.0[Z];
-
-    let p3x = hailstones[3].position.0[X];
-    let p3y = hailstones[3].position.0[Y];
-    let p3z = hailstones[3].position.0[Z];
-    let v3x = hailstones[3].velocity.0[X];
-    let v3y = hailstones[3].velocity.0[Y];
-    let v3z = hailstones[3].velocity.0[Z];
-
-    const ZERO: f64 = 0.0;
-
-    // The equation above copied down here
-    // [(V1.y - V2.y), -(V1.x - V2.x), 0, -(P1.y - P2.y), +(P1.x - P2.x), 0] [P0.x, P0.y, P0.z, V0.x, V0.y, V0.z]^T
-    // = (V2.xP2.y - V1.xP1.y) - (P2.xV2.y - P1.xV1.y)
+    // Applying this to the (1, 2) and (1, 3) pairs for all the (X, Y), (X, Z), (Y, Z) pairs of
+    // axes gives 6 equations with 6 unknowns
+    //
+    // Any three hailstones will do as long as those equations are independent. The arithmetic is
+    // done exactly since the numbers are far too big for f64 to keep track of the small differences
+    let solution = hailstones.iter()
+        .tuple_combinations()
+        .find_map(|(first, second, third)| {
+            let equations = pair_equations(first, second).into_iter()
+                .chain(pair_equations(first, third))
+                .collect();
+            solve_linear::<6>(equations)
+        })?;
+
+    // A single throw satisfies the equations for every triple, so if this one doesn't work then
+    // there is no throw which does
+    let integer = |value: &BigRational| value.is_integer().then(|| value.to_integer().to_i64()).flatten();
+    let throw = Throw {
+        position: Vector([integer(&solution[0])?, integer(&solution[1])?, integer(&solution[2])?]),
+        velocity: Vector([integer(&solution[3])?, integer(&solution[4])?, integer(&solution[5])?]),
+    };
+    hailstones.iter()
+        .all(|hailstone| hits(&throw, hailstone))
+        .then_some(throw)
+}
 
-    // Now we can construct the entire matrix using the left side of this applied to both the
-    // (1, 2) and (1, 3) pairs
-    // As well as all the (X, Y), (Y, Z), (Z, X) pairs
-    let a = array![
-        [v1y - v2y, -v1x + v2x, ZERO, -p1y + p2y, p1x - p2x, ZERO],
-        [v1y - v3y, -v1x + v3x, ZERO, -p1y + p3y, p1x - p3x, ZERO],
-        [v1z - v2z, ZERO, -v1x + v2x, -p1z + p2z, ZERO, p1x - p2x],
-        [v1z - v3z, ZERO, -v1x + v3x, -p1z + p3z, ZERO, p1x - p3x],
-        [ZERO, v1z - v2z, -v1y + v2y, ZERO, -p1z + p2z, p1y - p2y],
-        [ZERO, v1z - v3z, -v1y + v3y, ZERO, -p1z + p3z, p1y - p3y],
-    ];
-
-    let b = array![
-        (p2y * v2x - p1y * v1x) - (p2x * v2y - p1x * v1y),
-        (p3y * v3x - p1y * v1x) - (p3x * v3y - p1x * v1y),
-        (p2z * v2x - p1z * v1x) - (p2x * v2z - p1x * v1z),
-        (p3z * v3x - p1z * v1x) - (p3x * v3z - p1x * v1z),
-        (p2z * v2y - p1z * v1y) - (p2y * v2z - p1y * v1z),
-        (p3z * v3y - p1z * v1y) - (p3y * v3z - p1y * v1z),
-    ];
-
-    let x = a.solve_into(b).unwrap();
-
-    // Extract the x, y, and z from the result
-    (x[0] + x[1] + x[2]).round() as isize
+/// Solve part2
+pub fn get_initial_position(hailstones: &[Hailstone]) -> isize {
+    let throw = find_throw(hailstones).expect("No throw hits every hailstone");
+    throw.position.0.iter()
+        .map(|&coordinate| coordinate as i128)
+        .sum::<i128>()
+        .try_into()
+        .expect("The coordinates of the throw add up to more than fits in an isize")
 }
 
 #[test]
@@ -419,28 +485,55 @@ fn test_part1() {
     assert_eq!(
         2,
         get_intersection_count(
-            r"19, 13, 30 @ -2,  1, -2
+            &preprocess(r"19, 13, 30 @ -2,  1, -2
 18, 19, 22 @ -1, -1, -2
 20, 25, 34 @ -2, -2, -4
 12, 31, 28 @ -1, -2, -1
-20, 19, 15 @  1, -5, -3".lines(),
+20, 19, 15 @  1, -5, -3".lines()).unwrap(),
             7.0,
             27.0,
         )
     );
 }
 
+#[test]
+fn test_find_throw() {
+    let hailstones = preprocess(r"19, 13, 30 @ -2,  1, -2
+18, 19, 22 @ -1, -1, -2
+20, 25, 34 @ -2, -2, -4
+12, 31, 28 @ -1, -2, -1
+20, 19, 15 @  1, -5, -3".lines()).unwrap();
+    assert_eq!(
+        Some(Throw { position: Vector([24, 13, 10]), velocity: Vector([-3, 1, 2]) }),
+        find_throw(&hailstones)
+    );
+
+    // Moving the last hailstone means nothing hits all of them
+    let mut missed = hailstones.clone();
+    missed[4].position.0[0] += 1;
+    assert_eq!(None, find_throw(&missed));
+}
+
 #[test]
 fn test_part2() {
     assert_eq!(
         47,
         get_initial_position(
-            r"19, 13, 30 @ -2,  1, -2
+            &preprocess(r"19, 13, 30 @ -2,  1, -2
 18, 19, 22 @ -1, -1, -2
 20, 25, 34 @ -2, -2, -4
 12, 31, 28 @ -1, -2, -1
-20, 19, 15 @  1, -5, -3".lines()
+20, 19, 15 @  1, -5, -3".lines()).unwrap()
         )
     );
 }
 
+#[test]
+fn test_hits_far_apart() {
+    // The differences between these don't fit in an i64
+    let throw = Throw { position: Vector([i64::MIN, 0, 0]), velocity: Vector([i64::MAX, 0, 0]) };
+    let hailstone = Hailstone { position: Vector([i64::MAX, 0, 0]), velocity: Vector([-1, 0, 0]) };
+    assert!(hits(&throw, &hailstone));
+    let hailstone = Hailstone { position: Vector([i64::MAX, 1, 0]), velocity: Vector([-1, 0, 0]) };
+    assert!(!hits(&throw, &hailstone));
+}
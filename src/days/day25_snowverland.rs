@@ -0,0 +1,607 @@
+/*
+
+Still somehow without snow, you go to the last place you haven't checked:
+the center of Snow Island, directly below the waterfall.
+
+Here, someone has clearly been trying to fix the problem. Scattered
+everywhere are hundreds of weather machines, almanacs, communication
+modules, hoof prints, machine parts, mirrors, lenses, and so on.
+
+Somehow, everything has been wired together into a massive snow-producing
+apparatus, but nothing seems to be running. You check a tiny screen on one
+of the communication modules: Error 2023. It doesn't say what Error 2023
+means, but it does have the phone number for a support line printed on it.
+
+"Hi, you've reached Weather Machines And So On, Inc. How can I help you?"
+You explain the situation.
+
+"Error 2023, you say? Why, that's a power overload error, of course! It
+means you have too many components plugged in. Try unplugging some
+components and--" You explain that there are hundreds of components here
+and you're in a bit of a hurry.
+
+"Well, let's see how bad it is; do you see a big red reset button
+somewhere? It should be on its own module. If you push it, it probably
+won't fix anything, but it'll report how overloaded things are." After a
+minute or two, you find the reset button; it's so big that it takes two
+hands just to get enough leverage to push it. Its screen then displays:
+
+SYSTEM OVERLOAD!
+
+Connected components would require
+power equal to at least 100 stars!
+
+"Wait, how many components did you say are plugged in? With that much
+equipment, you could produce snow for an entire--" You disconnect the call.
+
+You have nowhere near that many stars - you need to find a way to
+disconnect at least half of the equipment here, but it's already Christmas!
+You only have time to disconnect three wires.
+
+Fortunately, someone left a wiring diagram (your puzzle input) that shows
+how the components are connected. For example:
+
+jqt: rhn xhk nvd
+rsh: frs pzl lsr
+xhk: hfx
+cmg: qnr nvd lhk bvb
+rhn: xhk bvb hfx
+bvb: xhk hfx
+pzl: lsr hfx nvd
+qnr: nvd
+ntq: jqt hfx bvb xhk
+nvd: lhk
+lsr: lhk
+rzs: qnr cmg lsr rsh
+frs: qnr lhk lsr
+
+Each line shows the name of a component, a colon, and then a list of other
+components to which that component is connected. Connections aren't
+directional; abc: xyz and xyz: abc both represent the same configuration.
+Each connection between two components is represented only once, so some
+components might only ever appear on the left or right side of a colon.
+
+In this example, if you disconnect the wire between hfx/pzl, the wire
+between bvb/cmg, and the wire between nvd/jqt, you will divide the
+components into two separate, disconnected groups:
+
+- 9 components: cmg, frs, lhk, lsr, nvd, pzl, qnr, rsh, and rzs.
+- 6 components: bvb, hfx, jqt, ntq, rhn, and xhk.
+
+Multiplying the sizes of these groups together produces 54.
+
+Find the three wires you need to disconnect in order to divide the
+components into two separate groups. What do you get if you multiply the
+sizes of these two groups together?
+
+--- Part Two ---
+
+You climb over weather machines, under giant springs, and narrowly avoid a
+pile of pipes as you find and disconnect the three wires.
+
+A moment after you disconnect the last wire, the big red reset button
+module makes a small ding noise:
+
+System overload resolved!
+Power required is now 50 stars.
+
+Out of the corner of your eye, you notice goggles and a loose-fitting hard
+hat peeking at you from behind an ultra crucible. You think you see a faint
+glow, but before you can investigate, you hear another small ding:
+
+Power required is now 49 stars.
+
+Please supply the necessary stars and
+push the button to restart the system.
+
+You have enough stars to [Push The Big Red Button].
+ */
+
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BinaryHeap, HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+
+use rand::Rng;
+use rand::prelude::IteratorRandom;
+
+use crate::parse::{self, ParseError};
+use crate::{NoAnswer, Solution};
+
+/// Day 25: Snowverload
+pub struct Snowverload;
+
+impl Solution for Snowverload {
+    const DAY: u8 = 25;
+    const TITLE: &'static str = "Snowverload";
+    const INPUT: &'static str = "./inputs/25_snowverland.txt";
+
+    type Input<'a> = Graph;
+    type Part1 = usize;
+    type Part2 = NoAnswer;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        preprocess(input.lines())
+    }
+
+    fn part1(graph: &Self::Input<'_>) -> usize {
+        get_division_product(graph)
+    }
+
+    fn part2(_: &Self::Input<'_>) -> NoAnswer {
+        NoAnswer
+    }
+}
+
+/// A structure to represent a node in a graph
+#[derive(Debug, Clone)]
+struct Node {
+    name: String,
+    edges: HashMap<usize, usize>,
+}
+
+/// Represents the graph
+#[derive(Debug, Clone)]
+pub struct Graph {
+    nodes: HashMap<usize, Node>,
+}
+
+impl Graph {
+    /// Get the number of nodes
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Check if there are no nodes
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Get the name of a node from the wiring diagram
+    pub fn name(&self, node: usize) -> Option<&str> {
+        self.nodes.get(&node).map(|node| node.name.as_str())
+    }
+
+    /// Create a graph of just the given nodes and the edges between them (the nodes keep their ids)
+    pub fn subgraph(&self, nodes: &[usize]) -> Graph {
+        let nodes = nodes.iter()
+            .filter_map(|id| self.nodes.get(id).map(|node| (*id, node)))
+            .collect::<HashMap<_, _>>();
+        Graph {
+            nodes: nodes.iter()
+                .map(|(&id, node)| (id, Node {
+                    name: node.name.clone(),
+                    edges: node.edges.iter()
+                        .filter(|(to, _)| nodes.contains_key(to))
+                        .map(|(&to, &weight)| (to, weight))
+                        .collect(),
+                }))
+                .collect(),
+        }
+    }
+
+    /// Find the groups of nodes which are connected to each other
+    /// Each group is sorted and the groups are ordered by their lowest node
+    pub fn connected_components(&self) -> Vec<Vec<usize>> {
+        let mut ids = self.nodes.keys().copied().collect::<Vec<_>>();
+        ids.sort_unstable();
+
+        let mut seen = HashSet::new();
+        let mut components = vec![];
+        for id in ids {
+            if !seen.insert(id) {
+                continue;
+            }
+            let mut component = vec![id];
+            let mut stack = vec![id];
+            while let Some(node) = stack.pop() {
+                for &to in self.nodes[&node].edges.keys() {
+                    if seen.insert(to) {
+                        component.push(to);
+                        stack.push(to);
+                    }
+                }
+            }
+            component.sort_unstable();
+            components.push(component);
+        }
+        components
+    }
+}
+
+/// Preprocess the input into a more useful form (a graph)
+pub fn preprocess<'a>(wiring_diagram: impl Iterator<Item=&'a str>) -> Result<Graph, ParseError> {
+    let wiring_diagram = parse::lines(wiring_diagram).collect::<Vec<_>>();
+
+    let mut graph = Graph {
+        nodes: HashMap::new()
+    };
+
+    // Helper function
+    fn add_node_if_needed<'a>(name: &'a str, graph: &mut Graph, lookup_table: &mut HashMap<&'a str, usize>) {
+        if !lookup_table.contains_key(name) {
+            lookup_table.insert(name, graph.nodes.len());
+            graph.nodes.insert(
+                graph.nodes.len(),
+                Node {
+                    name: name.into(),
+                    edges: HashMap::new(),
+                },
+            );
+        }
+    }
+
+    let mut lookup_table = HashMap::<&'a str, usize>::new();
+    for entry in wiring_diagram {
+        let (name, edges) = entry.split_once(entry.text, ":")?;
+        let edges = edges.split_whitespace();
+        add_node_if_needed(name, &mut graph, &mut lookup_table);
+        let this_index = *lookup_table.get(name).unwrap();
+        edges.for_each(|edge| {
+            add_node_if_needed(edge, &mut graph, &mut lookup_table);
+            let edge_index = *lookup_table.get(edge).unwrap();
+            graph.nodes.get_mut(&this_index).unwrap().edges.insert(edge_index, 1);
+            graph.nodes.get_mut(&edge_index).unwrap().edges.insert(this_index, 1);
+        });
+    }
+
+    Ok(graph)
+}
+
+/// A split of a graph's nodes into two sides
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Cut {
+    /// The total weight of the edges which cross between the two sides
+    pub weight: usize,
+    /// The edges which cross between the two sides, each going from the first side to the second
+    pub edges: Vec<(usize, usize)>,
+    /// The nodes on each side (the first side contains the lowest node)
+    pub partitions: [Vec<usize>; 2],
+}
+
+impl Cut {
+    /// Create the cut which separates the given nodes from the rest of the graph
+    fn new(graph: &Graph, side: &[usize]) -> Self {
+        let side = side.iter().copied().collect::<HashSet<_>>();
+        let (mut first, mut second): (Vec<_>, Vec<_>) = graph.nodes.keys().partition(|node| side.contains(node));
+        first.sort_unstable();
+        second.sort_unstable();
+        if second.first() < first.first() {
+            std::mem::swap(&mut first, &mut second);
+        }
+
+        let mut weight = 0;
+        let mut edges = vec![];
+        for &from in &first {
+            for (&to, &edge_weight) in &graph.nodes[&from].edges {
+                if second.binary_search(&to).is_ok() {
+                    weight += edge_weight;
+                    edges.push((from, to));
+                }
+            }
+        }
+        edges.sort_unstable();
+
+        Cut { weight, edges, partitions: [first, second] }
+    }
+}
+
+/// Implements the Stoer-Wagner Algorithm
+/// https://en.wikipedia.org/wiki/Stoer%E2%80%93Wagner_algorithm
+/// Returns None if the graph has fewer than two nodes
+pub fn minimum_cut(graph: &Graph) -> Option<Cut> {
+    let mut ids = graph.nodes.keys().copied().collect::<Vec<_>>();
+    ids.sort_unstable();
+    if ids.len() < 2 {
+        return None;
+    }
+
+    // Work on a copy with dense indices (and ordered edges so that ties are always broken the same way)
+    let index = ids.iter().enumerate().map(|(index, &id)| (id, index)).collect::<HashMap<_, _>>();
+    let mut edges = ids.iter()
+        .map(|id| graph.nodes[id].edges.iter().map(|(to, &weight)| (index[to], weight)).collect::<BTreeMap<_, _>>())
+        .collect::<Vec<_>>();
+    // Used to remember which of the initial nodes got merged into this node
+    let mut merged = ids.iter().map(|&id| vec![id]).collect::<Vec<_>>();
+    let mut active = vec![true; ids.len()];
+
+    let mut best: Option<(usize, Vec<usize>)> = None;
+    for _ in 1..ids.len() {
+        let (s, t, weight) = minimum_cut_phase(&edges, &active);
+        // The "cut-of-the-phase" separates everything merged into t from the rest
+        if best.as_ref().is_none_or(|(best_weight, _)| weight < *best_weight) {
+            best = Some((weight, merged[t].clone()));
+        }
+
+        // Now we merge t into s
+        for (to, weight) in std::mem::take(&mut edges[t]) {
+            edges[to].remove(&t);
+            if to != s {
+                *edges[s].entry(to).or_default() += weight;
+                *edges[to].entry(s).or_default() += weight;
+            }
+        }
+        let t_merged = std::mem::take(&mut merged[t]);
+        merged[s].extend(t_merged);
+        active[t] = false;
+    }
+
+    best.map(|(_, side)| Cut::new(graph, &side))
+}
+
+/// Add the active nodes one at a time, always picking the one most tightly connected to those added so far
+/// Returns the last two nodes added and the weight connecting the last one to the rest
+fn minimum_cut_phase(edges: &[BTreeMap<usize, usize>], active: &[bool]) -> (usize, usize, usize) {
+    let mut connection = vec![0_usize; edges.len()];
+    let mut added = vec![false; edges.len()];
+    // Every node starts in the queue so that disconnected nodes still get added
+    let mut queue = (0..edges.len())
+        .filter(|&node| active[node])
+        .map(|node| (0, Reverse(node)))
+        .collect::<BinaryHeap<_>>();
+
+    let mut last = (usize::MAX, usize::MAX, 0);
+    while let Some((weight, Reverse(node))) = queue.pop() {
+        // Skip entries which have been superseded
+        if added[node] || weight != connection[node] {
+            continue;
+        }
+        added[node] = true;
+        last = (last.1, node, weight);
+        for (&to, &edge_weight) in &edges[node] {
+            if !added[to] {
+                connection[to] += edge_weight;
+                queue.push((connection[to], Reverse(to)));
+            }
+        }
+    }
+    last
+}
+
+/// Implements Karger's Algorithm
+/// https://en.wikipedia.org/wiki/Karger%27s_algorithm
+/// This only finds a minimum cut some of the time, so it should be repeated (see [karger_minimum_cut])
+/// Returns None if the graph has fewer than two nodes
+pub fn kargers_algorithm(graph: &Graph, random: &mut impl Rng) -> Option<Cut> {
+    if graph.nodes.len() < 2 {
+        return None;
+    }
+    // Work on a copy with ordered nodes and edges so that the same random numbers always pick the same edge
+    let mut nodes = graph.nodes.iter()
+        .map(|(&id, node)| (id, node.edges.iter().map(|(&to, &weight)| (to, weight)).collect::<BTreeMap<_, _>>()))
+        .collect::<BTreeMap<_, _>>();
+
+    // Used to remember which of the initial nodes got merged into this node
+    let mut partition_memory = BTreeMap::<usize, Vec<usize>>::new();
+    for node in nodes.keys() {
+        partition_memory.insert(*node, vec![*node]);
+    }
+
+    // Until we've reduced the graph to just two nodes
+    while nodes.len() > 2 {
+        // Picking a random edge (note - this is not uniform as each node is picked separately)
+        // This makes the algorithm less optimal, but it still works
+        // Only nodes with edges can be picked, if there are none left the graph is already disconnected
+        let Some(s) = nodes.iter()
+            .filter(|(_, edges)| !edges.is_empty())
+            .map(|(s, _)| *s)
+            .choose(random) else {
+            break;
+        };
+        let t = *nodes[&s].keys().choose(random).unwrap();
+
+        // Combine t's partition into s's partition
+        let t_partition = partition_memory.remove(&t).unwrap();
+        partition_memory.get_mut(&s).unwrap().extend(t_partition);
+
+        // Collapse the edges of t into s
+        let t_edges = nodes.remove(&t).unwrap();
+        for (to, weight) in t_edges {
+            // Remove edges to this node from all other nodes
+            nodes.get_mut(&to).unwrap().remove(&t);
+
+            // Add this edge's weight to s (or move it over otherwise)
+            if to != s {
+                *nodes.get_mut(&s).unwrap().entry(to).or_default() += weight;
+                *nodes.get_mut(&to).unwrap().entry(s).or_default() += weight;
+            }
+        }
+    }
+
+    // Either of the remaining partitions makes a cut
+    let (_, partition) = partition_memory.pop_first().unwrap();
+    Some(Cut::new(graph, &partition))
+}
+
+/// Repeat Karger's Algorithm and keep the smallest cut found
+/// Returns None if the graph has fewer than two nodes
+pub fn karger_minimum_cut(graph: &Graph, attempts: usize, random: &mut impl Rng) -> Option<Cut> {
+    (0..attempts)
+        .filter_map(|_| kargers_algorithm(graph, random))
+        .min_by_key(|cut| cut.weight)
+}
+
+/// A wire between two named components
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Wire {
+    pub from: String,
+    pub to: String,
+}
+
+impl Display for Wire {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.from, self.to)
+    }
+}
+
+/// The result of splitting a wiring diagram into pieces
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Split {
+    /// The wires which need to be cut, each with its ends in alphabetical order
+    pub wires: Vec<Wire>,
+    /// The names of the components in each of the resulting pieces
+    pub components: Vec<Vec<String>>,
+}
+
+/// Split a wiring diagram into at least the given number of pieces by cutting at most `max_cut` wires
+/// Pieces which are already disconnected count without cutting anything. Otherwise, this repeatedly
+/// splits whichever piece has the smallest minimum cut, which is not guaranteed to find the fewest
+/// wires for more than two pieces (finding that in general is much harder)
+/// Returns None if the pieces can't be made by cutting few enough wires
+pub fn split_wiring(graph: &Graph, pieces: usize, max_cut: usize) -> Option<Split> {
+    split_wiring_with(graph, pieces, max_cut, minimum_cut)
+}
+
+/// Split a wiring diagram like [split_wiring] but using another way of finding the minimum cut of
+/// each piece (such as [karger_minimum_cut])
+pub fn split_wiring_with(graph: &Graph, pieces: usize, max_cut: usize, mut find_cut: impl FnMut(&Graph) -> Option<Cut>) -> Option<Split> {
+    // Remember the minimum cut of each piece so that it's only found once
+    let mut split = graph.connected_components()
+        .into_iter()
+        .map(|piece| {
+            let cut = find_cut(&graph.subgraph(&piece));
+            (piece, cut)
+        })
+        .collect::<Vec<_>>();
+
+    let mut weight = 0;
+    let mut edges = vec![];
+    while split.len() < pieces {
+        let (index, _) = split.iter()
+            .enumerate()
+            .filter_map(|(index, (_, cut))| Some((index, cut.as_ref()?.weight)))
+            .min_by_key(|&(_, weight)| weight)?;
+        let Cut { weight: cut_weight, edges: cut_edges, partitions: [first, second] } = split[index].1.take().unwrap();
+        weight += cut_weight;
+        if weight > max_cut {
+            return None;
+        }
+        edges.extend(cut_edges);
+        for piece in [first, second] {
+            let cut = find_cut(&graph.subgraph(&piece));
+            split.push((piece, cut));
+        }
+        split.swap_remove(index);
+    }
+
+    let mut wires = edges.into_iter()
+        .map(|(from, to)| {
+            let (from, to) = (graph.nodes[&from].name.clone(), graph.nodes[&to].name.clone());
+            if from < to { Wire { from, to } } else { Wire { from: to, to: from } }
+        })
+        .collect::<Vec<_>>();
+    wires.sort_unstable();
+    let mut components = split.into_iter()
+        .map(|(piece, _)| {
+            let mut names = piece.into_iter().map(|node| graph.nodes[&node].name.clone()).collect::<Vec<_>>();
+            names.sort_unstable();
+            names
+        })
+        .collect::<Vec<_>>();
+    components.sort_unstable();
+
+    Some(Split { wires, components })
+}
+
+/// Solve part1
+pub fn get_division_product(graph: &Graph) -> usize {
+    // This is asking us to find a minimum cut of the input graph
+    let cut = minimum_cut(graph).expect("The wiring diagram should have at least two components");
+
+    // Take the product of the size of both partitions
+    cut.partitions.iter().map(Vec::len).product()
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = r"jqt: rhn xhk nvd
+rsh: frs pzl lsr
+xhk: hfx
+cmg: qnr nvd lhk bvb
+rhn: xhk bvb hfx
+bvb: xhk hfx
+pzl: lsr hfx nvd
+qnr: nvd
+ntq: jqt hfx bvb xhk
+nvd: lhk
+lsr: lhk
+rzs: qnr cmg lsr rsh
+frs: qnr lhk lsr";
+
+#[test]
+fn test_part1() {
+    assert_eq!(54, get_division_product(&preprocess(EXAMPLE.lines()).unwrap()));
+}
+
+#[test]
+fn test_minimum_cut() {
+    let graph = preprocess(EXAMPLE.lines()).unwrap();
+    let cut = minimum_cut(&graph).unwrap();
+    assert_eq!(3, cut.weight);
+    assert_eq!(3, cut.edges.len());
+    assert_eq!(vec![6, 9], cut.partitions.iter().map(Vec::len).collect::<Vec<_>>());
+    // Always the same cut
+    assert_eq!(Some(cut), minimum_cut(&graph));
+
+    // A graph which is already in two pieces doesn't need anything cut
+    let cut = minimum_cut(&preprocess("a: b\nc: d".lines()).unwrap()).unwrap();
+    assert_eq!(0, cut.weight);
+    assert!(cut.edges.is_empty());
+    assert_eq!([vec![0, 1], vec![2, 3]], cut.partitions);
+
+    assert_eq!(None, minimum_cut(&preprocess("".lines()).unwrap()));
+}
+
+#[test]
+fn test_split_wiring() {
+    let graph = preprocess(EXAMPLE.lines()).unwrap();
+    let split = split_wiring(&graph, 2, 3).unwrap();
+    assert_eq!(
+        vec!["bvb/cmg", "hfx/pzl", "jqt/nvd"],
+        split.wires.iter().map(Wire::to_string).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec![
+            vec!["bvb", "hfx", "jqt", "ntq", "rhn", "xhk"],
+            vec!["cmg", "frs", "lhk", "lsr", "nvd", "pzl", "qnr", "rsh", "rzs"],
+        ],
+        split.components
+    );
+    assert_eq!(None, split_wiring(&graph, 2, 2));
+
+    // Three pieces needs more wires to be cut
+    let split = split_wiring(&graph, 3, usize::MAX).unwrap();
+    assert_eq!(3, split.components.len());
+    assert_eq!(15, split.components.iter().map(Vec::len).sum::<usize>());
+    assert_eq!(None, split_wiring(&graph, 3, split.wires.len() - 1));
+    assert_eq!(None, split_wiring(&graph, 16, usize::MAX));
+
+    // Pieces which are already apart don't need anything cut
+    let graph = preprocess("a: b c\nb: c\nd: e".lines()).unwrap();
+    let split = split_wiring(&graph, 2, 0).unwrap();
+    assert!(split.wires.is_empty());
+    assert_eq!(vec![vec!["a", "b", "c"], vec!["d", "e"]], split.components);
+    // Cutting the single wire is cheaper than splitting the triangle
+    let split = split_wiring(&graph, 3, 1).unwrap();
+    assert_eq!(vec![Wire { from: "d".into(), to: "e".into() }], split.wires);
+    assert_eq!(vec![vec!["a", "b", "c"], vec!["d"], vec!["e"]], split.components);
+}
+
+#[test]
+fn test_karger_minimum_cut() {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    let graph = preprocess(EXAMPLE.lines()).unwrap();
+    let cut = karger_minimum_cut(&graph, 100, &mut StdRng::seed_from_u64(2023)).unwrap();
+    assert_eq!(minimum_cut(&graph), Some(cut));
+
+    // The same seed always finds the same cut (even when the graph's hash maps are in a different order)
+    let other = preprocess(EXAMPLE.lines()).unwrap();
+    for seed in 0..20 {
+        let cut = kargers_algorithm(&graph, &mut StdRng::seed_from_u64(seed));
+        assert_eq!(cut, kargers_algorithm(&other, &mut StdRng::seed_from_u64(seed)));
+    }
+
+    let mut random = StdRng::seed_from_u64(2023);
+    let split = split_wiring_with(&graph, 2, 3, |graph| karger_minimum_cut(graph, 100, &mut random));
+    assert_eq!(split_wiring(&graph, 2, 3), split);
+}
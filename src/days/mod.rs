@@ -0,0 +1,63 @@
+//! The solutions for each day
+
+use crate::Day;
+
+pub mod day01_trebuchet;
+pub mod day02_cube_conundrum;
+pub mod day03_gear_ratios;
+pub mod day04_scratchcards;
+pub mod day05_fertilizer;
+pub mod day06_wait;
+pub mod day07_camel;
+pub mod day08_haunted;
+pub mod day09_mirage;
+pub mod day10_pipe;
+pub mod day11_cosmic;
+pub mod day12_springs;
+pub mod day13_incidence;
+pub mod day14_parabolic;
+pub mod day15_lens;
+pub mod day16_lava;
+pub mod day17_crucible;
+pub mod day18_lavaduct;
+pub mod day19_aplenty;
+pub mod day20_pulse;
+pub mod day21_step_counter;
+pub mod day22_slabs;
+pub mod day23_long;
+pub mod day24_odds;
+pub mod day25_snowverland;
+
+/// Every day's solution in order
+pub const ALL: [Day; 25] = [
+    Day::of::<day01_trebuchet::Trebuchet>(),
+    Day::of::<day02_cube_conundrum::CubeConundrum>(),
+    Day::of::<day03_gear_ratios::GearRatios>(),
+    Day::of::<day04_scratchcards::Scratchcards>(),
+    Day::of::<day05_fertilizer::Fertilizer>(),
+    Day::of::<day06_wait::WaitForIt>(),
+    Day::of::<day07_camel::CamelCards>(),
+    Day::of::<day08_haunted::HauntedWasteland>(),
+    Day::of::<day09_mirage::MirageMaintenance>(),
+    Day::of::<day10_pipe::PipeMaze>(),
+    Day::of::<day11_cosmic::CosmicExpansion>(),
+    Day::of::<day12_springs::HotSprings>(),
+    Day::of::<day13_incidence::PointOfIncidence>(),
+    Day::of::<day14_parabolic::ParabolicReflectorDish>(),
+    Day::of::<day15_lens::LensLibrary>(),
+    Day::of::<day16_lava::FloorWillBeLava>(),
+    Day::of::<day17_crucible::ClumsyCrucible>(),
+    Day::of::<day18_lavaduct::LavaductLagoon>(),
+    Day::of::<day19_aplenty::Aplenty>(),
+    Day::of::<day20_pulse::PulsePropagation>(),
+    Day::of::<day21_step_counter::StepCounter>(),
+    Day::of::<day22_slabs::SandSlabs>(),
+    Day::of::<day23_long::ALongWalk>(),
+    Day::of::<day24_odds::NeverTellMeTheOdds>(),
+    Day::of::<day25_snowverland::Snowverload>(),
+];
+
+/// Get the solution for a day of the advent calendar
+pub fn get(day: u8) -> Option<&'static Day> {
+    ALL.iter().find(|solution| solution.day == day)
+}
@@ -0,0 +1,348 @@
+//! A rectangular grid of tiles shared by the days whose input is a map
+//!
+//! Rows are numbered from the top and columns from the left, both starting at 0
+
+use std::ops::{Index, IndexMut};
+
+use crate::parse::{self, ParseError};
+
+/// A position in a grid
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Position {
+    pub row: usize,
+    pub column: usize,
+}
+
+impl Position {
+    /// Create a position from its row and column
+    pub const fn new(row: usize, column: usize) -> Self {
+        Position { row, column }
+    }
+
+    /// Move this position by an offset
+    /// Returns None if that would go past the top or left edge of a grid
+    pub fn offset(self, rows: isize, columns: isize) -> Option<Self> {
+        Some(Position {
+            row: self.row.checked_add_signed(rows)?,
+            column: self.column.checked_add_signed(columns)?,
+        })
+    }
+
+    /// Move this position one step in a direction
+    /// Returns None if that would go past the top or left edge of a grid
+    pub fn step(self, direction: Direction) -> Option<Self> {
+        let (rows, columns) = direction.offset();
+        self.offset(rows, columns)
+    }
+
+    /// The manhattan distance to another position
+    pub fn distance_to(self, other: Self) -> usize {
+        self.row.abs_diff(other.row) + self.column.abs_diff(other.column)
+    }
+}
+
+/// One of the four directions which can be moved in on a grid
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Every direction (clockwise from up)
+    pub const ALL: [Direction; 4] = [Direction::Up, Direction::Right, Direction::Down, Direction::Left];
+
+    /// The change in row and column when stepping in this direction
+    pub const fn offset(self) -> (isize, isize) {
+        match self {
+            Direction::Up => (-1, 0),
+            Direction::Right => (0, 1),
+            Direction::Down => (1, 0),
+            Direction::Left => (0, -1),
+        }
+    }
+
+    /// Get the direction pointing the other way
+    pub const fn opposite(self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Right => Direction::Left,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+        }
+    }
+
+    /// Turn the direction left
+    pub const fn turn_left(self) -> Self {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Right => Direction::Up,
+            Direction::Down => Direction::Right,
+            Direction::Left => Direction::Down,
+        }
+    }
+
+    /// Turn the direction right
+    pub const fn turn_right(self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    /// Check if this direction moves between rows (rather than between columns)
+    pub const fn is_vertical(self) -> bool {
+        matches!(self, Direction::Up | Direction::Down)
+    }
+}
+
+/// A rectangular grid of tiles
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    /// The tiles in row-major order
+    tiles: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Create a grid from its tiles in row-major order
+    pub fn new(width: usize, height: usize, tiles: Vec<T>) -> Self {
+        assert_eq!(width * height, tiles.len(), "A {width}x{height} grid needs {} tiles", width * height);
+        Grid { width, height, tiles }
+    }
+
+    /// Create a grid with every tile set to the same value
+    pub fn filled(width: usize, height: usize, tile: T) -> Self where T: Clone {
+        Grid::new(width, height, vec![tile; width * height])
+    }
+
+    /// Create a grid from its rows
+    /// Every row must be the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        assert!(rows.iter().all(|row| row.len() == width), "Every row of a grid must be the same length");
+        Grid::new(width, height, rows.into_iter().flatten().collect())
+    }
+
+    /// Parse a grid where each character is converted into a tile
+    /// `expected` describes the characters which can be converted
+    pub fn parse<'a>(rows: impl Iterator<Item=&'a str>, expected: &str) -> Result<Self, ParseError> where T: TryFrom<char> {
+        Grid::parse_with(rows, expected, |char| T::try_from(char).ok())
+    }
+
+    /// Parse a grid where `tile` converts each character into a tile
+    /// `expected` describes the characters which `tile` accepts
+    pub fn parse_with<'a>(rows: impl Iterator<Item=&'a str>, expected: &str, tile: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError> {
+        parse::grid(rows, expected, tile).map(Grid::from_rows)
+    }
+
+    /// The number of columns
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The number of rows
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Check if a position is inside the grid
+    pub fn contains(&self, position: Position) -> bool {
+        position.row < self.height && position.column < self.width
+    }
+
+    /// Get the tile at a position (or None if the position is outside the grid)
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.contains(position).then(|| &self.tiles[position.row * self.width + position.column])
+    }
+
+    /// Get the tile at a position mutably (or None if the position is outside the grid)
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.contains(position).then(|| &mut self.tiles[position.row * self.width + position.column])
+    }
+
+    /// Move a position one step in a direction
+    /// Returns None if that would leave the grid
+    pub fn step(&self, position: Position, direction: Direction) -> Option<Position> {
+        position.step(direction).filter(|&position| self.contains(position))
+    }
+
+    /// Get the position in this grid which a position on an infinite tiling of this grid lands on
+    pub fn wrap(&self, row: isize, column: isize) -> Position {
+        Position {
+            row: row.rem_euclid(self.height as isize) as usize,
+            column: column.rem_euclid(self.width as isize) as usize,
+        }
+    }
+
+    /// Get a tile as if this grid was repeated infinitely in every direction
+    pub fn get_wrapping(&self, row: isize, column: isize) -> &T {
+        &self[self.wrap(row, column)]
+    }
+
+    /// Get the (up to four) positions next to a position along with the direction to each of them
+    pub fn neighbors(&self, position: Position) -> impl Iterator<Item=(Direction, Position)> + '_ {
+        Direction::ALL.into_iter()
+            .filter_map(move |direction| Some((direction, self.step(position, direction)?)))
+    }
+
+    /// Get the (up to eight) positions next to or diagonal to a position
+    pub fn neighbors8(&self, position: Position) -> impl Iterator<Item=Position> + '_ {
+        (-1..=1).flat_map(|rows| (-1..=1).map(move |columns| (rows, columns)))
+            .filter(|&offset| offset != (0, 0))
+            .filter_map(move |(rows, columns)| position.offset(rows, columns))
+            .filter(|&position| self.contains(position))
+    }
+
+    /// Iterate over every position in row-major order
+    pub fn positions(&self) -> impl Iterator<Item=Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |column| Position { row, column }))
+    }
+
+    /// Iterate over every tile along with its position in row-major order
+    pub fn iter(&self) -> impl Iterator<Item=(Position, &T)> {
+        self.positions().zip(self.tiles.iter())
+    }
+
+    /// Find the position of the first tile (in row-major order) which matches a predicate
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Position> {
+        self.iter().find(|(_, tile)| predicate(tile)).map(|(position, _)| position)
+    }
+
+    /// Get a row
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.tiles[(row * self.width)..((row + 1) * self.width)]
+    }
+
+    /// Iterate over the rows from top to bottom
+    pub fn rows(&self) -> impl Iterator<Item=&[T]> {
+        // chunks would panic on an empty grid
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    /// Iterate over a column from top to bottom
+    pub fn column(&self, column: usize) -> impl Iterator<Item=&T> {
+        assert!(column < self.width, "Column {column} is outside of the grid");
+        self.tiles.iter().skip(column).step_by(self.width)
+    }
+
+    /// Iterate over the columns from left to right
+    pub fn columns(&self) -> impl Iterator<Item=impl Iterator<Item=&T>> {
+        (0..self.width).map(|column| self.column(column))
+    }
+
+    /// Convert every tile
+    pub fn map<U>(&self, tile: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.tiles.iter().map(tile).collect())
+    }
+
+    /// Create a grid whose tiles are looked up from the positions in this grid
+    fn rearranged(&self, width: usize, height: usize, source: impl Fn(Position) -> Position) -> Self where T: Clone {
+        let tiles = (0..height)
+            .flat_map(|row| (0..width).map(move |column| Position { row, column }))
+            .map(|position| self[source(position)].clone())
+            .collect();
+        Grid::new(width, height, tiles)
+    }
+
+    /// Swap the rows and columns
+    pub fn transpose(&self) -> Self where T: Clone {
+        self.rearranged(self.height, self.width, |position| Position::new(position.column, position.row))
+    }
+
+    /// Rotate the grid a quarter turn clockwise
+    pub fn rotate_clockwise(&self) -> Self where T: Clone {
+        let height = self.height;
+        self.rearranged(self.height, self.width, |position| Position::new(height - 1 - position.column, position.row))
+    }
+
+    /// Rotate the grid a quarter turn counterclockwise
+    pub fn rotate_counterclockwise(&self) -> Self where T: Clone {
+        let width = self.width;
+        self.rearranged(self.height, self.width, |position| Position::new(position.column, width - 1 - position.row))
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &Self::Output {
+        self.get(position)
+            .unwrap_or_else(|| panic!("{position:?} is outside of a {}x{} grid", self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{position:?} is outside of a {width}x{height} grid"))
+    }
+}
+
+#[cfg(test)]
+fn example() -> Grid<char> {
+    Grid::parse("abc\ndef".lines(), "a letter").unwrap()
+}
+
+#[test]
+fn test_parse() {
+    let grid = example();
+    assert_eq!((3, 2), (grid.width(), grid.height()));
+    assert_eq!('f', grid[Position::new(1, 2)]);
+    assert_eq!(None, grid.get(Position::new(2, 0)));
+    assert_eq!(
+        Err(ParseError::at(1, 1, "x", "a digit")),
+        Grid::<u32>::parse_with("12\n3x".lines(), "a digit", |char| char.to_digit(10))
+    );
+}
+
+#[test]
+fn test_rows_and_columns() {
+    let grid = example();
+    assert_eq!(vec![&['a', 'b', 'c'][..], &['d', 'e', 'f'][..]], grid.rows().collect::<Vec<_>>());
+    assert_eq!(
+        vec![vec!['a', 'd'], vec!['b', 'e'], vec!['c', 'f']],
+        grid.columns().map(|column| column.copied().collect::<Vec<_>>()).collect::<Vec<_>>()
+    );
+    assert_eq!(Some(Position::new(1, 0)), grid.find(|&tile| tile == 'd'));
+}
+
+#[test]
+fn test_neighbors() {
+    let grid = example();
+    assert_eq!(
+        vec![(Direction::Right, Position::new(0, 1)), (Direction::Down, Position::new(1, 0))],
+        grid.neighbors(Position::new(0, 0)).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec![Position::new(0, 0), Position::new(0, 2), Position::new(1, 0), Position::new(1, 2)],
+        grid.neighbors8(Position::new(0, 1)).filter(|position| position.column != 1).collect::<Vec<_>>()
+    );
+    assert_eq!(5, grid.neighbors8(Position::new(0, 1)).count());
+    assert_eq!(None, grid.step(Position::new(1, 2), Direction::Right));
+}
+
+#[test]
+fn test_wrapping() {
+    let grid = example();
+    assert_eq!('f', *grid.get_wrapping(-1, -1));
+    assert_eq!('a', *grid.get_wrapping(4, 6));
+    assert_eq!(Position::new(1, 0), grid.wrap(3, -3));
+}
+
+#[test]
+fn test_transformations() {
+    let grid = example();
+    assert_eq!(Grid::parse("ad\nbe\ncf".lines(), "a letter").unwrap(), grid.transpose());
+    assert_eq!(Grid::parse("da\neb\nfc".lines(), "a letter").unwrap(), grid.rotate_clockwise());
+    assert_eq!(Grid::parse("cf\nbe\nad".lines(), "a letter").unwrap(), grid.rotate_counterclockwise());
+    assert_eq!(grid, grid.rotate_clockwise().rotate_counterclockwise());
+}
@@ -0,0 +1,136 @@
+//! My solutions to Advent of Code 2023 packaged as a library
+//!
+//! Every day lives in its own module under [`days`] and exposes a type implementing [`Solution`]
+//! so that the solvers can be called directly instead of going through the `aoc` binary
+
+use std::fmt::{Display, Formatter};
+use std::panic;
+use std::panic::AssertUnwindSafe;
+use std::path::PathBuf;
+
+use crate::bench::Samples;
+use crate::parse::ParseError;
+
+pub mod answers;
+pub mod bench;
+pub mod days;
+pub mod grid;
+pub mod math;
+pub mod parse;
+pub mod polygon;
+pub mod random;
+pub mod search;
+
+/// The interface shared by the solutions for each day
+pub trait Solution {
+    /// The day of the advent calendar (1 through 25)
+    const DAY: u8;
+    /// The title of the puzzle
+    const TITLE: &'static str;
+    /// The path to my puzzle input (relative to the root of the repository)
+    const INPUT: &'static str;
+
+    /// The preprocessed form of the input which is shared by both parts
+    type Input<'a>;
+    /// The answer to part 1
+    type Part1: Display;
+    /// The answer to part 2
+    type Part2: Display;
+
+    /// Preprocess the raw puzzle input into a more useful form
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+
+    /// Solve part 1
+    fn part1(input: &Self::Input<'_>) -> Self::Part1;
+
+    /// Solve part 2
+    fn part2(input: &Self::Input<'_>) -> Self::Part2;
+}
+
+/// The answer for a part which doesn't have a puzzle (i.e. the second part of day 25)
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct NoAnswer;
+
+impl Display for NoAnswer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "-")
+    }
+}
+
+/// One of the two parts of a puzzle
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    /// Both parts in order
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// The answers to the requested parts of a day (None if solving that part failed)
+pub type Answers = Vec<Option<String>>;
+
+/// A [`Solution`] with its types erased so that the day to run can be picked at runtime
+#[derive(Debug, Copy, Clone)]
+pub struct Day {
+    /// The day of the advent calendar (1 through 25)
+    pub day: u8,
+    /// The title of the puzzle
+    pub title: &'static str,
+    /// The path to my puzzle input (relative to the root of the repository)
+    pub input: &'static str,
+    solve: fn(&str, &[Part]) -> Result<Answers, ParseError>,
+    time: fn(&str, &[Part], usize) -> Result<Samples, ParseError>,
+}
+
+impl Day {
+    /// Erase the types of a solution
+    pub const fn of<S: Solution>() -> Self {
+        Day {
+            day: S::DAY,
+            title: S::TITLE,
+            input: S::INPUT,
+            solve: solve::<S>,
+            time: bench::time::<S>,
+        }
+    }
+
+    /// The path to my puzzle input for this day
+    /// This doesn't depend on the current working directory
+    pub fn input_path(&self) -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(self.input)
+    }
+
+    /// Parse the input once and then solve each of the requested parts
+    /// A part's answer is None if solving it panicked
+    pub fn solve(&self, input: &str, parts: &[Part]) -> Result<Answers, ParseError> {
+        (self.solve)(input, parts)
+    }
+
+    /// Time parsing the input and each of the requested parts over a number of iterations
+    pub fn time(&self, input: &str, parts: &[Part], iterations: usize) -> Result<Samples, ParseError> {
+        (self.time)(input, parts, iterations)
+    }
+}
+
+/// Solve the requested parts of a day, catching any panics so that the other parts can still run
+fn solve<S: Solution>(input: &str, parts: &[Part]) -> Result<Answers, ParseError> {
+    let parsed = S::parse(input)?;
+    Ok(parts.iter()
+        .map(|part| panic::catch_unwind(AssertUnwindSafe(|| match part {
+            Part::One => S::part1(&parsed).to_string(),
+            Part::Two => S::part2(&parsed).to_string(),
+        })).ok())
+        .collect())
+}
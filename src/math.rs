@@ -0,0 +1,121 @@
+//! Number theory for the days which need to line up several repeating cycles
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use num::Integer;
+
+/// The set of numbers `x` where `x ≡ residue (mod modulus)`
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Congruence {
+    pub residue: u64,
+    pub modulus: u64,
+}
+
+impl Congruence {
+    /// Every number (this is the identity when combining congruences)
+    pub const ANY: Congruence = Congruence { residue: 0, modulus: 1 };
+
+    /// Create a congruence, reducing the residue by the modulus
+    pub fn new(residue: u64, modulus: u64) -> Self {
+        assert!(modulus > 0, "The modulus of a congruence must be positive");
+        Congruence { residue: residue % modulus, modulus }
+    }
+
+    /// Find the numbers which satisfy both congruences using the generalized Chinese Remainder Theorem
+    /// The moduli don't need to be coprime
+    pub fn combine(self, other: Self) -> Result<Self, CongruenceError> {
+        // We want x = a + m * k where a + m * k ≡ b (mod n)
+        // i.e. m * k ≡ b - a (mod n), which is only solvable if gcd(m, n) divides b - a
+        let (a, m) = (self.residue as i128, self.modulus as i128);
+        let (b, n) = (other.residue as i128, other.modulus as i128);
+        let gcd = m.extended_gcd(&n);
+        let difference = b - a;
+        if difference % gcd.gcd != 0 {
+            return Err(CongruenceError::NoSolution);
+        }
+        // m * gcd.x ≡ gcd.gcd (mod n) so k = (b - a) / gcd * gcd.x is a solution (mod n / gcd)
+        // Both factors are reduced below n, which fits in a u64, so only the product can overflow
+        let reduced = n / gcd.gcd;
+        let k = (difference / gcd.gcd % reduced).checked_mul(gcd.x % reduced)
+            .ok_or(CongruenceError::Overflow)?
+            .rem_euclid(reduced);
+        let modulus = m.checked_mul(reduced).ok_or(CongruenceError::Overflow)?;
+        let residue = m.checked_mul(k)
+            .and_then(|offset| a.checked_add(offset))
+            .ok_or(CongruenceError::Overflow)?
+            .rem_euclid(modulus);
+        Ok(Congruence {
+            residue: u64::try_from(residue).map_err(|_| CongruenceError::Overflow)?,
+            modulus: u64::try_from(modulus).map_err(|_| CongruenceError::Overflow)?,
+        })
+    }
+
+    /// Find the smallest number which is at least `minimum` and satisfies this congruence
+    /// Returns None if that number doesn't fit in a u64
+    pub fn smallest_from(self, minimum: u64) -> Option<u64> {
+        if self.residue >= minimum {
+            Some(self.residue)
+        } else {
+            (minimum - self.residue).div_ceil(self.modulus)
+                .checked_mul(self.modulus)?
+                .checked_add(self.residue)
+        }
+    }
+}
+
+/// Why a system of congruences couldn't be solved
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum CongruenceError {
+    /// No number satisfies every congruence
+    NoSolution,
+    /// The combined modulus (or an intermediate value) doesn't fit
+    Overflow,
+}
+
+impl Display for CongruenceError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CongruenceError::NoSolution => write!(f, "no number satisfies every congruence"),
+            CongruenceError::Overflow => write!(f, "the combined modulus is too large to represent"),
+        }
+    }
+}
+
+impl Error for CongruenceError {}
+
+/// Find the numbers which satisfy every congruence
+pub fn solve_congruences(congruences: impl IntoIterator<Item=Congruence>) -> Result<Congruence, CongruenceError> {
+    congruences.into_iter().try_fold(Congruence::ANY, Congruence::combine)
+}
+
+#[test]
+fn test_combine() {
+    // The classic example: x ≡ 2 (mod 3), x ≡ 3 (mod 5), x ≡ 2 (mod 7)
+    assert_eq!(
+        Ok(Congruence::new(23, 105)),
+        solve_congruences([Congruence::new(2, 3), Congruence::new(3, 5), Congruence::new(2, 7)])
+    );
+    // Moduli which share a factor
+    assert_eq!(Ok(Congruence::new(10, 12)), Congruence::new(4, 6).combine(Congruence::new(2, 4)));
+    assert_eq!(Err(CongruenceError::NoSolution), Congruence::new(1, 6).combine(Congruence::new(2, 4)));
+    assert_eq!(Ok(Congruence::ANY), solve_congruences([]));
+}
+
+#[test]
+fn test_combine_overflow() {
+    // Two large coprime moduli whose product doesn't fit in a u64
+    let first = Congruence::new(1, u64::MAX);
+    let second = Congruence::new(2, u64::MAX - 1);
+    assert_eq!(Err(CongruenceError::Overflow), first.combine(second));
+    // Large moduli which are fine as long as their lcm fits
+    assert_eq!(Ok(Congruence::new(5, u64::MAX)), Congruence::new(5, u64::MAX).combine(Congruence::new(2, 3)));
+}
+
+#[test]
+fn test_smallest_from() {
+    assert_eq!(Some(23), Congruence::new(23, 105).smallest_from(0));
+    assert_eq!(Some(23), Congruence::new(23, 105).smallest_from(23));
+    assert_eq!(Some(128), Congruence::new(23, 105).smallest_from(24));
+    assert_eq!(None, Congruence::new(0, u64::MAX / 2 + 1).smallest_from(u64::MAX));
+}
@@ -0,0 +1,160 @@
+//! Errors and helpers for parsing puzzle inputs
+//!
+//! Every day's parser reports malformed input as a [`ParseError`] pointing at the offending token
+//! instead of panicking
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use regex::{Captures, Regex};
+
+/// An error produced when a puzzle input is malformed
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ParseError {
+    /// The line the error is on (starting at 1)
+    pub line: usize,
+    /// The column of the start of the offending token (starting at 1)
+    pub column: usize,
+    /// The offending token (empty if something was missing)
+    pub token: String,
+    /// A description of what was expected instead
+    pub expected: String,
+}
+
+impl ParseError {
+    /// Create an error for a token which starts at the (0-indexed) `line` and `column`
+    pub fn at(line: usize, column: usize, token: impl Into<String>, expected: impl Into<String>) -> Self {
+        ParseError {
+            line: line + 1,
+            column: column + 1,
+            token: token.into(),
+            expected: expected.into(),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.token.is_empty() {
+            write!(f, "line {}, column {}: expected {}", self.line, self.column, self.expected)
+        } else {
+            write!(f, "line {}, column {}: unexpected `{}` (expected {})", self.line, self.column, self.token, self.expected)
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// A line of the puzzle input which remembers where it is so that errors can point into it
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Line<'a> {
+    /// The index of this line in the input (starting at 0)
+    pub index: usize,
+    /// The text of this line
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// Create a line from its (0-indexed) position in the input
+    pub fn new(index: usize, text: &'a str) -> Self {
+        Line { index, text }
+    }
+
+    /// Get the (0-indexed) column where a token starts
+    /// The token should be a slice of this line, otherwise it is assumed to be at the start
+    pub fn column_of(&self, token: &str) -> usize {
+        let offset = (token.as_ptr() as usize).wrapping_sub(self.text.as_ptr() as usize);
+        if offset <= self.text.len() {
+            self.text[..offset].chars().count()
+        } else {
+            0
+        }
+    }
+
+    /// Create an error pointing at a token which is a slice of this line
+    pub fn error(&self, token: &str, expected: impl Into<String>) -> ParseError {
+        ParseError::at(self.index, self.column_of(token), token, expected)
+    }
+
+    /// Create an error for something missing at the end of this line
+    pub fn missing(&self, expected: impl Into<String>) -> ParseError {
+        ParseError::at(self.index, self.text.chars().count(), "", expected)
+    }
+
+    /// Parse a token which is a slice of this line into a value
+    pub fn parse<T: FromStr>(&self, token: &str, expected: impl Into<String>) -> Result<T, ParseError> {
+        T::from_str(token.trim()).map_err(|_| self.error(token, expected))
+    }
+
+    /// Split a token which is a slice of this line around the first instance of a delimiter
+    pub fn split_once(&self, token: &'a str, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+        token.split_once(delimiter)
+            .ok_or_else(|| self.error(token, format!("`{delimiter}`")))
+    }
+
+    /// Match this line against a regex
+    pub fn captures(&self, regex: &Regex, expected: impl Into<String>) -> Result<Captures<'a>, ParseError> {
+        regex.captures(self.text)
+            .ok_or_else(|| self.error(self.text, expected))
+    }
+}
+
+/// Pair each line with its position in the input
+pub fn lines<'a>(lines: impl Iterator<Item=&'a str>) -> impl Iterator<Item=Line<'a>> {
+    lines.enumerate().map(|(index, text)| Line::new(index, text))
+}
+
+/// Parse a rectangular grid of characters where `cell` converts each character
+/// `expected` describes the characters which `cell` accepts
+pub fn grid<'a, T>(rows: impl Iterator<Item=&'a str>, expected: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Vec<Vec<T>>, ParseError> {
+    let mut width = None;
+    lines(rows).map(|line| {
+        let row = line.text.char_indices()
+            .enumerate()
+            .map(|(column, (offset, char))| {
+                if width.is_some_and(|width| column >= width) {
+                    Err(line.error(&line.text[offset..], format!("the end of the row (rows are {} wide)", width.unwrap_or(0))))
+                } else {
+                    cell(char).ok_or_else(|| ParseError::at(line.index, column, char, expected))
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let width = *width.get_or_insert(row.len());
+        if row.len() < width {
+            return Err(line.missing(format!("{} more characters (rows are {width} wide)", width - row.len())));
+        }
+        Ok(row)
+    }).collect()
+}
+
+#[test]
+fn test_error_position() {
+    let line = Line::new(2, "R 6 (#70c710)");
+    let (_, rest) = line.split_once(line.text, " ").unwrap();
+    let (distance, _) = line.split_once(rest, " ").unwrap();
+    assert_eq!(6, line.parse::<usize>(distance, "a distance").unwrap());
+    assert_eq!(
+        ParseError::at(2, 4, "(#70c710)", "a distance"),
+        line.parse::<usize>(&rest[2..], "a distance").unwrap_err()
+    );
+    assert_eq!(
+        "line 3, column 5: unexpected `(#70c710)` (expected a distance)",
+        line.parse::<usize>(&rest[2..], "a distance").unwrap_err().to_string()
+    );
+}
+
+#[test]
+fn test_grid() {
+    let digit = |char: char| char.to_digit(10);
+    assert_eq!(Ok(vec![vec![1, 2], vec![3, 4]]), grid("12\n34".lines(), "a digit", digit));
+    assert_eq!(Err(ParseError::at(1, 1, "x", "a digit")), grid("12\n3x".lines(), "a digit", digit));
+    assert_eq!(
+        Err(ParseError::at(1, 2, "56", "the end of the row (rows are 2 wide)")),
+        grid("12\n3456".lines(), "a digit", digit)
+    );
+    assert_eq!(
+        Err(ParseError::at(1, 1, "", "1 more characters (rows are 2 wide)")),
+        grid("12\n3".lines(), "a digit", digit)
+    );
+}
@@ -0,0 +1,292 @@
+//! Closed loops of horizontal and vertical edges through points of the integer lattice
+//!
+//! These use the same orientation as the grid: `x` grows to the right and `y` grows downwards. None
+//! of the measurements depend on which way around a loop goes.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::grid::{Direction, Position};
+
+/// A point of the integer lattice
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    /// Create a point
+    pub const fn new(x: i64, y: i64) -> Self {
+        Point { x, y }
+    }
+
+    /// Get the point which is `distance` steps away in a direction
+    pub fn step(self, direction: Direction, distance: i64) -> Self {
+        let (rows, columns) = direction.offset();
+        Point::new(self.x + columns as i64 * distance, self.y + rows as i64 * distance)
+    }
+}
+
+impl From<Position> for Point {
+    fn from(value: Position) -> Self {
+        Point::new(value.column as i64, value.row as i64)
+    }
+}
+
+/// Where a point is relative to a polygon
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Location {
+    Inside,
+    Boundary,
+    Outside,
+}
+
+/// A rectilinear polygon: a closed loop where every edge is horizontal or vertical
+///
+/// The loop may touch itself (which splits it into several lobes), either at a corner or by going
+/// back along part of itself, but its edges must not cross.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Polygon {
+    /// The corners in the order they are visited (the last one joins back up with the first)
+    vertices: Vec<Point>,
+}
+
+impl Polygon {
+    /// Create a polygon from its corners
+    /// Returns None if any of the edges (including the one closing the loop) are diagonal
+    pub fn new(vertices: Vec<Point>) -> Option<Self> {
+        let polygon = Polygon { vertices };
+        let rectilinear = polygon.edges().all(|(start, end)| start.x == end.x || start.y == end.y);
+        rectilinear.then_some(polygon)
+    }
+
+    /// Create a polygon by following a series of moves from a starting point
+    /// Returns None if the moves don't end up back at the start
+    pub fn trace(start: Point, moves: impl IntoIterator<Item=(Direction, i64)>) -> Option<Self> {
+        let mut vertices = vec![start];
+        let mut last = None;
+        for (direction, distance) in moves {
+            let point = vertices[vertices.len() - 1].step(direction, distance);
+            // Going straight on doesn't make a new corner
+            if last == Some(direction) && vertices.len() > 1 {
+                vertices.pop();
+            }
+            vertices.push(point);
+            last = Some(direction);
+        }
+        if vertices.pop() != Some(start) {
+            return None;
+        }
+        Some(Polygon { vertices })
+    }
+
+    /// Get the corners in the order they are visited
+    pub fn vertices(&self) -> &[Point] {
+        &self.vertices
+    }
+
+    /// Iterate on the edges as pairs of their ends
+    pub fn edges(&self) -> impl Iterator<Item=(Point, Point)> + '_ {
+        self.vertices.iter()
+            .copied()
+            .zip(self.vertices.iter().copied().cycle().skip(1))
+    }
+
+    /// Get twice the signed area using the shoelace formula
+    /// This is positive when the loop goes clockwise (as `y` grows downwards) and negative otherwise
+    pub fn signed_double_area(&self) -> i64 {
+        self.edges()
+            .map(|(start, end)| start.x * end.y - end.x * start.y)
+            .sum()
+    }
+
+    /// Get the area enclosed by the loop
+    pub fn area(&self) -> u64 {
+        // A rectilinear polygon with integer corners always has an integer area
+        self.signed_double_area().unsigned_abs() / 2
+    }
+
+    /// Get the length of the loop
+    /// This is the number of lattice points visited by walking the loop (counting any point where
+    /// the loop touches itself once for each visit)
+    pub fn boundary_length(&self) -> u64 {
+        self.edges()
+            .map(|(start, end)| start.x.abs_diff(end.x) + start.y.abs_diff(end.y))
+            .sum()
+    }
+
+    /// Count the extra visits to lattice points where the loop touches itself
+    fn touches(&self) -> u64 {
+        self.boundary_length() - self.boundary_points()
+    }
+
+    /// Count the different lattice points which are on the loop
+    pub fn boundary_points(&self) -> u64 {
+        // Merge the edges on each row and column into runs so that shared stretches are only counted
+        // once, then take off the points on both a row and a column run (as the edges don't cross,
+        // these can only be corners)
+        let mut rows = HashMap::<i64, Vec<(i64, i64)>>::new();
+        let mut columns = HashMap::<i64, Vec<(i64, i64)>>::new();
+        for (start, end) in self.edges() {
+            if start.y == end.y {
+                rows.entry(start.y).or_default().push((start.x.min(end.x), start.x.max(end.x)));
+            } else {
+                columns.entry(start.x).or_default().push((start.y.min(end.y), start.y.max(end.y)));
+            }
+        }
+        let rows = rows.into_iter().map(|(y, runs)| (y, merge_runs(runs))).collect::<HashMap<_, _>>();
+        let columns = columns.into_iter().map(|(x, runs)| (x, merge_runs(runs))).collect::<HashMap<_, _>>();
+
+        let on_run = |runs: Option<&Vec<(i64, i64)>>, value: i64| runs.is_some_and(|runs| {
+            let index = runs.partition_point(|&(_, end)| end < value);
+            runs.get(index).is_some_and(|&(start, _)| start <= value)
+        });
+        let corners = self.vertices.iter()
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .filter(|vertex| on_run(rows.get(&vertex.y), vertex.x) && on_run(columns.get(&vertex.x), vertex.y))
+            .count() as u64;
+        let run_points = |runs: &HashMap<i64, Vec<(i64, i64)>>| runs.values()
+            .flatten()
+            .map(|(start, end)| start.abs_diff(*end) + 1)
+            .sum::<u64>();
+
+        run_points(&rows) + run_points(&columns) - corners
+    }
+
+    /// Count the lattice points strictly inside the loop
+    pub fn interior_points(&self) -> u64 {
+        // Pick's theorem gives A = I + B / 2 - 1 for each of the lobes, where the B of the lobes
+        // add up to the length of the loop and each place the loop touches itself adds a lobe
+        (self.area() + self.touches() + 1).saturating_sub(self.boundary_length() / 2)
+    }
+
+    /// Count the lattice points which are either inside the loop or on it
+    pub fn lattice_points(&self) -> u64 {
+        self.interior_points() + self.boundary_points()
+    }
+
+    /// Find out where a point is relative to the loop
+    pub fn locate(&self, point: Point) -> Location {
+        let on_edge = |(start, end): (Point, Point)| {
+            (start.x.min(end.x)..=start.x.max(end.x)).contains(&point.x)
+                && (start.y.min(end.y)..=start.y.max(end.y)).contains(&point.y)
+        };
+        if self.edges().any(on_edge) {
+            return Location::Boundary;
+        }
+
+        // Cast a ray to the right and count the vertical edges it crosses
+        // Treating the edges as half open means a ray through a corner is only counted when the
+        // loop actually crosses it
+        let crossings = self.edges()
+            .filter(|(start, end)| start.x == end.x && start.x > point.x)
+            .filter(|(start, end)| (start.y.min(end.y)..start.y.max(end.y)).contains(&point.y))
+            .count();
+        if crossings % 2 == 1 {
+            Location::Inside
+        } else {
+            Location::Outside
+        }
+    }
+}
+
+/// Merge overlapping (inclusive) runs into sorted runs which don't overlap
+fn merge_runs(mut runs: Vec<(i64, i64)>) -> Vec<(i64, i64)> {
+    runs.sort_unstable();
+    let mut merged: Vec<(i64, i64)> = vec![];
+    for (start, end) in runs {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+#[test]
+fn test_measurements() {
+    // A 3x3 square, with a point in the middle of each edge to check they get merged
+    let square = Polygon::trace(Point::new(0, 0), [
+        (Direction::Right, 1), (Direction::Right, 1),
+        (Direction::Down, 2),
+        (Direction::Left, 2),
+        (Direction::Up, 2),
+    ]).unwrap();
+    assert_eq!(4, square.vertices().len());
+    assert_eq!(8, square.signed_double_area());
+    assert_eq!(4, square.area());
+    assert_eq!(8, square.boundary_length());
+    assert_eq!(1, square.interior_points());
+    assert_eq!(9, square.lattice_points());
+
+    // Going the other way around only changes the sign
+    let reversed = Polygon::new(square.vertices().iter().rev().copied().collect()).unwrap();
+    assert_eq!(-8, reversed.signed_double_area());
+    assert_eq!(1, reversed.interior_points());
+
+    assert_eq!(None, Polygon::new(vec![Point::new(0, 0), Point::new(1, 1), Point::new(0, 1)]));
+    assert_eq!(None, Polygon::trace(Point::new(0, 0), [(Direction::Right, 1)]));
+}
+
+#[test]
+fn test_touching() {
+    // Two 3x3 squares which touch at a corner
+    let polygon = Polygon::new(vec![
+        Point::new(0, 0), Point::new(2, 0), Point::new(2, 2), Point::new(4, 2),
+        Point::new(4, 4), Point::new(2, 4), Point::new(2, 2), Point::new(0, 2),
+    ]).unwrap();
+    assert_eq!(8, polygon.area());
+    assert_eq!(16, polygon.boundary_length());
+    assert_eq!(15, polygon.boundary_points());
+    assert_eq!(2, polygon.interior_points());
+    assert_eq!(17, polygon.lattice_points());
+}
+
+#[test]
+fn test_locate() {
+    // An L shape
+    let polygon = Polygon::new(vec![
+        Point::new(0, 0), Point::new(2, 0), Point::new(2, 2),
+        Point::new(4, 2), Point::new(4, 4), Point::new(0, 4),
+    ]).unwrap();
+    assert_eq!(Location::Inside, polygon.locate(Point::new(1, 1)));
+    assert_eq!(Location::Inside, polygon.locate(Point::new(3, 3)));
+    assert_eq!(Location::Boundary, polygon.locate(Point::new(2, 1)));
+    assert_eq!(Location::Boundary, polygon.locate(Point::new(4, 4)));
+    // A ray through the corner at (2, 2) mustn't count it
+    assert_eq!(Location::Outside, polygon.locate(Point::new(3, 1)));
+    assert_eq!(Location::Outside, polygon.locate(Point::new(-1, 2)));
+    assert_eq!(Location::Outside, polygon.locate(Point::new(5, 2)));
+}
+
+#[test]
+fn test_doubling_back() {
+    // Count the points by hand to check against
+    fn count(polygon: &Polygon, location: Location) -> u64 {
+        (-1..=7).flat_map(|x| (-1..=5).map(move |y| Point::new(x, y)))
+            .filter(|&point| polygon.locate(point) == location)
+            .count() as u64
+    }
+
+    // A 5x3 rectangle with a spur going down from the middle of its bottom edge
+    let spur = Polygon::new(vec![
+        Point::new(0, 0), Point::new(4, 0), Point::new(4, 2), Point::new(2, 2),
+        Point::new(2, 4), Point::new(2, 2), Point::new(0, 2),
+    ]).unwrap();
+    // Two 3x3 squares joined by a corridor which meets the middle of the right square's side
+    let corridor = Polygon::new(vec![
+        Point::new(0, 0), Point::new(2, 0), Point::new(2, 1), Point::new(4, 1), Point::new(4, 0),
+        Point::new(6, 0), Point::new(6, 2), Point::new(4, 2), Point::new(4, 1), Point::new(2, 1),
+        Point::new(2, 2), Point::new(0, 2),
+    ]).unwrap();
+
+    assert_eq!(14, spur.boundary_points());
+    assert_eq!(3, spur.interior_points());
+    assert_eq!(17, corridor.boundary_points());
+    assert_eq!(2, corridor.interior_points());
+    for polygon in [spur, corridor] {
+        assert_eq!(count(&polygon, Location::Boundary), polygon.boundary_points());
+        assert_eq!(count(&polygon, Location::Inside), polygon.interior_points());
+    }
+}
@@ -0,0 +1,55 @@
+//! The source of randomness for the randomized algorithms
+//!
+//! Every run picks a single seed, either the one given to [`set_seed`] (the `--seed` argument of the
+//! `aoc` binary), the one in the `AOC_SEED` environment variable, or a fresh one. The seed is logged
+//! to stderr the first time it's used so that a slow or failing run can be repeated exactly.
+
+use std::env;
+use std::sync::{Once, OnceLock};
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+/// The environment variable which can hold the seed
+pub const SEED_VARIABLE: &str = "AOC_SEED";
+
+/// The seed for this run (once it has been picked)
+static SEED: OnceLock<u64> = OnceLock::new();
+/// Makes sure the seed is only logged once
+static LOGGED: Once = Once::new();
+
+/// Use this seed for the rest of the run
+/// Returns false if a seed had already been picked (in which case that one is kept)
+pub fn set_seed(seed: u64) -> bool {
+    SEED.set(seed).is_ok()
+}
+
+/// Get the seed for this run, picking one if it hasn't been yet
+pub fn seed() -> u64 {
+    let seed = *SEED.get_or_init(|| match env::var(SEED_VARIABLE) {
+        Ok(value) => value.trim().parse().unwrap_or_else(|error| {
+            eprintln!("Ignoring {SEED_VARIABLE}={value}: {error}");
+            rand::random()
+        }),
+        Err(_) => rand::random(),
+    });
+    LOGGED.call_once(|| eprintln!("Random seed: {seed} (set {SEED_VARIABLE}={seed} to repeat this run)"));
+    seed
+}
+
+/// Create a random number generator from the seed for this run
+/// Every generator starts from the same state, so an algorithm gives the same results each time it's run
+pub fn generator() -> StdRng {
+    StdRng::seed_from_u64(seed())
+}
+
+#[test]
+fn test_generator() {
+    use rand::Rng;
+
+    assert_eq!(generator().gen::<u64>(), generator().gen::<u64>());
+    // The seed can't change once it's been picked
+    let seed = seed();
+    assert!(!set_seed(seed.wrapping_add(1)));
+    assert_eq!(seed, self::seed());
+}
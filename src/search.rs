@@ -0,0 +1,152 @@
+//! Shortest path searches over an implicit graph of states
+//!
+//! The graph is never built up front: a search only needs the states to start from, a function
+//! giving the successors of a state along with the cost of moving to each of them, and a check for
+//! whether a state is a goal
+
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// The cheapest path found by a search
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Path<S, C> {
+    /// The total cost of the path
+    pub cost: C,
+    /// Every state along the path, from the start state to the goal state (inclusive)
+    pub states: Vec<S>,
+}
+
+/// A state which has been seen by a search
+struct Node<S, C> {
+    state: S,
+    /// The cheapest known cost to reach this state
+    cost: C,
+    /// The index of the state this one was reached from on the cheapest known path
+    parent: Option<usize>,
+    /// Whether the cheapest path to this state has been found
+    finalized: bool,
+}
+
+/// Find the cheapest path from any of the start states to a goal state using Dijkstra's algorithm
+/// Returns None if no goal state can be reached
+pub fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item=S>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+    where S: Clone + Eq + Hash,
+          C: Copy + Ord + Default + Add<Output=C>,
+          I: IntoIterator<Item=(S, C)> {
+    a_star(starts, successors, |_| C::default(), is_goal)
+}
+
+/// Find the cheapest path from any of the start states to a goal state using A*
+///
+/// The heuristic estimates the remaining cost from a state to the nearest goal. It must be
+/// consistent: the estimate for a state can't be more than the cost of a step plus the estimate for
+/// the state it leads to (otherwise the path found might not be the cheapest one)
+/// Returns None if no goal state can be reached
+pub fn a_star<S, C, I>(
+    starts: impl IntoIterator<Item=S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+    where S: Clone + Eq + Hash,
+          C: Copy + Ord + Default + Add<Output=C>,
+          I: IntoIterator<Item=(S, C)> {
+    // Every state which has been seen gets an index so that the queue and the parent links don't
+    // need to hold copies of the states
+    let mut indices = HashMap::<S, usize>::new();
+    let mut nodes: Vec<Node<S, C>> = vec![];
+    // Ordered by the estimated total cost, using the index to break ties so that the order is deterministic
+    let mut queue = BinaryHeap::<Reverse<(C, usize)>>::new();
+
+    for start in starts {
+        if let Entry::Vacant(entry) = indices.entry(start.clone()) {
+            entry.insert(nodes.len());
+            queue.push(Reverse((heuristic(&start), nodes.len())));
+            nodes.push(Node { state: start, cost: C::default(), parent: None, finalized: false });
+        }
+    }
+
+    while let Some(Reverse((_, index))) = queue.pop() {
+        if nodes[index].finalized {
+            continue; // A cheaper way to this state was already explored
+        }
+        nodes[index].finalized = true;
+
+        if is_goal(&nodes[index].state) {
+            return Some(Path {
+                cost: nodes[index].cost,
+                states: reconstruct(&nodes, index),
+            });
+        }
+
+        let cost = nodes[index].cost;
+        for (next, step_cost) in successors(&nodes[index].state) {
+            let next_cost = cost + step_cost;
+            let next_index = match indices.entry(next) {
+                Entry::Occupied(entry) => {
+                    let next_index = *entry.get();
+                    let node = &mut nodes[next_index];
+                    if node.finalized || node.cost <= next_cost {
+                        continue;
+                    }
+                    node.cost = next_cost;
+                    node.parent = Some(index);
+                    next_index
+                }
+                Entry::Vacant(entry) => {
+                    let next_index = nodes.len();
+                    nodes.push(Node { state: entry.key().clone(), cost: next_cost, parent: Some(index), finalized: false });
+                    entry.insert(next_index);
+                    next_index
+                }
+            };
+            queue.push(Reverse((next_cost + heuristic(&nodes[next_index].state), next_index)));
+        }
+    }
+
+    None
+}
+
+/// Follow the parent links back from a state to build the path leading to it
+fn reconstruct<S: Clone, C>(nodes: &[Node<S, C>], mut index: usize) -> Vec<S> {
+    let mut states = vec![nodes[index].state.clone()];
+    while let Some(parent) = nodes[index].parent {
+        states.push(nodes[parent].state.clone());
+        index = parent;
+    }
+    states.reverse();
+    states
+}
+
+#[test]
+fn test_dijkstra() {
+    // A small weighted graph where the direct edge is not the cheapest route
+    let edges = |node: &char| match node {
+        'a' => vec![('b', 7), ('c', 2)],
+        'c' => vec![('b', 3), ('d', 10)],
+        'b' => vec![('d', 1)],
+        _ => vec![],
+    };
+    assert_eq!(
+        Some(Path { cost: 6, states: vec!['a', 'c', 'b', 'd'] }),
+        dijkstra(['a'], edges, |node| *node == 'd')
+    );
+    assert_eq!(Some(Path { cost: 0, states: vec!['d'] }), dijkstra(['a', 'd'], edges, |node| *node == 'd'));
+    assert_eq!(None, dijkstra(['a'], edges, |node| *node == 'e'));
+}
+
+#[test]
+fn test_a_star() {
+    // Walking along a number line towards 10 where each step costs 1
+    let steps = |x: &i32| [(x - 1, 1), (x + 1, 1)];
+    let path = a_star([0], steps, |x| (10 - x).abs(), |x| *x == 10).unwrap();
+    assert_eq!(10, path.cost);
+    assert_eq!((0..=10).collect::<Vec<_>>(), path.states);
+}